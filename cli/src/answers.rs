@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml_edit::{value, DocumentMut, InlineTable, Item, Table, Value};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum StoredAnswer {
+    Text(String),
+    Number(i64),
+}
+
+impl Display for StoredAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoredAnswer::Text(text) => write!(f, "{text}"),
+            StoredAnswer::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+struct DayAnswers {
+    part1: Option<StoredAnswer>,
+    part2: Option<StoredAnswer>,
+}
+
+/// Known good answers, read from a toml file keyed by day then part, eg:
+///
+/// ```toml
+/// [day01]
+/// part1 = 142
+/// part2 = "281"
+/// ```
+///
+/// Answers recorded with `--save` also say when, and at which commit:
+///
+/// ```toml
+/// part1_saved = { at = "2023-12-01T06:12:45Z", commit = "8c4e2f1" }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Answers(HashMap<String, DayAnswers>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// The tables in the file, which should each be a day, eg `day01`
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<String> {
+        let answers = self.0.get(&format!("day{day:0>2}"))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+        .map(StoredAnswer::to_string)
+    }
+}
+
+/// An answer `--save` didn't write, because it disagrees with the one already stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub day: usize,
+    pub part: usize,
+    pub stored: String,
+    pub answer: String,
+}
+
+/// Record the answers (day, part, answer) in the toml file at `path`, creating it if need be and
+/// keeping everything else in it, comments included. A different answer is never written over a
+/// stored one, it's more likely to be a bug than a correction, so those are returned instead
+pub fn save_answers(path: &Path, answers: &[(usize, usize, String)]) -> Result<Vec<Conflict>> {
+    let contents = if path.exists() {
+        read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let mut document: DocumentMut = contents
+        .parse()
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let saved = saved_metadata(SystemTime::now(), git_commit());
+    let mut conflicts = vec![];
+    for (day, part, answer) in answers {
+        if let Some(conflict) = save_answer(&mut document, *day, *part, answer, &saved) {
+            conflicts.push(conflict);
+        }
+    }
+
+    write(path, document.to_string())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(conflicts)
+}
+
+fn save_answer(
+    document: &mut DocumentMut,
+    day: usize,
+    part: usize,
+    answer: &str,
+    saved: &InlineTable,
+) -> Option<Conflict> {
+    let day_table = document
+        .entry(&format!("day{day:0>2}"))
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_like_mut()?;
+    let key = format!("part{part}");
+
+    let stored = day_table.get(&key).and_then(|item| match item.as_value()? {
+        Value::String(text) => Some(text.value().clone()),
+        Value::Integer(number) => Some(number.value().to_string()),
+        _ => None,
+    });
+    if let Some(stored) = stored.filter(|stored| stored != answer) {
+        return Some(Conflict {
+            day,
+            part,
+            stored,
+            answer: answer.to_string(),
+        });
+    }
+
+    // Keep numbers as numbers, the way they'd be written by hand
+    let answer_value = match answer.parse::<i64>() {
+        Ok(number) => value(number),
+        Err(_) => value(answer),
+    };
+    day_table.insert(&key, answer_value);
+    day_table.insert(&format!("{key}_saved"), value(saved.clone()));
+    None
+}
+
+fn saved_metadata(now: SystemTime, commit: Option<String>) -> InlineTable {
+    let mut saved = InlineTable::new();
+    saved.insert("at", utc_timestamp(now).into());
+    if let Some(commit) = commit {
+        saved.insert("commit", commit.into());
+    }
+    saved
+}
+
+/// The commit being run, marked dirty if there are uncommitted changes. None outside a git repo
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !commit.trim().is_empty()).then(|| commit.trim().to_string())
+}
+
+/// An RFC 3339 timestamp in UTC, eg `2023-12-01T06:12:45Z`
+pub fn utc_timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time_of_day = seconds.rem_euclid(86_400);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60
+    )
+}
+
+/// The (year, month, day) a number of days after 1970-01-01, see
+/// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let answers: Answers = toml::from_str(
+            r#"
+[day01]
+part1 = 142
+part2 = "281"
+
+[day12]
+part1 = "21"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("142".to_string()));
+        assert_eq!(answers.get(1, 2), Some("281".to_string()));
+        assert_eq!(answers.get(12, 1), Some("21".to_string()));
+        assert_eq!(answers.get(12, 2), None);
+        assert_eq!(answers.get(2, 1), None);
+        assert_eq!(answers.get(1, 3), None);
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(utc_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        let time = UNIX_EPOCH + std::time::Duration::from_secs(1_701_411_165);
+        assert_eq!(utc_timestamp(time), "2023-12-01T06:12:45Z");
+        let time = UNIX_EPOCH + std::time::Duration::from_secs(951_782_400);
+        assert_eq!(utc_timestamp(time), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn test_save_answer() {
+        let mut document: DocumentMut = r#"# Checked by hand
+[day01]
+part1 = 142
+"#
+        .parse()
+        .unwrap();
+        let saved = saved_metadata(UNIX_EPOCH, Some("abc1234".to_string()));
+
+        assert_eq!(save_answer(&mut document, 1, 1, "142", &saved), None);
+        assert_eq!(save_answer(&mut document, 1, 2, "x281", &saved), None);
+        assert_eq!(save_answer(&mut document, 3, 1, "4361", &saved), None);
+        assert_eq!(
+            save_answer(&mut document, 1, 1, "143", &saved),
+            Some(Conflict {
+                day: 1,
+                part: 1,
+                stored: "142".to_string(),
+                answer: "143".to_string(),
+            })
+        );
+
+        let answers: Answers = toml::from_str(&document.to_string()).unwrap();
+        assert_eq!(answers.get(1, 1), Some("142".to_string()));
+        assert_eq!(answers.get(1, 2), Some("x281".to_string()));
+        assert_eq!(answers.get(3, 1), Some("4361".to_string()));
+        assert!(document.to_string().starts_with("# Checked by hand"));
+        assert!(document
+            .to_string()
+            .contains(r#"part1_saved = { at = "1970-01-01T00:00:00Z", commit = "abc1234" }"#));
+    }
+}
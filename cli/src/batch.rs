@@ -0,0 +1,133 @@
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::registry::{get_implementation, DEFAULT_IMPLEMENTATION};
+use crate::report::RunReport;
+use crate::runner::time_solver;
+use crate::to_report;
+
+/// A puzzle to solve, read from a line of JSON, eg:
+///
+/// ```json
+/// {"id": "a", "day": 1, "part": 1, "input": "1abc2\npqr3stu8vwx", "impl": "default", "expected": "50"}
+/// ```
+///
+/// Only the day, part and input are needed. The id, which can be any JSON, is passed back with the
+/// result to match them up
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Job {
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub day: usize,
+    pub part: usize,
+    pub input: String,
+    #[serde(default, rename = "impl")]
+    pub implementation: Option<String>,
+    #[serde(default)]
+    pub expected: Option<String>,
+}
+
+/// What's written back for each line read, the same as `run --format json` gives along with the job's id
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JobResult {
+    Done {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<Value>,
+        #[serde(flatten)]
+        report: Box<RunReport>,
+    },
+    /// The line couldn't be read as a job
+    Invalid { line: usize, error: String },
+}
+
+impl Job {
+    pub fn solve(self, timeout: Option<Duration>) -> JobResult {
+        let (day, part) = (self.day, self.part);
+        let input_path = PathBuf::from("-");
+        let name = self
+            .implementation
+            .as_deref()
+            .unwrap_or(DEFAULT_IMPLEMENTATION);
+        let report = match get_implementation(day, part, name) {
+            Some(solver) => {
+                let outcome = time_solver(solver, &self.input, timeout, false);
+                to_report(day, part, input_path, outcome).check_against(self.expected)
+            }
+            None => RunReport::failed(
+                day,
+                part,
+                input_path,
+                format!("has no implementation called {name}"),
+            ),
+        };
+        JobResult::Done {
+            id: self.id,
+            report: Box::new(report),
+        }
+    }
+}
+
+/// Solve a job for every line of `jobs`, writing each result as a line of JSON as soon as it's done
+pub fn run_batch(
+    jobs: impl BufRead,
+    mut results: impl Write,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    for (index, line) in jobs.lines().enumerate() {
+        let line = line.context("failed to read job")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let result = match serde_json::from_str::<Job>(&line) {
+            Ok(job) => job.solve(timeout),
+            Err(error) => JobResult::Invalid {
+                line: index + 1,
+                error: error.to_string(),
+            },
+        };
+        writeln!(results, "{}", serde_json::to_string(&result)?)
+            .context("failed to write result")?;
+        results.flush().context("failed to write result")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_run_batch() {
+        let jobs = [
+            json!({"id": 7, "day": 1, "part": 1, "input": "1abc2\npqr3stu8vwx", "expected": "50"}),
+            json!({"day": 4, "part": 2, "input": crate::examples::get_example(4, 2).unwrap().input, "impl": "queue"}),
+            json!({"day": 4, "part": 2, "input": "", "impl": "missing"}),
+        ]
+        .map(|job| job.to_string())
+        .join("\n");
+        let jobs = format!("{jobs}\n\nnot json\n");
+        let mut results = Vec::new();
+        run_batch(jobs.as_bytes(), &mut results, None).unwrap();
+        let results: Vec<Value> = String::from_utf8(results)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0]["id"], 7);
+        assert_eq!(results[0]["answer"], 50);
+        assert_eq!(results[0]["correct"], true);
+        assert_eq!(results[1]["answer"], 30);
+        assert_eq!(results[1].get("id"), None);
+        assert_eq!(results[2]["error"], "has no implementation called missing");
+        assert_eq!(results[3]["line"], 5);
+    }
+}
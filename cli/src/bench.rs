@@ -0,0 +1,174 @@
+use std::hint::black_box;
+use std::time::Duration;
+
+use crate::registry::Solve;
+use crate::runner::time_solution;
+
+/// How many interquartile ranges past the quartiles a sample has to be to count as an outlier
+const OUTLIER_FENCE: f64 = 1.5;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len() as u32;
+        let mean = sorted.iter().sum::<Duration>() / count;
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Self {
+            min: sorted[0],
+            mean,
+            median,
+            max: sorted[sorted.len() - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Time `iterations` runs of the solution after `warmup` untimed ones. The input is only ever read
+/// from memory, and the warm-up pulls it into cache along with warming up the allocator
+pub fn sample(solve: Solve, input: &str, warmup: usize, iterations: usize) -> Vec<Duration> {
+    for _ in 0..warmup {
+        let _ = black_box(solve.call(black_box(input)));
+    }
+    (0..iterations)
+        .map(|_| time_solution(solve, black_box(input)).1)
+        .collect()
+}
+
+/// How many times faster `other` is than `baseline`, below 1 when it's slower
+pub fn speedup(baseline: Duration, other: Duration) -> f64 {
+    baseline.as_secs_f64() / other.as_secs_f64()
+}
+
+/// Linearly interpolated quantile `q` of already sorted samples
+fn quantile(sorted: &[Duration], q: f64) -> Duration {
+    let position = q * (sorted.len() - 1) as f64;
+    let below = sorted[position.floor() as usize].as_secs_f64();
+    let above = sorted[position.ceil() as usize].as_secs_f64();
+    Duration::from_secs_f64(below + (above - below) * position.fract())
+}
+
+/// Split samples into those within Tukey's fences and the outliers beyond them, which are usually
+/// the OS scheduling something else rather than anything the solver did
+pub fn reject_outliers(samples: &[Duration]) -> (Vec<Duration>, Vec<Duration>) {
+    if samples.is_empty() {
+        return (vec![], vec![]);
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let lower_quartile = quantile(&sorted, 0.25);
+    let upper_quartile = quantile(&sorted, 0.75);
+    let fence = (upper_quartile - lower_quartile).mul_f64(OUTLIER_FENCE);
+    let low = lower_quartile.saturating_sub(fence);
+    let high = upper_quartile + fence;
+
+    samples
+        .iter()
+        .partition(|&&sample| (low..=high).contains(&sample))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_odd_samples() {
+        let stats = Stats::from_samples(&millis(&[9, 1, 5])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.max, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn test_even_samples() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_sample() {
+        let shout = Solve::BuiltIn(|input| Ok(input.to_uppercase().into()));
+        let samples = sample(shout, "abc", 2, 5);
+        assert_eq!(samples.len(), 5);
+    }
+
+    #[test]
+    fn test_speedup() {
+        let millis = Duration::from_millis;
+        assert_eq!(speedup(millis(10), millis(5)), 2.0);
+        assert_eq!(speedup(millis(5), millis(10)), 0.5);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let sorted = millis(&[1, 2, 3, 4, 5]);
+        assert_eq!(quantile(&sorted, 0.0), Duration::from_millis(1));
+        assert_eq!(quantile(&sorted, 0.25), Duration::from_millis(2));
+        assert_eq!(quantile(&sorted, 0.5), Duration::from_millis(3));
+        assert_eq!(quantile(&sorted, 1.0), Duration::from_millis(5));
+
+        let sorted = millis(&[2, 4]);
+        assert_eq!(quantile(&sorted, 0.5), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_reject_outliers() {
+        let (kept, rejected) = reject_outliers(&millis(&[10, 11, 100, 12, 10, 11, 1]));
+        assert_eq!(kept, millis(&[10, 11, 12, 10, 11]));
+        assert_eq!(rejected, millis(&[100, 1]));
+
+        let (kept, rejected) = reject_outliers(&millis(&[5, 5, 5]));
+        assert_eq!(kept, millis(&[5, 5, 5]));
+        assert!(rejected.is_empty());
+
+        assert_eq!(reject_outliers(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_std_dev() {
+        let stats = Stats::from_samples(&millis(&[2, 4, 4, 4, 5, 5, 7, 9])).unwrap();
+        assert_eq!(stats.std_dev.as_millis(), 2);
+
+        let stats = Stats::from_samples(&millis(&[3, 3, 3])).unwrap();
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::answer::Answer;
+use crate::registry::{Implementation, DEFAULT_IMPLEMENTATION};
+
+/// Where `run --all` keeps the answers it doesn't want to work out again
+pub const CACHE_PATH: &str = ".answer-cache.json";
+
+/// Only answers that took at least this long are worth caching, the rest are quicker to solve again
+/// and it's nice to still see how long they take
+pub const EXPENSIVE: Duration = Duration::from_secs(1);
+
+/// The 64 bit FNV-1a hash of an input or a day's parameters. Unlike `DefaultHasher` this won't
+/// change between Rust versions, so the cache survives an upgrade
+fn hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Only the main implementations are cached, an alternative might not agree with them yet. Tables
+/// keep their keys sorted, so the same parameters always hash the same
+fn key(solver: &Implementation, input: &str, params: Option<&toml::Table>) -> Option<String> {
+    let params = params.map(toml::Table::to_string).unwrap_or_default();
+    (solver.name == DEFAULT_IMPLEMENTATION).then(|| {
+        format!(
+            "day{:0>2}/part{}/{:016x}/{:016x}",
+            solver.day,
+            solver.part,
+            hash(input),
+            hash(&params)
+        )
+    })
+}
+
+/// Answers for slow days and parts, keyed by a hash of the input and the day's parameters they were
+/// for, so changing either solves it again
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AnswerCache(BTreeMap<String, Answer>);
+
+impl AnswerCache {
+    /// Read the cache from `path`, which is empty if there's no such file yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .map(Self)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.0)?;
+        write(path, json).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn get(
+        &self,
+        solver: &Implementation,
+        input: &str,
+        params: Option<&toml::Table>,
+    ) -> Option<&Answer> {
+        self.0.get(&key(solver, input, params)?)
+    }
+
+    pub fn insert(
+        &mut self,
+        solver: &Implementation,
+        input: &str,
+        params: Option<&toml::Table>,
+        answer: Answer,
+    ) {
+        if let Some(key) = key(solver, input, params) {
+            self.0.insert(key, answer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::{get_implementation, get_solver};
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_cache() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.json", std::process::id()));
+        let part1 = get_solver(5, 1).unwrap();
+        let part2 = get_solver(5, 2).unwrap();
+        let mut cache = AnswerCache::load(&path).unwrap();
+        cache.insert(part2, "seeds: 1 2", None, Answer::U64(46));
+        cache.save(&path).unwrap();
+        let cache = AnswerCache::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(cache.get(part2, "seeds: 1 2", None), Some(&Answer::U64(46)));
+        assert_eq!(cache.get(part2, "seeds: 1 3", None), None);
+        assert_eq!(cache.get(part1, "seeds: 1 2", None), None);
+    }
+
+    #[test]
+    fn test_changed_params_miss() {
+        let solver = get_solver(11, 2).unwrap();
+        let params =
+            |expansion: i64| toml::Table::from_iter([("expansion".into(), expansion.into())]);
+        let mut cache = AnswerCache::default();
+        cache.insert(solver, "#.#", Some(&params(100)), Answer::U64(102));
+
+        assert_eq!(
+            cache.get(solver, "#.#", Some(&params(100))),
+            Some(&Answer::U64(102))
+        );
+        assert_eq!(cache.get(solver, "#.#", Some(&params(10))), None);
+        assert_eq!(cache.get(solver, "#.#", None), None);
+    }
+
+    #[test]
+    fn test_alternatives_arent_cached() {
+        let solver = get_implementation(5, 2, "combined").unwrap();
+        let mut cache = AnswerCache::default();
+        cache.insert(solver, "seeds: 1 2", None, Answer::U64(46));
+        assert_eq!(cache, AnswerCache::default());
+        assert_eq!(cache.get(solver, "seeds: 1 2", None), None);
+    }
+}
@@ -0,0 +1,284 @@
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use crate::config::DayParam;
+use crate::report::Format;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Solutions to Advent of Code 2023")]
+pub struct Opt {
+    /// Don't colour the output, colour is already off when not writing to a terminal
+    #[structopt(long = "no-color", global = true)]
+    pub no_color: bool,
+    /// Log what the solvers are doing to stderr, repeat for more detail. `RUST_LOG` overrides this
+    #[structopt(short = "v", long = "verbose", global = true, parse(from_occurrences))]
+    pub verbose: u8,
+    /// Give up on a solver that's still going after this many seconds
+    #[structopt(short = "t", long = "timeout", global = true)]
+    pub timeout: Option<f64>,
+    /// How many threads to solve with, defaults to one per CPU
+    #[structopt(short = "j", long = "threads", global = true)]
+    pub threads: Option<usize>,
+    /// Where to read default settings from
+    #[structopt(
+        long = "config",
+        default_value = "aoc.toml",
+        global = true,
+        parse(from_os_str)
+    )]
+    pub config: PathBuf,
+    /// Where to find (and download) inputs, defaults to `inputs`
+    #[structopt(long = "input-dir", global = true, parse(from_os_str))]
+    pub input_dir: Option<PathBuf>,
+    /// Which year's inputs to use, read from `<input-dir>/<year>/`. Defaults to 2023
+    #[structopt(long = "year", global = true)]
+    pub year: Option<usize>,
+    /// Load more implementations from a library built with `plugin!`, can be given more than once
+    #[structopt(
+        long = "plugin",
+        global = true,
+        number_of_values = 1,
+        parse(from_os_str)
+    )]
+    pub plugins: Vec<PathBuf>,
+    /// Set one of a day's parameters as `<day>.<name>=<value>`, eg `2.red=20`, overriding the config
+    /// file. Can be given more than once
+    #[structopt(long = "param", global = true, number_of_values = 1)]
+    pub params: Vec<DayParam>,
+    #[structopt(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Solve a day and part, or every implemented one with --all
+    Run(RunOpt),
+    /// Time a day and part over many iterations
+    Bench(BenchOpt),
+    /// Check two implementations of a day and part agree, and see which is faster
+    Compare(CompareOpt),
+    /// Write a bar chart of each day's times from a file written by `bench --export`, as an SVG
+    Chart(ChartOpt),
+    /// Show how the timings recorded by `run --history` have changed, flagging any creeping up
+    Trends(TrendsOpt),
+    /// Read jobs from stdin, a line of JSON each, and write a line of JSON back as each is solved
+    Batch,
+    /// Download a day's input from adventofcode.com (using AOC_SESSION), replacing any existing one
+    Fetch(FetchOpt),
+    /// Show which days and parts are solved and which inputs are present
+    List,
+    /// Check everything's set up, the inputs, session cookie, answers and files written to, and say
+    /// how to fix anything that isn't
+    Doctor(DoctorOpt),
+    /// Create `core/src/dayXX.rs` from the template and add it to `core/src/lib.rs` to be run
+    NewDay(NewDayOpt),
+    /// Draw what a day made of its input, for days that can (so far days 3 and 10)
+    Viz(VizOpt),
+    /// Write a day's input as a GraphViz graph, for days that are one (so far day 8)
+    Graph(GraphOpt),
+}
+
+#[derive(Debug, StructOpt)]
+pub struct RunOpt {
+    /// The input file to use, or `-` to read from stdin. Defaults to `dXX.txt` in the input directory
+    #[structopt(parse(from_os_str))]
+    pub input: Option<PathBuf>,
+    #[structopt(short = "d", long = "day", required_unless_one = &["all", "check-examples"])]
+    pub day: Option<usize>,
+    #[structopt(short = "p", long = "part", required_unless_one = &["all", "check-examples"])]
+    pub part: Option<usize>,
+    /// Run every implemented day and part against its input in `inputs/`
+    #[structopt(short = "a", long = "all", conflicts_with_all = &["input", "day", "part"])]
+    pub all: bool,
+    /// With --all, solve everything again rather than using answers cached from earlier slow runs
+    #[structopt(long = "force", requires = "all")]
+    pub force: bool,
+    /// With --all, run one solver at a time rather than in parallel, for less noisy timings
+    #[structopt(long = "sequential", requires = "all")]
+    pub sequential: bool,
+    /// Output format, one of `text`, `json`, `csv` or `raw`. Defaults to `text`
+    #[structopt(short = "f", long = "format")]
+    pub format: Option<Format>,
+    /// Print only the answer, the same as `--format raw`
+    #[structopt(long = "raw", conflicts_with = "format")]
+    pub raw: bool,
+    /// Compare answers with those in the answers file and exit non-zero if any don't match
+    #[structopt(short = "c", long = "check")]
+    pub check: bool,
+    /// The toml file of known good answers used by --check
+    #[structopt(long = "answers", default_value = "answers.toml", parse(from_os_str))]
+    pub answers: PathBuf,
+    /// Record answers in the answers file, along with when and at which commit
+    #[structopt(short = "s", long = "save", conflicts_with = "example")]
+    pub save: bool,
+    /// Record timings in the history file and compare them with earlier runs of the same input
+    #[structopt(long = "history", conflicts_with = "example")]
+    pub history: bool,
+    /// The file of timings recorded by --history
+    #[structopt(
+        long = "history-file",
+        default_value = "history.jsonl",
+        parse(from_os_str)
+    )]
+    pub history_file: PathBuf,
+    /// How much slower than last time (in percent) --history should call a regression
+    #[structopt(long = "regression-threshold", default_value = "10")]
+    pub regression_threshold: f64,
+    /// Run against the example from the puzzle description and compare with its answer
+    #[structopt(
+        short = "e",
+        long = "example",
+        conflicts_with_all = &["input", "all", "check"]
+    )]
+    pub example: bool,
+    /// Run every day and part against its example, showing which pass. The same as the tests, but
+    /// for checking an installed binary
+    #[structopt(
+        long = "check-examples",
+        conflicts_with_all = &["input", "day", "part", "all", "example"]
+    )]
+    pub check_examples: bool,
+    /// Before the real input, check the solver still gets the example right and stop if it doesn't
+    #[structopt(short = "E", long = "example-first", conflicts_with = "example")]
+    pub example_first: bool,
+    /// Keep running, solving again whenever the input file changes
+    #[structopt(short = "w", long = "watch", conflicts_with_all = &["all", "example"])]
+    pub watch: bool,
+    /// Use an alternative implementation of the solver, if the day and part has any
+    #[structopt(long = "impl", conflicts_with = "all")]
+    pub implementation: Option<String>,
+    /// Profile the solver, writing a flamegraph (or a pprof protobuf if the file ends `.pb`)
+    #[structopt(long = "profile", conflicts_with_all = &["all", "watch"], parse(from_os_str))]
+    pub profile: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct BenchOpt {
+    /// The input file to use, or `-` to read from stdin. Defaults to `dXX.txt` in the input directory
+    #[structopt(parse(from_os_str))]
+    pub input: Option<PathBuf>,
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+    #[structopt(short = "p", long = "part")]
+    pub part: usize,
+    /// How many times to run the solver after warming up
+    #[structopt(short = "n", long = "iterations", default_value = "100")]
+    pub iterations: usize,
+    /// How many untimed runs to warm up caches and the allocator with, there's always at least one
+    #[structopt(long = "warmup", default_value = "3")]
+    pub warmup: usize,
+    /// Use an alternative implementation of the solver, to compare it with the default
+    #[structopt(long = "impl")]
+    pub implementation: Option<String>,
+    /// Keep samples far outside the interquartile range instead of rejecting them as noise
+    #[structopt(long = "keep-outliers")]
+    pub keep_outliers: bool,
+    /// Profile the timed iterations, writing a flamegraph (or a pprof protobuf if the file ends `.pb`)
+    #[structopt(long = "profile", parse(from_os_str))]
+    pub profile: Option<PathBuf>,
+    /// Add the results to a JSON file of benchmarks that critcmp can compare
+    #[structopt(long = "export", parse(from_os_str))]
+    pub export: Option<PathBuf>,
+    /// What to call the benchmarks in the exported file, to tell them apart from other runs
+    #[structopt(long = "baseline", default_value = "base")]
+    pub baseline: String,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct CompareOpt {
+    /// The input file to use, or `-` to read from stdin. Defaults to `dXX.txt` in the input directory
+    #[structopt(parse(from_os_str))]
+    pub input: Option<PathBuf>,
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+    #[structopt(short = "p", long = "part")]
+    pub part: usize,
+    /// The implementation to compare against, defaults to the main one
+    #[structopt(long = "impl-a")]
+    pub impl_a: Option<String>,
+    /// The implementation being compared
+    #[structopt(long = "impl-b")]
+    pub impl_b: String,
+    /// How many times to run each implementation after warming up
+    #[structopt(short = "n", long = "iterations", default_value = "100")]
+    pub iterations: usize,
+    /// How many untimed runs to warm up each implementation with
+    #[structopt(long = "warmup", default_value = "3")]
+    pub warmup: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct ChartOpt {
+    /// The benchmarks to chart, each day and part's latest run exported there by `bench --export`
+    #[structopt(parse(from_os_str))]
+    pub benchmarks: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct TrendsOpt {
+    /// Only show this day
+    #[structopt(short = "d", long = "day")]
+    pub day: Option<usize>,
+    /// How many of the latest runs of each day and part to fit the trend to
+    #[structopt(short = "n", long = "runs", default_value = "10")]
+    pub runs: usize,
+    /// How much slower (in percent) the trend can get over those runs before it's flagged
+    #[structopt(long = "threshold", default_value = "10")]
+    pub threshold: f64,
+    /// The file of timings recorded by `run --history`
+    #[structopt(
+        long = "history-file",
+        default_value = "history.jsonl",
+        parse(from_os_str)
+    )]
+    pub history_file: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct FetchOpt {
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct DoctorOpt {
+    /// The toml file of known good answers used by `run --check`
+    #[structopt(long = "answers", default_value = "answers.toml", parse(from_os_str))]
+    pub answers: PathBuf,
+    /// The file of timings recorded by `run --history`
+    #[structopt(
+        long = "history-file",
+        default_value = "history.jsonl",
+        parse(from_os_str)
+    )]
+    pub history_file: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct VizOpt {
+    /// The input file to draw, or `-` to read from stdin. Defaults to `dXX.txt` in the input directory
+    #[structopt(parse(from_os_str))]
+    pub input: Option<PathBuf>,
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+    /// Write an HTML page rather than colouring the text for a terminal, for days 3 and 10
+    #[structopt(long = "html")]
+    pub html: bool,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct GraphOpt {
+    /// The input file to write as a graph, or `-` to read from stdin. Defaults to `dXX.txt` in the
+    /// input directory
+    #[structopt(parse(from_os_str))]
+    pub input: Option<PathBuf>,
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct NewDayOpt {
+    #[structopt(short = "d", long = "day")]
+    pub day: usize,
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::DEFAULT_IMPLEMENTATION;
+
+/// The z score of a 95% confidence interval
+const Z_95: f64 = 1.96;
+/// Scales the median absolute deviation to estimate the standard deviation, as criterion does
+const MAD_SCALE: f64 = 1.4826;
+
+/// A set of benchmarks in the format `critcmp --export` writes, which `critcmp` can then read back
+/// to compare against other baselines, eg:
+///
+/// ```sh
+/// $ critcmp before.json after.json
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub name: String,
+    pub benchmarks: BTreeMap<String, Benchmark>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Benchmark {
+    pub baseline: String,
+    pub fullname: String,
+    #[serde(rename = "criterion_benchmark_v1")]
+    pub info: BenchmarkInfo,
+    #[serde(rename = "criterion_estimates_v1")]
+    pub estimates: Estimates,
+}
+
+/// What criterion writes to `benchmark.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkInfo {
+    pub group_id: String,
+    pub function_id: Option<String>,
+    pub value_str: Option<String>,
+    pub throughput: Option<serde_json::Value>,
+    pub full_id: String,
+    pub directory_name: String,
+}
+
+/// What criterion writes to `estimates.json`, all in nanoseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Estimates {
+    pub mean: Estimate,
+    pub median: Estimate,
+    pub median_abs_dev: Estimate,
+    pub slope: Option<Estimate>,
+    pub std_dev: Estimate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Estimate {
+    pub confidence_interval: ConfidenceInterval,
+    pub point_estimate: f64,
+    pub standard_error: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceInterval {
+    pub confidence_level: f64,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+}
+
+impl Estimate {
+    /// Criterion bootstraps its confidence intervals, these assume the estimates are normally
+    /// distributed instead, which is close enough for comparing runs
+    fn new(point_estimate: f64, standard_error: f64) -> Self {
+        Self {
+            confidence_interval: ConfidenceInterval {
+                confidence_level: 0.95,
+                lower_bound: point_estimate - Z_95 * standard_error,
+                upper_bound: point_estimate + Z_95 * standard_error,
+            },
+            point_estimate,
+            standard_error,
+        }
+    }
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2.0
+    } else {
+        sorted[middle]
+    }
+}
+
+impl Estimates {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut nanos: Vec<_> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        nanos.sort_by(f64::total_cmp);
+        let count = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / count;
+        let std_dev = (nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count).sqrt();
+        let median = median(&nanos);
+        let mut deviations: Vec<_> = nanos.iter().map(|n| (n - median).abs()).collect();
+        deviations.sort_by(f64::total_cmp);
+        let median_abs_dev = self::median(&deviations) * MAD_SCALE;
+
+        let standard_error = std_dev / count.sqrt();
+        let spread_error = std_dev / (2.0 * (count - 1.0).max(1.0)).sqrt();
+        Some(Self {
+            mean: Estimate::new(mean, standard_error),
+            // The median's standard error is larger than the mean's by sqrt(pi / 2)
+            median: Estimate::new(median, standard_error * 1.2533),
+            median_abs_dev: Estimate::new(median_abs_dev, spread_error),
+            slope: None,
+            std_dev: Estimate::new(std_dev, spread_error),
+        })
+    }
+}
+
+impl Benchmark {
+    /// Named `dayXX/partN` like criterion would, with the implementation after if it isn't the default
+    pub fn new(
+        baseline: &str,
+        day: usize,
+        part: usize,
+        implementation: &str,
+        estimates: Estimates,
+    ) -> Self {
+        let group_id = format!("day{day:0>2}");
+        let function_id = format!("part{part}");
+        let value_str =
+            (implementation != DEFAULT_IMPLEMENTATION).then(|| implementation.to_string());
+        let full_id = match &value_str {
+            Some(value) => format!("{group_id}/{function_id}/{value}"),
+            None => format!("{group_id}/{function_id}"),
+        };
+        Self {
+            baseline: baseline.to_string(),
+            fullname: format!("{baseline}/{full_id}"),
+            info: BenchmarkInfo {
+                group_id,
+                function_id: Some(function_id),
+                value_str,
+                throughput: None,
+                directory_name: full_id.clone(),
+                full_id,
+            },
+            estimates,
+        }
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// The median time of the main implementation of each day and part benchmarked, in order
+    pub fn runtimes(&self) -> Vec<(usize, usize, Duration)> {
+        self.benchmarks
+            .values()
+            .filter(|benchmark| benchmark.info.value_str.is_none())
+            .filter_map(|benchmark| {
+                let day = benchmark.info.group_id.strip_prefix("day")?.parse().ok()?;
+                let function_id = benchmark.info.function_id.as_deref()?;
+                let part = function_id.strip_prefix("part")?.parse().ok()?;
+                let median = benchmark.estimates.median.point_estimate;
+                Some((day, part, Duration::from_nanos(median as u64)))
+            })
+            .collect()
+    }
+}
+
+/// Add the benchmark to the baseline file at `path`, replacing an earlier run of the same one and
+/// keeping the rest, so the days can be benchmarked one at a time into the same file
+pub fn export_benchmark(path: &Path, benchmark: Benchmark) -> Result<()> {
+    let mut baseline = if path.exists() {
+        Baseline::load(path)?
+    } else {
+        Baseline {
+            name: benchmark.baseline.clone(),
+            benchmarks: BTreeMap::new(),
+        }
+    };
+    baseline.name = benchmark.baseline.clone();
+    for existing in baseline.benchmarks.values_mut() {
+        existing.baseline = benchmark.baseline.clone();
+        existing.fullname = format!("{}/{}", benchmark.baseline, existing.info.full_id);
+    }
+    baseline
+        .benchmarks
+        .insert(benchmark.info.full_id.clone(), benchmark);
+    let json = serde_json::to_string_pretty(&baseline)?;
+    write(path, json).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_estimates() {
+        let samples: Vec<_> = [10, 20, 30, 40, 1000]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+        let estimates = Estimates::from_samples(&samples).unwrap();
+        assert_eq!(estimates.mean.point_estimate, 220.0);
+        assert_eq!(estimates.median.point_estimate, 30.0);
+        assert_eq!(estimates.median_abs_dev.point_estimate, 10.0 * MAD_SCALE);
+        assert!(estimates.mean.confidence_interval.lower_bound < 220.0);
+        assert!(Estimates::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_export_benchmark() {
+        let path = std::env::temp_dir().join(format!("aoc-criterion-{}.json", std::process::id()));
+        let estimates = Estimates::from_samples(&[Duration::from_nanos(5)]).unwrap();
+        export_benchmark(
+            &path,
+            Benchmark::new("before", 4, 2, DEFAULT_IMPLEMENTATION, estimates.clone()),
+        )
+        .unwrap();
+        export_benchmark(&path, Benchmark::new("after", 4, 2, "queue", estimates)).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(baseline.name, "after");
+        assert_eq!(
+            baseline.benchmarks.keys().collect::<Vec<_>>(),
+            vec!["day04/part2", "day04/part2/queue"]
+        );
+        assert_eq!(
+            baseline.benchmarks["day04/part2/queue"].fullname,
+            "after/day04/part2/queue"
+        );
+        assert_eq!(
+            baseline.benchmarks["day04/part2"].fullname,
+            "after/day04/part2"
+        );
+        assert_eq!(baseline.runtimes(), vec![(4, 2, Duration::from_nanos(5))]);
+    }
+}
@@ -0,0 +1,246 @@
+use std::fs::{remove_file, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+
+use crate::answers::Answers;
+use crate::fetch::FetchError;
+use crate::inputs::existing_input;
+
+/// What one of `doctor`'s checks found, and if anything's wrong, how to put it right
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    /// What was found, whether or not it's a problem
+    pub found: String,
+    /// What to do about each problem, empty if there weren't any
+    pub fixes: Vec<String>,
+}
+
+impl Check {
+    fn fine(name: &'static str, found: impl Into<String>) -> Self {
+        Self {
+            name,
+            found: found.into(),
+            fixes: vec![],
+        }
+    }
+
+    fn problem(name: &'static str, found: impl Into<String>, fixes: Vec<String>) -> Self {
+        Self {
+            name,
+            found: found.into(),
+            fixes,
+        }
+    }
+
+    pub fn is_fine(&self) -> bool {
+        self.fixes.is_empty()
+    }
+}
+
+/// Each `(day, path)` has an input at that path, compressed or not
+pub fn check_inputs(inputs: &[(usize, PathBuf)]) -> Check {
+    let missing: Vec<_> = inputs
+        .iter()
+        .filter(|(_, path)| existing_input(path).is_none())
+        .collect();
+    if missing.is_empty() {
+        return Check::fine(
+            "Inputs",
+            format!("all {} solved days have one", inputs.len()),
+        );
+    }
+    let days = missing.iter().map(|(day, _)| day).join(", ");
+    let fix = match missing.as_slice() {
+        [(day, path)] => format!(
+            "run `cargo run -- fetch -d {day}` to download it to {}",
+            path.display()
+        ),
+        _ => "run `cargo run -- fetch -d <day>` for each of them, or `cargo run -- run --all` to \
+              download them all"
+            .to_string(),
+    };
+    Check::problem("Inputs", format!("missing for days {days}"), vec![fix])
+}
+
+/// What came of asking adventofcode.com whether the session cookie is logged in
+pub fn check_session(result: Result<(), FetchError>) -> Check {
+    let Err(error) = result else {
+        return Check::fine("Session", "logged in to adventofcode.com");
+    };
+    let fix = match error {
+        FetchError::NoSession => "set AOC_SESSION to your adventofcode.com session cookie, or \
+                                  session_file in aoc.toml to a file containing it"
+            .to_string(),
+        FetchError::Rejected(_) => "log in to adventofcode.com again and copy the new session \
+                                    cookie from your browser"
+            .to_string(),
+        FetchError::Unreachable(_) => {
+            "check you're online, inputs can't be downloaded until you are".to_string()
+        }
+    };
+    Check::problem("Session", error.to_string(), vec![fix])
+}
+
+/// The answers file at `path` only has days in `days`, named like `day01`, and has an answer for
+/// each of the `solved` days and parts so `--check` can check them
+pub fn check_answers(path: &Path, days: &[usize], solved: &[(usize, usize)]) -> Check {
+    let save = "`cargo run -- run --all --save`";
+    if !path.exists() {
+        return Check::problem(
+            "Answers",
+            format!("there's no {}", path.display()),
+            vec![format!("record the answers you get with {save}")],
+        );
+    }
+    let answers = match Answers::load(path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            return Check::problem(
+                "Answers",
+                format!("{error:#}"),
+                vec![format!(
+                    "fix {}, or move it out of the way and record the answers again with {save}",
+                    path.display()
+                )],
+            )
+        }
+    };
+
+    let is_day = |key: &str| {
+        let day = key.strip_prefix("day").and_then(|day| day.parse().ok());
+        day.is_some_and(|day: usize| format!("day{day:0>2}") == key && days.contains(&day))
+    };
+    let mut fixes: Vec<_> = answers
+        .keys()
+        .filter(|key| !is_day(key))
+        .sorted()
+        .map(|key| {
+            format!(
+                "[{key}] in {} isn't a day, rename it like [day01] or remove it",
+                path.display()
+            )
+        })
+        .collect();
+    let unanswered: Vec<_> = solved
+        .iter()
+        .filter(|(day, part)| answers.get(*day, *part).is_none())
+        .map(|(day, part)| format!("day {day} part {part}"))
+        .collect();
+    if !unanswered.is_empty() {
+        fixes.push(format!(
+            "record the answers for {} with {save}, once you're sure they're right",
+            unanswered.join(", ")
+        ));
+    }
+    let found = format!(
+        "{} of the {} solved parts have an answer",
+        solved.len() - unanswered.len(),
+        solved.len()
+    );
+    Check::problem("Answers", found, fixes)
+}
+
+/// The file can be written, or created if it isn't there yet. A file created to find out is
+/// removed again
+fn writable(path: &Path) -> std::io::Result<()> {
+    let existed = path.exists();
+    OpenOptions::new().append(true).create(true).open(path)?;
+    if !existed {
+        remove_file(path)?;
+    }
+    Ok(())
+}
+
+pub fn check_writable(name: &'static str, path: &Path) -> Check {
+    match writable(path) {
+        Ok(()) => Check::fine(name, format!("{} can be written", path.display())),
+        Err(error) => Check::problem(
+            name,
+            format!("can't write {}: {error}", path.display()),
+            vec![format!(
+                "check the permissions of {} and the directory it's in",
+                path.display()
+            )],
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-doctor-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_check_inputs() {
+        let present = temp_path("d01.txt");
+        std::fs::write(&present, "1abc2").unwrap();
+        let missing = temp_path("d02.txt");
+        let check = check_inputs(&[(1, present.clone()), (2, missing.clone())]);
+        std::fs::remove_file(&present).unwrap();
+
+        assert_eq!(check.found, "missing for days 2");
+        assert_eq!(
+            check.fixes,
+            vec![format!(
+                "run `cargo run -- fetch -d 2` to download it to {}",
+                missing.display()
+            )]
+        );
+        let check = check_inputs(&[(2, missing.clone()), (3, temp_path("d03.txt"))]);
+        assert_eq!(check.found, "missing for days 2, 3");
+        assert_eq!(check.fixes.len(), 1);
+        assert!(check_inputs(&[]).is_fine());
+    }
+
+    #[test]
+    fn test_check_session() {
+        assert!(check_session(Ok(())).is_fine());
+        let check = check_session(Err(FetchError::Rejected(400)));
+        assert_eq!(
+            check.found,
+            "adventofcode.com turned down the session cookie (HTTP 400), it has probably expired"
+        );
+        assert_eq!(check.fixes.len(), 1);
+        assert!(!check_session(Err(FetchError::NoSession)).is_fine());
+    }
+
+    #[test]
+    fn test_check_answers() {
+        let path = temp_path("answers.toml");
+        std::fs::write(
+            &path,
+            "[day01]\npart1 = 142\n\n[day1]\npart2 = 281\n\n[day26]\npart1 = 1\n",
+        )
+        .unwrap();
+        let days: Vec<_> = (1..=25).collect();
+        let check = check_answers(&path, &days, &[(1, 1), (1, 2)]);
+        std::fs::write(&path, "[day01]\npart1 = 142\npart2 = 281\n").unwrap();
+        let fine = check_answers(&path, &days, &[(1, 1), (1, 2)]);
+        std::fs::write(&path, "[day01\n").unwrap();
+        let broken = check_answers(&path, &days, &[(1, 1)]);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(check.found, "1 of the 2 solved parts have an answer");
+        assert_eq!(check.fixes.len(), 3);
+        assert!(check.fixes[0].starts_with("[day1] in "));
+        assert!(check.fixes[1].starts_with("[day26] in "));
+        assert!(check.fixes[2].starts_with("record the answers for day 1 part 2 with"));
+        assert!(fine.is_fine());
+        assert!(broken.found.starts_with("failed to parse"));
+        assert!(!check_answers(&path, &days, &[]).is_fine());
+    }
+
+    #[test]
+    fn test_check_writable() {
+        let path = temp_path("history.jsonl");
+        assert!(check_writable("History", &path).is_fine());
+        assert!(!path.exists());
+        let nowhere = temp_path("nowhere").join("history.jsonl");
+        assert!(!check_writable("History", &nowhere).is_fine());
+    }
+}
@@ -0,0 +1,89 @@
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use thiserror::Error;
+
+use crate::config::config;
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const USER_AGENT: &str = "github.com/Gisleburt/advent-of-code-2023";
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error(
+        "{SESSION_VAR} (or session_file in aoc.toml) must be set to your adventofcode.com session cookie to download inputs"
+    )]
+    NoSession,
+    #[error("adventofcode.com turned down the session cookie (HTTP {0}), it has probably expired")]
+    Rejected(u16),
+    #[error("couldn't reach adventofcode.com: {0}")]
+    Unreachable(String),
+}
+
+fn input_url(year: usize, day: usize) -> String {
+    format!("https://adventofcode.com/{year}/day/{day}/input")
+}
+
+/// The session cookie from AOC_SESSION, or failing that the session file in the config
+fn session() -> Result<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| {
+            let path = config().session_file.as_ref()?;
+            read_to_string(path).ok()
+        })
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| FetchError::NoSession.into())
+}
+
+/// Download the input for the given day and save it to `path`
+pub fn fetch_input(day: usize, path: &Path) -> Result<String> {
+    let session = session()?;
+    let input = ureq::get(&input_url(config().year, day))
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .with_context(|| format!("failed to download input for day {day}"))?
+        .into_string()?;
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, &input).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(input)
+}
+
+/// Check the session cookie is still logged in, with a HEAD request for day 1's input so nothing
+/// is actually downloaded
+pub fn check_logged_in() -> Result<(), FetchError> {
+    let session = session().map_err(|_| FetchError::NoSession)?;
+    match ureq::head(&input_url(config().year, 1))
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+    {
+        Ok(_) => Ok(()),
+        Err(ureq::Error::Status(status, _)) => Err(FetchError::Rejected(status)),
+        Err(error) => Err(FetchError::Unreachable(error.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_url() {
+        assert_eq!(
+            input_url(2023, 7),
+            "https://adventofcode.com/2023/day/7/input"
+        );
+        assert_eq!(
+            input_url(2022, 25),
+            "https://adventofcode.com/2022/day/25/input"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{git_commit, utc_timestamp};
+
+/// One timed run, stored a line of JSON each in the history file, eg:
+///
+/// ```json
+/// {"day":1,"part":1,"implementation":"default","input":"inputs/d01.txt","time_ns":51200,"at":"2023-12-01T06:12:45Z","commit":"8c4e2f1"}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: usize,
+    pub part: usize,
+    pub implementation: String,
+    pub input: PathBuf,
+    pub time_ns: u64,
+    pub at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+impl Timing {
+    pub fn new(
+        day: usize,
+        part: usize,
+        implementation: &str,
+        input: PathBuf,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            implementation: implementation.to_string(),
+            input,
+            time_ns: duration.as_nanos() as u64,
+            at: utc_timestamp(SystemTime::now()),
+            commit: git_commit(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.time_ns)
+    }
+
+    /// Whether two timings are of the same thing, so it's fair to compare them
+    fn same_run(&self, other: &Timing) -> bool {
+        self.day == other.day
+            && self.part == other.part
+            && self.implementation == other.implementation
+            && self.input == other.input
+    }
+}
+
+/// How a timing compares to the ones recorded before it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Comparison {
+    pub best: Duration,
+    pub latest: Duration,
+    /// How much slower than the latest timing this one has to be to count as a regression
+    pub threshold: f64,
+    pub duration: Duration,
+}
+
+impl Comparison {
+    /// The change from the best timing, as a fraction, so 0.5 is 50% slower
+    pub fn vs_best(&self) -> f64 {
+        change(self.best, self.duration)
+    }
+
+    /// The change from the latest timing, as a fraction
+    pub fn vs_latest(&self) -> f64 {
+        change(self.latest, self.duration)
+    }
+
+    /// Slower than last time by more than the threshold. It's compared with the latest rather than
+    /// the best timing so one lucky run doesn't make everything after it look like a regression
+    pub fn is_regression(&self) -> bool {
+        self.vs_latest() > self.threshold
+    }
+}
+
+fn change(from: Duration, to: Duration) -> f64 {
+    to.as_secs_f64() / from.as_secs_f64() - 1.0
+}
+
+/// How a run's timings have changed over the last few times it was recorded
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trend {
+    pub day: usize,
+    pub part: usize,
+    pub implementation: String,
+    pub input: PathBuf,
+    /// The timings looked at, oldest first
+    pub timings: Vec<Timing>,
+}
+
+impl Trend {
+    pub fn latest(&self) -> Duration {
+        self.timings
+            .last()
+            .map(Timing::duration)
+            .unwrap_or_default()
+    }
+
+    /// How much the least squares line through the timings rises from the first to the last, as a
+    /// fraction of their mean, so 0.2 has the run getting 20% slower. Fitting a line means creeping
+    /// up a little each time shows, where one slow run on its own doesn't count for much
+    pub fn drift(&self) -> f64 {
+        let times: Vec<_> = self
+            .timings
+            .iter()
+            .map(|timing| timing.duration().as_secs_f64())
+            .collect();
+        let count = times.len() as f64;
+        let mean_x = (count - 1.0) / 2.0;
+        let mean_time = times.iter().sum::<f64>() / count;
+        let (covariance, variance) =
+            times
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(covariance, variance), (x, time)| {
+                    let x = x as f64 - mean_x;
+                    (covariance + x * (time - mean_time), variance + x * x)
+                });
+        if variance == 0.0 || mean_time == 0.0 {
+            return 0.0;
+        }
+        covariance / variance * (count - 1.0) / mean_time
+    }
+
+    /// Drifted upwards by more than `threshold`, a fraction like [Trend::drift]
+    pub fn is_drifting(&self, threshold: f64) -> bool {
+        self.drift() > threshold
+    }
+
+    /// The commits the first and last timings were recorded at, where known
+    pub fn commits(&self) -> (Option<&str>, Option<&str>) {
+        let first = self
+            .timings
+            .first()
+            .and_then(|timing| timing.commit.as_deref());
+        let last = self
+            .timings
+            .last()
+            .and_then(|timing| timing.commit.as_deref());
+        (first, last)
+    }
+}
+
+/// Every timing recorded so far, oldest first
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History(Vec<Timing>);
+
+impl History {
+    /// Read the history from `path`, which is empty if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("failed to parse {} line {}", path.display(), index + 1)
+                })
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    /// Compare `timing` with the earlier ones of the same run, if there are any
+    pub fn compare(&self, timing: &Timing, threshold: f64) -> Option<Comparison> {
+        let earlier: Vec<_> = self
+            .0
+            .iter()
+            .filter(|earlier| earlier.same_run(timing))
+            .map(Timing::duration)
+            .collect();
+        Some(Comparison {
+            best: earlier.iter().copied().min()?,
+            latest: *earlier.last()?,
+            threshold,
+            duration: timing.duration(),
+        })
+    }
+
+    /// The trend over the last `runs` timings of everything that's been timed more than once, in
+    /// order of day and part
+    pub fn trends(&self, runs: usize) -> Vec<Trend> {
+        let mut runs_by_key: BTreeMap<_, Vec<&Timing>> = BTreeMap::new();
+        for timing in &self.0 {
+            let key = (
+                timing.day,
+                timing.part,
+                &timing.implementation,
+                &timing.input,
+            );
+            runs_by_key.entry(key).or_default().push(timing);
+        }
+        runs_by_key
+            .into_iter()
+            .filter(|(_, timings)| timings.len() > 1)
+            .map(|((day, part, implementation, input), timings)| Trend {
+                day,
+                part,
+                implementation: implementation.clone(),
+                input: input.clone(),
+                timings: timings[timings.len().saturating_sub(runs)..]
+                    .iter()
+                    .map(|&timing| timing.clone())
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Add the timings to the end of the history file at `path`, creating it if need be
+pub fn record_timings(path: &Path, timings: &[Timing]) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    for timing in timings {
+        writeln!(file, "{}", serde_json::to_string(timing)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timing(part: usize, millis: u64) -> Timing {
+        Timing {
+            day: 1,
+            part,
+            implementation: "default".to_string(),
+            input: PathBuf::from("inputs/d01.txt"),
+            time_ns: millis * 1_000_000,
+            at: "2023-12-01T06:12:45Z".to_string(),
+            commit: None,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = History(vec![
+            timing(1, 10),
+            timing(1, 8),
+            timing(1, 12),
+            timing(2, 1),
+        ]);
+
+        let comparison = history.compare(&timing(1, 15), 0.1).unwrap();
+        assert_eq!(comparison.best, Duration::from_millis(8));
+        assert_eq!(comparison.latest, Duration::from_millis(12));
+        assert!((comparison.vs_best() - 0.875).abs() < 1e-9);
+        assert!((comparison.vs_latest() - 0.25).abs() < 1e-9);
+        assert!(comparison.is_regression());
+
+        assert!(!history
+            .compare(&timing(1, 13), 0.1)
+            .unwrap()
+            .is_regression());
+        assert_eq!(history.compare(&timing(3, 1), 0.1), None);
+    }
+
+    #[test]
+    fn test_trends() {
+        let history = History(vec![
+            timing(1, 50),
+            timing(1, 10),
+            timing(2, 5),
+            timing(1, 11),
+            timing(1, 12),
+            timing(1, 13),
+        ]);
+
+        let trends = history.trends(4);
+        assert_eq!(trends.len(), 1);
+        let trend = &trends[0];
+        assert_eq!((trend.day, trend.part), (1, 1));
+        assert_eq!(trend.timings.len(), 4);
+        assert_eq!(trend.latest(), Duration::from_millis(13));
+        // The line goes from 10ms to 13ms, and the mean is 11.5ms
+        assert!((trend.drift() - 3.0 / 11.5).abs() < 1e-9);
+        assert!(trend.is_drifting(0.1));
+        assert!(!trend.is_drifting(0.3));
+
+        // The slow first run pulls the line the other way
+        let trend = &history.trends(5)[0];
+        assert!(trend.drift() < 0.0);
+        assert!(!trend.is_drifting(0.1));
+    }
+
+    #[test]
+    fn test_flat_trend() {
+        let history = History(vec![timing(1, 10), timing(1, 10), timing(1, 10)]);
+        assert_eq!(history.trends(10)[0].drift(), 0.0);
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        assert_eq!(History::load(&path).unwrap(), History::default());
+        record_timings(&path, &[timing(1, 10)]).unwrap();
+        record_timings(&path, &[timing(2, 20)]).unwrap();
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(history, History(vec![timing(1, 10), timing(2, 20)]));
+    }
+}
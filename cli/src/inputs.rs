@@ -0,0 +1,194 @@
+use std::ffi::OsString;
+use std::fs::{read, read_dir};
+use std::io::{stdin, Read};
+use std::path::{absolute, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use itertools::Itertools;
+
+use crate::config::config;
+use crate::fetch::fetch_input;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+/// What a compressed copy of an input might be called, after the usual `dXX.txt`
+const COMPRESSED_EXTENSIONS: [&str; 2] = ["gz", "zst"];
+
+pub fn default_input_path(day: usize) -> PathBuf {
+    config().input_path(day)
+}
+
+/// The input at `path`, or failing that a compressed copy of it, if there is either
+pub fn existing_input(path: &Path) -> Option<PathBuf> {
+    let compressed = COMPRESSED_EXTENSIONS.iter().map(|extension| {
+        let mut name = OsString::from(path.as_os_str());
+        name.push(format!(".{extension}"));
+        PathBuf::from(name)
+    });
+    [path.to_path_buf()]
+        .into_iter()
+        .chain(compressed)
+        .find(|path| path.exists())
+}
+
+/// Decompress the input if it's gzipped or zstd compressed. This goes by the first few bytes rather
+/// than the file name, so compressed input can be piped in too
+fn decompress(bytes: Vec<u8>) -> Result<String> {
+    let bytes = if bytes.starts_with(GZIP_MAGIC) {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decompressed)
+            .context("failed to decompress gzipped input")?;
+        decompressed
+    } else if bytes.starts_with(ZSTD_MAGIC) {
+        zstd::decode_all(bytes.as_slice()).context("failed to decompress zstd input")?
+    } else {
+        bytes
+    };
+    String::from_utf8(bytes).context("the input isn't valid UTF-8")
+}
+
+/// Read the input file at `path`, decompressing it if need be
+pub fn read_input(path: &Path) -> Result<String> {
+    read(path)
+        .map_err(anyhow::Error::from)
+        .and_then(decompress)
+        .with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Read the input for a day, downloading it first if the default input file doesn't exist yet
+pub fn load_input(day: usize, input_path: Option<PathBuf>) -> Result<(PathBuf, String)> {
+    if let Some(path) = input_path {
+        if path.as_os_str() == "-" {
+            let mut input = Vec::new();
+            return stdin()
+                .read_to_end(&mut input)
+                .map_err(anyhow::Error::from)
+                .and_then(|_| decompress(input))
+                .context("failed to read input from stdin")
+                .map(|input| (path, input));
+        }
+        let input = read_input(&path)?;
+        return Ok((path, input));
+    }
+
+    let path = default_input_path(day);
+    match existing_input(&path) {
+        Some(path) => {
+            let input = read_input(&path)?;
+            Ok((path, input))
+        }
+        None => {
+            let input =
+                fetch_input(day, &path).with_context(|| format!("{} not found", path.display()))?;
+            Ok((path, input))
+        }
+    }
+}
+
+/// The `.txt` files in `dir`, compressed or not, sorted by name
+fn available_inputs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| Some(path.file_name()?.to_string_lossy().into_owned()))
+        .filter(|name| {
+            name.ends_with(".txt")
+                || COMPRESSED_EXTENSIONS
+                    .iter()
+                    .any(|extension| name.ends_with(&format!(".txt.{extension}")))
+        })
+        .sorted()
+        .collect()
+}
+
+/// Some pointers on where the input for `day` was looked for and how to get it there, or `None`
+/// if it wasn't missing in the first place
+pub fn missing_input_help(day: usize, input_path: Option<&Path>) -> Option<String> {
+    let default_path = default_input_path(day);
+    let path = input_path.unwrap_or(&default_path);
+    if path.as_os_str() == "-" || existing_input(path).is_some() {
+        return None;
+    }
+
+    let resolved = absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let available = available_inputs(dir);
+    let mut help = vec![format!("looked for it at {}", resolved.display())];
+    if !dir.is_dir() {
+        help.push(format!("{} doesn't exist", dir.display()));
+    } else if available.is_empty() {
+        help.push(format!("there are no inputs in {}", dir.display()));
+    } else {
+        help.push(format!(
+            "inputs in {}: {}",
+            dir.display(),
+            available.join(", ")
+        ));
+    }
+    help.push(format!(
+        "run `cargo run -- fetch -d {day}` to download it to {}",
+        default_path.display()
+    ));
+    Some(help.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_available_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-available-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2022")).unwrap();
+        for file in ["d02.txt", "d01.txt", "d03.txt.gz", "notes.md"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+        assert_eq!(
+            available_inputs(&dir),
+            vec!["d01.txt", "d02.txt", "d03.txt.gz"]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(available_inputs(&dir).is_empty());
+    }
+
+    #[test]
+    fn test_read_compressed_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-compressed-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = "two1nine\neightwothree\n";
+
+        let mut gzipped = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        std::io::Write::write_all(&mut gzipped, input.as_bytes()).unwrap();
+        std::fs::write(dir.join("d01.txt.gz"), gzipped.finish().unwrap()).unwrap();
+        let zstd = zstd::encode_all(input.as_bytes(), 0).unwrap();
+        std::fs::write(dir.join("d02.txt.zst"), zstd).unwrap();
+        std::fs::write(dir.join("d03.txt"), input).unwrap();
+
+        for day in 1..=3 {
+            let path = existing_input(&dir.join(format!("d{day:0>2}.txt"))).unwrap();
+            assert_eq!(read_input(&path).unwrap(), input);
+        }
+        assert_eq!(existing_input(&dir.join("d04.txt")), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input_help() {
+        let missing = PathBuf::from("definitely/not/here.txt");
+        let help = missing_input_help(1, Some(&missing)).unwrap();
+        assert!(help.contains("definitely/not/here.txt"));
+        assert!(help.contains("definitely/not doesn't exist"));
+        assert!(help.contains("fetch -d 1"));
+        assert_eq!(missing_input_help(1, Some(Path::new("-"))), None);
+        assert_eq!(missing_input_help(1, Some(Path::new("Cargo.toml"))), None);
+    }
+}
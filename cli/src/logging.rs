@@ -0,0 +1,38 @@
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// What to log for each `-v`, anything set in `RUST_LOG` takes precedence
+fn default_filter(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Log to stderr, so it doesn't get mixed up with answers or machine readable output. Each span
+/// (a run at info, its parse and solve phases at debug) logs how long it took when it closes
+pub fn init_logging(verbosity: u8) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(default_filter(verbosity)));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(console::colors_enabled_stderr())
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_default_filter() {
+        assert_eq!(default_filter(0), "warn");
+        assert_eq!(default_filter(1), "info");
+        assert_eq!(default_filter(2), "debug");
+        assert_eq!(default_filter(5), "trace");
+    }
+}
@@ -0,0 +1,1100 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{stdin, stdout};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use structopt::StructOpt;
+
+use advent_of_code_2024::day03::{Day03, GearRule};
+use advent_of_code_2024::day08::Day08;
+use advent_of_code_2024::day10::Day10;
+use advent_of_code_2024::solver::Day;
+use advent_of_code_2024::{answer, config, error, examples, phases, plugin, registry, report};
+use advent_of_code_2024_viz::{chart, network, pipes, profile, progress, schematic, style};
+
+use crate::answer::Answer;
+use crate::answers::{save_answers, Answers};
+use crate::batch::run_batch;
+use crate::bench::{reject_outliers, sample, speedup, Stats};
+use crate::cache::{AnswerCache, CACHE_PATH, EXPENSIVE};
+use crate::cli::{
+    BenchOpt, ChartOpt, Command, CompareOpt, DoctorOpt, FetchOpt, GraphOpt, NewDayOpt, Opt, RunOpt,
+    TrendsOpt, VizOpt,
+};
+use crate::config::{config, set_config, Config};
+use crate::criterion::{export_benchmark, Baseline, Benchmark, Estimates};
+use crate::doctor::{check_answers, check_inputs, check_session, check_writable};
+use crate::examples::{examples, get_example};
+use crate::fetch::{check_logged_in, fetch_input};
+use crate::history::{record_timings, History, Timing};
+use crate::inputs::{
+    default_input_path, existing_input, load_input, missing_input_help, read_input,
+};
+use crate::logging::init_logging;
+use crate::memory::format_bytes;
+use crate::plugins::load_plugins;
+use crate::profile::Profiler;
+use crate::progress::progress_bar;
+use crate::registry::{
+    get_implementation, get_info, get_solver, implementations, solved, Implementation, Status,
+    DAYS, DEFAULT_IMPLEMENTATION,
+};
+use crate::report::{to_csv, Format, RunReport};
+use crate::runner::{time_parts, time_solver, SolveError, Solved};
+use crate::scaffold::new_day;
+
+mod answers;
+mod batch;
+mod bench;
+mod cache;
+mod cli;
+mod criterion;
+mod doctor;
+mod fetch;
+mod history;
+mod inputs;
+mod logging;
+mod memory;
+mod plugins;
+mod runner;
+mod scaffold;
+
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Read the input for a day, or explain where it was looked for and how to get it before exiting
+fn load_input_or_exit(day: usize, input_path: Option<PathBuf>) -> (PathBuf, String) {
+    match load_input(day, input_path.clone()) {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            if let Some(help) = missing_input_help(day, input_path.as_deref()) {
+                eprintln!("{}", style::error(format!("{error:#}")).for_stderr());
+                exit_with_error(help);
+            }
+            exit_with_error(format!("{error:#}"))
+        }
+    }
+}
+
+fn load_answers_or_exit(check: bool, path: &Path) -> Option<Answers> {
+    if !check {
+        return None;
+    }
+    match Answers::load(path) {
+        Ok(answers) => Some(answers),
+        Err(error) => exit_with_error(format!("{error:#}")),
+    }
+}
+
+/// Compare the timings with those from earlier runs, then add them to the history file
+fn record_history_or_exit(
+    opt: &RunOpt,
+    format: Format,
+    implementation: &str,
+    reports: &[RunReport],
+) {
+    let history = match History::load(&opt.history_file) {
+        Ok(history) => history,
+        Err(error) => exit_with_error(format!("{error:#}")),
+    };
+    let threshold = opt.regression_threshold / 100.0;
+    let timings: Vec<_> = reports
+        .iter()
+        .filter(|report| report.error.is_none())
+        .filter_map(|report| {
+            let duration = report.duration?;
+            Some(Timing::new(
+                report.day,
+                report.part,
+                implementation,
+                report.input.clone(),
+                duration,
+            ))
+        })
+        .collect();
+    for timing in &timings {
+        let Some(comparison) = history.compare(timing, threshold) else {
+            continue;
+        };
+        let line = format!(
+            "Day {} part {}: {:+.1}% on the best ({}), {:+.1}% on the last run ({})",
+            timing.day,
+            timing.part,
+            comparison.vs_best() * 100.0,
+            format_duration(comparison.best),
+            comparison.vs_latest() * 100.0,
+            format_duration(comparison.latest)
+        );
+        if comparison.is_regression() {
+            eprintln!(
+                "{}",
+                style::error(format!("{line}, a regression")).for_stderr()
+            );
+        } else if format == Format::Text {
+            println!("{line}");
+        }
+    }
+    if let Err(error) = record_timings(&opt.history_file, &timings) {
+        exit_with_error(format!("{error:#}"));
+    }
+}
+
+/// Record the answers from any successful runs with `--save`. Messages go to stderr, so as not to get
+/// mixed up with JSON or CSV output
+fn save_answers_or_exit(path: &Path, reports: &[RunReport]) {
+    let answers: Vec<_> = reports
+        .iter()
+        .filter_map(|report| Some((report.day, report.part, report.answer.as_ref()?.to_string())))
+        .collect();
+    let conflicts = match save_answers(path, &answers) {
+        Ok(conflicts) => conflicts,
+        Err(error) => exit_with_error(format!("{error:#}")),
+    };
+    for conflict in &conflicts {
+        eprintln!(
+            "{}",
+            style::error(format!(
+                "Not saving {} for day {} part {}, {} already has {}",
+                conflict.answer,
+                conflict.day,
+                conflict.part,
+                path.display(),
+                conflict.stored
+            ))
+            .for_stderr()
+        );
+    }
+    let saved = answers.len() - conflicts.len();
+    if saved > 0 {
+        eprintln!("Saved {saved} answer(s) to {}", path.display());
+    }
+}
+
+/// Print the error to stderr in red and exit with a failure code
+fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("{}", style::error(error).for_stderr());
+    exit(1);
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let sub_millis = duration.subsec_millis();
+    let sub_micros = duration.subsec_micros() - (sub_millis * 1000);
+    let sub_nanos = (duration.subsec_nanos() - (sub_millis * 1_000_000)) - (sub_micros * 1000);
+    format!("{seconds}s {sub_millis}ms {sub_micros}µs {sub_nanos}ns")
+}
+
+/// The format asked for on the command line, or in the config file, or text
+fn output_format(opt: &RunOpt) -> Format {
+    if opt.raw {
+        return Format::Raw;
+    }
+    opt.format.or(config().format).unwrap_or(Format::Text)
+}
+
+fn run_one(opt: RunOpt, timeout: Option<Duration>) {
+    let day = opt.day.expect("day is required");
+    let part = opt.part.expect("part is required");
+    let format = output_format(&opt);
+
+    let example = if opt.example {
+        let Some(example) = get_example(day, part) else {
+            exit_with_error(format!("There is no example for day {day} part {part}"));
+        };
+        Some(example)
+    } else {
+        None
+    };
+
+    if opt.watch
+        && opt
+            .input
+            .as_ref()
+            .is_some_and(|path| path.as_os_str() == "-")
+    {
+        exit_with_error("Can't watch stdin for changes, pass an input file instead");
+    }
+    let solver = get_implementation_or_exit(day, part, opt.implementation.as_deref());
+    if opt.example_first {
+        match check_example(solver, timeout) {
+            Ok(true) => eprintln!(
+                "{}",
+                style::correct(
+                    format!("Example for day {day} part {part} is correct"),
+                    Some(true)
+                )
+                .for_stderr()
+            ),
+            Ok(false) => {}
+            Err(error) => exit_with_error(error),
+        }
+    }
+    let (input_path, input) = match example {
+        Some(example) => (PathBuf::from(example.path), example.input.to_string()),
+        None => load_input_or_exit(day, opt.input.clone()),
+    };
+
+    let answers = load_answers_or_exit(opt.check, &opt.answers);
+    let checked = answers.is_some() || example.is_some();
+    let expected = match (&answers, example) {
+        (Some(answers), _) => answers.get(day, part),
+        (None, Some(example)) => Some(example.answer.to_string()),
+        (None, None) => None,
+    };
+
+    let solve = |input: &str| {
+        let outcome = time_solver(solver, input, timeout, true);
+        let report = to_report(day, part, input_path.clone(), outcome);
+        let report = if checked {
+            report.check_against(expected.clone())
+        } else {
+            report
+        };
+        print_run(format, &report, checked);
+        if opt.save {
+            save_answers_or_exit(&opt.answers, std::slice::from_ref(&report));
+        }
+        if opt.history {
+            record_history_or_exit(&opt, format, solver.name, std::slice::from_ref(&report));
+        }
+        report
+    };
+
+    let profiling = start_profiling(opt.profile.as_deref());
+    let report = solve(&input);
+    finish_profiling(profiling);
+    if !opt.watch {
+        if report.error.is_some() || report.is_incorrect() {
+            exit(1);
+        }
+        return;
+    }
+
+    loop {
+        wait_for_change(&input_path);
+        match read_input(&input_path) {
+            Ok(input) => {
+                println!();
+                solve(&input);
+            }
+            Err(error) => eprintln!("{}", style::error(format!("{error:#}")).for_stderr()),
+        }
+    }
+}
+
+/// Start sampling the solver if asked to with `--profile`
+fn start_profiling(path: Option<&Path>) -> Option<(Profiler, &Path)> {
+    let path = path?;
+    match Profiler::start() {
+        Ok(profiler) => Some((profiler, path)),
+        Err(error) => exit_with_error(format!("{error:#}")),
+    }
+}
+
+fn finish_profiling(profiling: Option<(Profiler, &Path)>) {
+    let Some((profiler, path)) = profiling else {
+        return;
+    };
+    match profiler.write(path) {
+        // stderr, so as not to get mixed up with JSON or CSV output
+        Ok(()) => eprintln!("Wrote profile to {}", path.display()),
+        Err(error) => exit_with_error(format!("{error:#}")),
+    }
+}
+
+fn get_implementation_or_exit(
+    day: usize,
+    part: usize,
+    name: Option<&str>,
+) -> &'static Implementation {
+    let name = name.unwrap_or(DEFAULT_IMPLEMENTATION);
+    if let Some(solver) = get_implementation(day, part, name) {
+        return solver;
+    }
+    let names = implementations(day, part)
+        .map(|solver| solver.name)
+        .join(", ");
+    if names.is_empty() {
+        exit_with_error(format!("Day {day} part {part} not found"));
+    }
+    exit_with_error(format!(
+        "Day {day} part {part} has no implementation called {name}, try one of: {names}"
+    ))
+}
+
+fn to_report(
+    day: usize,
+    part: usize,
+    input_path: PathBuf,
+    outcome: Result<Solved, SolveError>,
+) -> RunReport {
+    match outcome {
+        Ok(solved) => RunReport::solved(day, part, input_path, solved.answer, solved.duration)
+            .with_phases(solved.phases)
+            .with_peak_memory(solved.peak_memory),
+        Err(error) => RunReport::failed(day, part, input_path, error),
+    }
+}
+
+/// Solve each of the day and part's examples, returning whether there were any to check. It's an
+/// error if an answer's wrong, so a broken solver isn't left to churn through the real input
+fn check_example(
+    solver: &'static Implementation,
+    timeout: Option<Duration>,
+) -> Result<bool, String> {
+    let (day, part) = (solver.day, solver.part);
+    let mut checked = false;
+    for example in examples(day, part) {
+        match time_solver(solver, example.input, timeout, false) {
+            Ok(solved) => match solved.answer.compare(example.answer) {
+                Ok(()) => checked = true,
+                Err(mismatch) => {
+                    return Err(format!(
+                        "Example {} for day {day} part {part} {mismatch}",
+                        example.path
+                    ))
+                }
+            },
+            Err(error) => {
+                return Err(format!(
+                    "Example {} for day {day} part {part} {error}",
+                    example.path
+                ))
+            }
+        }
+    }
+    Ok(checked)
+}
+
+/// Print which solved days and parts get their examples right, exiting non-zero if any don't
+fn run_check_examples(timeout: Option<Duration>) {
+    let mut failures = vec![];
+    let mut check = |day, part| {
+        let cell = |text| format!("{text:<10}");
+        let solver = get_solver(day, part).filter(|solver| solver.status == Status::Solved);
+        match solver.map(|solver| check_example(solver, timeout)) {
+            Some(Ok(true)) => style::correct(cell("pass"), Some(true)).to_string(),
+            Some(Err(error)) => {
+                failures.push(error);
+                style::correct(cell("fail"), Some(false)).to_string()
+            }
+            Some(Ok(false)) | None => style::correct(cell("-"), None).to_string(),
+        }
+    };
+    println!("Day  Part 1      Part 2");
+    for day in 1..=25 {
+        let part1 = check(day, 1);
+        let part2 = check(day, 2);
+        println!("{}", format!("{day:>3}  {part1}  {part2}").trim_end());
+    }
+    for failure in &failures {
+        eprintln!("{}", style::error(failure).for_stderr());
+    }
+    if !failures.is_empty() {
+        exit(1);
+    }
+}
+
+/// Block until the file's modified time changes. Polling is plenty for a file edited by hand
+fn wait_for_change(path: &Path) {
+    let modified = || {
+        path.metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    };
+    let last_modified = modified();
+    while modified() == last_modified {
+        sleep(WATCH_INTERVAL);
+    }
+}
+
+fn print_run(format: Format, report: &RunReport, checked: bool) {
+    let (day, part) = (report.day, report.part);
+    match (format, &report.error) {
+        (Format::Text | Format::Raw, Some(error)) => eprintln!(
+            "{}",
+            style::error(format!("Day {day} part {part} {error}")).for_stderr()
+        ),
+        (Format::Text, None) => {
+            let duration = report.duration.unwrap_or_default();
+            println!("Answer for day {day} part {part} is:");
+            println!(
+                "{}",
+                style::answer(
+                    report
+                        .answer
+                        .as_ref()
+                        .map(Answer::to_string)
+                        .unwrap_or_default()
+                )
+            );
+            print!(
+                "Time taken: {}",
+                style::timing(format_duration(duration), duration)
+            );
+            match report.parse_duration.zip(report.solve_duration) {
+                Some((parse, solve)) => println!(
+                    " (parsed in {}, solved in {})",
+                    format_duration(parse),
+                    format_duration(solve)
+                ),
+                None => println!(),
+            }
+            if let Some(peak_memory) = report.peak_memory {
+                println!("Peak memory: {}", format_bytes(peak_memory));
+            }
+            if checked {
+                println!("{}", style::correct(check_status(report), report.correct));
+            }
+        }
+        (Format::Raw, None) => println!(
+            "{}",
+            report
+                .answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default()
+        ),
+        (Format::Json | Format::Csv, _) => print_report(format, report),
+    }
+}
+
+/// Print the report of a single run in a machine readable format
+fn print_report(format: Format, report: &RunReport) {
+    match format {
+        Format::Text | Format::Raw => unreachable!("text output is printed as it's run"),
+        Format::Json => println!("{}", serde_json::to_string(report).unwrap()),
+        Format::Csv => println!("{}", to_csv(std::slice::from_ref(report))),
+    }
+}
+
+fn check_status(report: &RunReport) -> String {
+    match (report.correct, &report.expected) {
+        (Some(true), _) => "Correct".to_string(),
+        (Some(false), Some(expected)) => match report.mismatch() {
+            Some(mismatch) => format!("Incorrect, {mismatch}"),
+            None => format!("Incorrect, expected {expected}"),
+        },
+        (_, None) => "No stored answer".to_string(),
+        (None, Some(_)) => "Not checked".to_string(),
+    }
+}
+
+fn run_bench(opt: BenchOpt, timeout: Option<Duration>) {
+    let BenchOpt {
+        input,
+        day,
+        part,
+        iterations,
+        warmup,
+        implementation,
+        keep_outliers,
+        profile,
+        export,
+        baseline,
+    } = opt;
+    // The input is read into memory once, up front, so the disk is never part of the timings
+    let (_, input) = load_input_or_exit(day, input);
+    let solver = get_implementation_or_exit(day, part, implementation.as_deref());
+    let solution = solver.solve;
+
+    // The first warm-up run also checks the solver finishes within the timeout before committing
+    // to many more
+    let result = match time_solver(solver, &input, timeout, true) {
+        Ok(solved) => solved.answer,
+        Err(error) => exit_with_error(format!("Day {day} part {part} {error}")),
+    };
+    let profiling = start_profiling(profile.as_deref());
+    let samples = sample(solution, &input, warmup.saturating_sub(1), iterations);
+    finish_profiling(profiling);
+    let (samples, outliers) = if keep_outliers {
+        (samples, vec![])
+    } else {
+        reject_outliers(&samples)
+    };
+    let Some(stats) = Stats::from_samples(&samples) else {
+        exit_with_error("At least one iteration is required to benchmark");
+    };
+    let timing = |duration| style::timing(format_duration(duration), duration);
+
+    println!("Answer for day {day} part {part} is:");
+    println!("{}", style::answer(result));
+    println!("Benchmarked {iterations} iterations:");
+    if !outliers.is_empty() {
+        println!("  ({} outliers rejected)", outliers.len());
+    }
+    println!("  min:     {}", timing(stats.min));
+    println!("  mean:    {}", timing(stats.mean));
+    println!("  median:  {}", timing(stats.median));
+    println!("  max:     {}", timing(stats.max));
+    println!("  std dev: {}", format_duration(stats.std_dev));
+
+    if let Some(path) = export {
+        let estimates = Estimates::from_samples(&samples).expect("there are samples");
+        let benchmark = Benchmark::new(&baseline, day, part, solver.name, estimates);
+        if let Err(error) = export_benchmark(&path, benchmark) {
+            exit_with_error(format!("{error:#}"));
+        }
+        println!("Exported to {} as {baseline}", path.display());
+    }
+}
+
+fn run_compare(opt: CompareOpt, timeout: Option<Duration>) {
+    let CompareOpt {
+        input,
+        day,
+        part,
+        impl_a,
+        impl_b,
+        iterations,
+        warmup,
+    } = opt;
+    let (_, input) = load_input_or_exit(day, input);
+    let solvers = [
+        get_implementation_or_exit(day, part, impl_a.as_deref()),
+        get_implementation_or_exit(day, part, Some(&impl_b)),
+    ];
+
+    let answers = solvers.map(|solver| match time_solver(solver, &input, timeout, true) {
+        Ok(solved) => solved.answer.without_stats(),
+        Err(error) => exit_with_error(format!("Day {day} part {part} {} {error}", solver.name)),
+    });
+    let [(a, answer_a), (b, answer_b)] = [0, 1].map(|i| (solvers[i].name, &answers[i]));
+    if answer_a != answer_b {
+        exit_with_error(format!(
+            "Day {day} part {part} implementations disagree, {a} gives {answer_a} but {b} gives {answer_b}"
+        ));
+    }
+
+    let medians = solvers.map(|solver| {
+        let samples = sample(solver.solve, &input, warmup, iterations);
+        let (samples, _) = reject_outliers(&samples);
+        match Stats::from_samples(&samples) {
+            Some(stats) => stats.median,
+            None => exit_with_error("At least one iteration is required to compare"),
+        }
+    });
+    let timing = |duration| style::timing(format_duration(duration), duration);
+    let name_width = a.len().max(b.len());
+
+    println!("Answer for day {day} part {part} is:");
+    println!("{}", style::answer(answer_a));
+    println!(
+        "{}",
+        style::correct("Both implementations agree", Some(true))
+    );
+    println!("Median of {iterations} iterations:");
+    println!("  {a:<name_width$}  {}", timing(medians[0]));
+    println!("  {b:<name_width$}  {}", timing(medians[1]));
+    let speedup = speedup(medians[0], medians[1]);
+    if speedup >= 1.0 {
+        println!("{b} is {speedup:.2}x faster than {a}");
+    } else {
+        println!("{b} is {:.2}x slower than {a}", 1.0 / speedup);
+    }
+}
+
+fn run_all(opt: RunOpt, timeout: Option<Duration>) {
+    let format = output_format(&opt);
+    let answers = load_answers_or_exit(opt.check, &opt.answers);
+    let solvers: Vec<_> = solved().collect();
+
+    if opt.example_first {
+        let errors: Vec<_> = solvers
+            .iter()
+            .filter_map(|solver| check_example(solver, timeout).err())
+            .collect();
+        for error in &errors {
+            eprintln!("{}", style::error(error).for_stderr());
+        }
+        if !errors.is_empty() {
+            exit(1);
+        }
+    }
+
+    // Read (or download) every input first so it doesn't happen once per part or count towards timings
+    let inputs: HashMap<_, _> = solvers
+        .iter()
+        .map(|solver| solver.day)
+        .dedup()
+        .map(|day| {
+            (
+                day,
+                load_input(day, None).map_err(|error| format!("{error:#}")),
+            )
+        })
+        .collect();
+
+    let cache_path = Path::new(CACHE_PATH);
+    let mut cache = match AnswerCache::load(cache_path) {
+        Ok(cache) => cache,
+        Err(error) => exit_with_error(format!("{error:#}")),
+    };
+
+    let bar = progress_bar();
+    bar.set_length(solvers.len() as u64);
+    // Both parts of a day are solved together, so its input is only parsed once
+    let days: Vec<_> = solvers.iter().map(|solver| solver.day).dedup().collect();
+    let run = |day: &usize| {
+        let day = *day;
+        let parts: Vec<_> = solvers
+            .iter()
+            .filter(|solver| solver.day == day)
+            .map(|solver| solver.part)
+            .collect();
+        let reports: Vec<_> = match &inputs[&day] {
+            Err(error) => parts
+                .iter()
+                .map(|&part| RunReport::failed(day, part, default_input_path(day), error))
+                .collect(),
+            Ok((input_path, input)) => {
+                let params = config().day_params(day);
+                let cached = |part| {
+                    let solver = solvers
+                        .iter()
+                        .find(|solver| solver.day == day && solver.part == part);
+                    cache.get(solver?, input, params).filter(|_| !opt.force)
+                };
+                let to_solve: Vec<_> = parts
+                    .iter()
+                    .copied()
+                    .filter(|&part| cached(part).is_none())
+                    .collect();
+                let mut outcomes = time_parts(day, &to_solve, input, timeout).into_iter();
+                parts
+                    .iter()
+                    .map(|&part| match cached(part) {
+                        Some(answer) => {
+                            RunReport::cached(day, part, input_path.clone(), answer.clone())
+                        }
+                        None => {
+                            let outcome = outcomes.next().expect("an outcome for each part solved");
+                            let report = to_report(day, part, input_path.clone(), outcome);
+                            if opt.sequential {
+                                report
+                            } else {
+                                // Allocations from solvers running at the same time can't be told apart
+                                report.with_peak_memory(None)
+                            }
+                        }
+                    })
+                    .collect()
+            }
+        };
+        bar.inc(reports.len() as u64);
+        reports
+            .into_iter()
+            .map(|report| match &answers {
+                Some(answers) => {
+                    let expected = answers.get(day, report.part);
+                    report.check_against(expected)
+                }
+                None => report,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let start = Instant::now();
+    let reports: Vec<_> = if opt.sequential {
+        days.iter().flat_map(run).collect()
+    } else {
+        days.par_iter().flat_map_iter(run).collect()
+    };
+    let wall_time = start.elapsed();
+    bar.finish_and_clear();
+
+    let expensive = reports.iter().filter(|report| {
+        report
+            .duration
+            .is_some_and(|duration| duration >= EXPENSIVE)
+            && !report.is_incorrect()
+    });
+    for report in expensive {
+        let solver = solvers
+            .iter()
+            .find(|solver| solver.day == report.day && solver.part == report.part);
+        if let (Some(solver), Some(answer), Ok((_, input))) =
+            (solver, &report.answer, &inputs[&report.day])
+        {
+            let params = config().day_params(report.day);
+            cache.insert(solver, input, params, answer.clone());
+        }
+    }
+    if let Err(error) = cache.save(cache_path) {
+        exit_with_error(format!("{error:#}"));
+    }
+
+    match format {
+        Format::Text => print_summary_table(&reports, answers.is_some(), wall_time),
+        Format::Json => println!("{}", serde_json::to_string(&reports).unwrap()),
+        Format::Csv => println!("{}", to_csv(&reports)),
+        Format::Raw => {
+            for report in &reports {
+                print_run(format, report, false);
+            }
+        }
+    }
+    if opt.save {
+        save_answers_or_exit(&opt.answers, &reports);
+    }
+    if opt.history {
+        record_history_or_exit(&opt, format, DEFAULT_IMPLEMENTATION, &reports);
+    }
+
+    if reports.iter().any(RunReport::is_incorrect) {
+        exit(1);
+    }
+}
+
+fn print_summary_table(reports: &[RunReport], checked: bool, wall_time: Duration) {
+    let answer_text = |report: &RunReport| {
+        report
+            .answer
+            .as_ref()
+            .map(Answer::to_string)
+            .or_else(|| report.error.clone())
+            .unwrap_or_default()
+    };
+    let time_text = |report: &RunReport| match report.duration {
+        Some(duration) => format_duration(duration),
+        None if report.cached => "cached".to_string(),
+        None => String::new(),
+    };
+    let memory_text = |report: &RunReport| report.peak_memory.map(format_bytes).unwrap_or_default();
+    let show_memory = reports.iter().any(|report| report.peak_memory.is_some());
+
+    let answer_width = reports
+        .iter()
+        .map(|report| answer_text(report).chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+    let time_width = reports
+        .iter()
+        .map(|report| time_text(report).chars().count())
+        .chain(["Time".len()])
+        .max()
+        .unwrap_or_default();
+    let memory_width = reports
+        .iter()
+        .map(|report| memory_text(report).chars().count())
+        .chain(["Memory".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!();
+    let mut header = format!(
+        "Day  Part  {:<answer_width$}  {:<time_width$}",
+        "Answer", "Time"
+    );
+    if show_memory {
+        header += &format!("  {:<memory_width$}", "Memory");
+    }
+    if checked {
+        header += "  Check";
+    }
+    println!("{}", header.trim_end());
+    for report in reports {
+        // Pad before styling so the escape codes don't throw the columns out
+        let answer = format!("{:<answer_width$}", answer_text(report));
+        let answer = if report.answer.is_some() {
+            style::answer(answer)
+        } else {
+            style::error(answer)
+        };
+        let mut row = format!("{:>3}  {:>4}  {answer}  ", report.day, report.part);
+        if let Some(duration) = report.duration {
+            let time = format!("{:<time_width$}", time_text(report));
+            row += &style::timing(time, duration).to_string();
+        } else {
+            let time = format!("{:<time_width$}", time_text(report));
+            row += &style::correct(time, None).to_string();
+        }
+        if show_memory {
+            row += &format!("  {:<memory_width$}", memory_text(report));
+        }
+        if checked {
+            let check = style::correct(check_status(report), report.correct);
+            row += &format!("  {check}");
+        }
+        println!("{}", row.trim_end());
+    }
+    let total: Duration = reports.iter().filter_map(|report| report.duration).sum();
+    println!(
+        "Total time: {} (wall time {} on {} threads)",
+        style::timing(format_duration(total), total),
+        format_duration(wall_time),
+        rayon::current_num_threads()
+    );
+}
+
+fn run_viz(opt: VizOpt) {
+    let (_, input) = load_input_or_exit(opt.day, opt.input);
+    let drawing = match opt.day {
+        3 => {
+            let marks = match Day03::parse(&input) {
+                Ok(grid) => grid.marks(&GearRule::default()),
+                Err(error) => exit_with_error(format!("Day 3 failed: {error}")),
+            };
+            if opt.html {
+                schematic::schematic_html(&input, &marks)
+            } else {
+                schematic::schematic_ansi(&input, &marks)
+            }
+        }
+        10 => {
+            let tiles = match Day10::parse(&input).and_then(|pipe_map| pipe_map.tiles()) {
+                Ok(tiles) => tiles,
+                Err(error) => exit_with_error(format!("Day 10 failed: {error}")),
+            };
+            if opt.html {
+                pipes::pipes_html(&tiles)
+            } else {
+                pipes::pipes_ansi(&tiles)
+            }
+        }
+        day => exit_with_error(format!("Day {day} has nothing to draw")),
+    };
+    println!("{drawing}");
+}
+
+fn run_graph(opt: GraphOpt) {
+    let (_, input) = load_input_or_exit(opt.day, opt.input);
+    let graph = match opt.day {
+        8 => match Day08::parse(&input) {
+            Ok((_, network)) => network::network_dot(network.turnings()),
+            Err(error) => exit_with_error(format!("Day 8 failed: {error}")),
+        },
+        day => exit_with_error(format!("Day {day} has no graph to write")),
+    };
+    println!("{graph}");
+}
+
+fn run_chart(opt: ChartOpt) {
+    let baseline = match Baseline::load(&opt.benchmarks) {
+        Ok(baseline) => baseline,
+        Err(error) => exit_with_error(format!("{error:#}")),
+    };
+    let title = format!("Median times ({})", baseline.name);
+    match chart::runtimes_svg(&title, &baseline.runtimes()) {
+        Ok(svg) => println!("{svg}"),
+        Err(error) => exit_with_error(format!(
+            "Couldn't chart {}: {error:#}",
+            opt.benchmarks.display()
+        )),
+    }
+}
+
+fn run_trends(opt: TrendsOpt) {
+    if opt.runs < 2 {
+        exit_with_error("--runs needs to be at least 2 to have a trend");
+    }
+    let history = match History::load(&opt.history_file) {
+        Ok(history) => history,
+        Err(error) => exit_with_error(format!("{error:#}")),
+    };
+    let trends: Vec<_> = history
+        .trends(opt.runs)
+        .into_iter()
+        .filter(|trend| opt.day.is_none_or(|day| trend.day == day))
+        .collect();
+    if trends.is_empty() {
+        println!(
+            "Nothing in {} has been timed more than once yet, record timings with `run --history`",
+            opt.history_file.display()
+        );
+        return;
+    }
+
+    let threshold = opt.threshold / 100.0;
+    let implementation_width = trends
+        .iter()
+        .map(|trend| trend.implementation.chars().count())
+        .chain(["Implementation".len()])
+        .max()
+        .unwrap_or_default();
+    let latest_width = trends
+        .iter()
+        .map(|trend| format_duration(trend.latest()).chars().count())
+        .chain(["Latest".len()])
+        .max()
+        .unwrap_or_default();
+    println!(
+        "Day  Part  {:<implementation_width$}  Runs  {:<latest_width$}   Drift  Commits",
+        "Implementation", "Latest"
+    );
+    for trend in &trends {
+        let commits = match trend.commits() {
+            (Some(first), Some(last)) if first != last => format!("{first}..{last}"),
+            (_, Some(last)) => last.to_string(),
+            _ => String::new(),
+        };
+        let row = format!(
+            "{:>3}  {:>4}  {:<implementation_width$}  {:>4}  {:<latest_width$}  {:>+6.1}%  {commits}",
+            trend.day,
+            trend.part,
+            trend.implementation,
+            trend.timings.len(),
+            format_duration(trend.latest()),
+            trend.drift() * 100.0
+        );
+        let row = row.trim_end();
+        if trend.is_drifting(threshold) {
+            println!("{}", style::error(format!("{row}, creeping up")));
+        } else {
+            println!("{row}");
+        }
+    }
+    if trends.iter().any(|trend| trend.is_drifting(threshold)) {
+        exit(1);
+    }
+}
+
+fn run_fetch(opt: FetchOpt) {
+    let path = default_input_path(opt.day);
+    if let Err(error) = fetch_input(opt.day, &path) {
+        exit_with_error(format!("{error:#}"));
+    }
+    println!("Saved input for day {} to {}", opt.day, path.display());
+}
+
+fn run_list() {
+    let title_width = DAYS
+        .iter()
+        .map(|registration| registration.info.title.chars().count())
+        .chain(["Title".len()])
+        .max()
+        .unwrap_or_default();
+    println!(
+        "Day  {:<title_width$}  Part 1      Part 2      Input",
+        "Title"
+    );
+    for day in 1..=25 {
+        let title = get_info(day).map(|info| info.title).unwrap_or_default();
+        let status = |part| {
+            get_solver(day, part)
+                .map(|solver| style::status(format!("{:<10}", solver.status), solver.status))
+                .map(|status| status.to_string())
+                .unwrap_or_else(|| " ".repeat(10))
+        };
+        let input = match existing_input(&default_input_path(day)) {
+            Some(input_path) => input_path.display().to_string(),
+            None => style::error("missing").to_string(),
+        };
+        println!(
+            "{day:>3}  {title:<title_width$}  {}  {}  {input}",
+            status(1),
+            status(2)
+        );
+    }
+}
+
+fn run_doctor(opt: DoctorOpt) {
+    let solved: Vec<_> = solved().map(|solver| (solver.day, solver.part)).collect();
+    let inputs: Vec<_> = solved
+        .iter()
+        .map(|(day, _)| *day)
+        .dedup()
+        .map(|day| (day, default_input_path(day)))
+        .collect();
+    let days: Vec<_> = (1..=25).filter(|&day| get_info(day).is_some()).collect();
+    let checks = [
+        check_inputs(&inputs),
+        check_session(check_logged_in()),
+        check_answers(&opt.answers, &days, &solved),
+        check_writable("Answer cache", Path::new(CACHE_PATH)),
+        check_writable("History", &opt.history_file),
+    ];
+    for check in &checks {
+        if check.is_fine() {
+            println!(
+                "{} {}: {}",
+                style::correct("ok", Some(true)),
+                check.name,
+                check.found
+            );
+        } else {
+            println!(
+                "{}",
+                style::error(format!("!! {}: {}", check.name, check.found))
+            );
+            for fix in &check.fixes {
+                println!("   - {fix}");
+            }
+        }
+    }
+    if !checks.iter().all(|check| check.is_fine()) {
+        exit(1);
+    }
+}
+
+fn run_new_day(opt: NewDayOpt) {
+    match new_day(opt.day) {
+        Ok(path) => println!("Created {}", path.display()),
+        Err(error) => exit_with_error(format!("{error:#}")),
+    }
+}
+
+fn main() {
+    let opt = Opt::from_args();
+    match Config::load(&opt.config) {
+        Ok(mut config) => {
+            if let Some(input_dir) = opt.input_dir.clone() {
+                config.inputs = input_dir;
+            }
+            if let Some(year) = opt.year {
+                config.year = year;
+            }
+            for param in opt.params.iter().cloned() {
+                config.set_day_param(param);
+            }
+            set_config(config)
+        }
+        Err(error) => exit_with_error(format!("{error:#}")),
+    }
+    if let Some(threads) = opt.threads.or(config().threads) {
+        if threads == 0 {
+            exit_with_error("--threads needs to be at least 1");
+        }
+        ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("the global thread pool should only be built once");
+    }
+    if opt.no_color {
+        style::disable_colors();
+    }
+    init_logging(opt.verbose);
+    if let Err(error) = load_plugins(&opt.plugins) {
+        exit_with_error(format!("{error:#}"));
+    }
+    let timeout = opt.timeout.map(|seconds| {
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or_else(|_| exit_with_error("The timeout must be a positive number of seconds"))
+    });
+    match opt.command {
+        Command::Run(opt) if opt.check_examples => run_check_examples(timeout),
+        Command::Run(opt) if opt.all => run_all(opt, timeout),
+        Command::Run(opt) => run_one(opt, timeout),
+        Command::Bench(opt) => run_bench(opt, timeout),
+        Command::Compare(opt) => run_compare(opt, timeout),
+        Command::Chart(opt) => run_chart(opt),
+        Command::Trends(opt) => run_trends(opt),
+        Command::Batch => {
+            if let Err(error) = run_batch(stdin().lock(), stdout().lock(), timeout) {
+                exit_with_error(format!("{error:#}"));
+            }
+        }
+        Command::Fetch(opt) => run_fetch(opt),
+        Command::List => run_list(),
+        Command::Doctor(opt) => run_doctor(opt),
+        Command::NewDay(opt) => run_new_day(opt),
+        Command::Viz(opt) => run_viz(opt),
+        Command::Graph(opt) => run_graph(opt),
+    }
+}
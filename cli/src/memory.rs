@@ -0,0 +1,124 @@
+//! Peak heap usage of a solver. Counting every allocation slows everything else down a little, so
+//! it's only done when built with `--features memory`
+
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    pub static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+    pub static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    /// Hands everything on to the system allocator, keeping count of how much is in use
+    pub struct CountingAllocator;
+
+    fn grow(by: usize) {
+        let now = ALLOCATED.fetch_add(by, Relaxed) + by;
+        PEAK.fetch_max(now, Relaxed);
+    }
+
+    fn shrink(by: usize) {
+        ALLOCATED.fetch_sub(by, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            shrink(layout.size());
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                if new_size > layout.size() {
+                    grow(new_size - layout.size());
+                } else {
+                    shrink(layout.size() - new_size);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+}
+
+/// Run `f`, also returning the most heap it had allocated at once, on top of whatever was already
+/// allocated when it started. The count is process wide, so anything else allocating at the same
+/// time (eg another solver in parallel) is included too
+#[cfg(feature = "memory")]
+pub fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    use std::sync::atomic::Ordering::Relaxed;
+
+    let start = counting::ALLOCATED.load(Relaxed);
+    counting::PEAK.store(start, Relaxed);
+    let result = f();
+    let peak = counting::PEAK.load(Relaxed).saturating_sub(start);
+    (result, Some(peak))
+}
+
+/// Without the `memory` feature there's nothing counting allocations, so no peak to report
+#[cfg(not(feature = "memory"))]
+pub fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    (f(), None)
+}
+
+/// Bytes in the biggest binary unit that keeps the number above 1, eg `1.50 MiB`
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(1536 * 1024), "1.50 MiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.00 GiB");
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn test_measure_peak() {
+        let (length, peak) = measure_peak(|| vec![0u8; 1024 * 1024].len());
+        assert_eq!(length, 1024 * 1024);
+        assert!(peak.unwrap() >= 1024 * 1024);
+    }
+
+    #[cfg(not(feature = "memory"))]
+    #[test]
+    fn test_measure_peak_disabled() {
+        assert_eq!(measure_peak(|| 42), (42, None));
+    }
+}
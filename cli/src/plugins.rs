@@ -0,0 +1,37 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use libloading::{Library, Symbol};
+
+use crate::plugin::{plugin_implementations, PluginEntryPoint, PLUGIN_ENTRY_POINT};
+use crate::registry::{add_plugins, Implementation};
+
+/// Every solver in the library at `path`. The library is never unloaded, its solvers are run until
+/// the process exits
+fn load_plugin(path: &Path) -> Result<Vec<Implementation>> {
+    // Loading runs the library's initialisers and calling it trusts it to follow the plugin ABI,
+    // there's no checking either, it's only for libraries built with `plugin!`
+    unsafe {
+        let library = Library::new(path)?;
+        let entry_point: Symbol<PluginEntryPoint> = library
+            .get(PLUGIN_ENTRY_POINT.as_bytes())
+            .context("it doesn't export any solvers, was it built with plugin!?")?;
+        let implementations = plugin_implementations(*entry_point)?;
+        std::mem::forget(library);
+        Ok(implementations)
+    }
+}
+
+/// Load the solvers from each plugin, so they can be picked with `--impl` like the built in ones
+pub fn load_plugins(paths: &[PathBuf]) -> Result<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+    let mut implementations = vec![];
+    for path in paths {
+        let loaded =
+            load_plugin(path).with_context(|| format!("Couldn't load {}", path.display()))?;
+        implementations.extend(loaded);
+    }
+    Ok(add_plugins(implementations)?)
+}
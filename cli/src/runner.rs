@@ -0,0 +1,450 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2024::progress::{CancelToken, Cancellable, NoProgress, Progress};
+use advent_of_code_2024::solver::Solver;
+use thiserror::Error;
+use tracing::info_span;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::format_duration;
+use crate::memory::measure_peak;
+use crate::phases::PhaseTimes;
+use crate::progress::{progress_bar, ProgressBar};
+use crate::registry::{get_day, Implementation, Solve, DEFAULT_IMPLEMENTATION};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SolveError {
+    #[error("timed out after {}", format_duration(*.0))]
+    TimedOut(Duration),
+    #[error("the solver panicked")]
+    Panicked,
+    #[error("isn't implemented yet")]
+    Unimplemented,
+    /// The solver gave up on its input
+    #[error("failed: {0}")]
+    Failed(#[from] AocError),
+}
+
+/// What a solver answered and how long it took
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub duration: Duration,
+    /// How much of that was parsing and how much solving, for days that keep the two apart
+    pub phases: Option<PhaseTimes>,
+    /// The most heap in use at once while solving, when built with the `memory` feature
+    pub peak_memory: Option<usize>,
+}
+
+pub fn time_solution(solve: Solve, input: &str) -> (Result<Answer, AocError>, Duration) {
+    let start = Instant::now();
+    let result = solve.call(input);
+    let end = Instant::now();
+    (result, end - start)
+}
+
+fn time_with_progress(
+    solver: &Implementation,
+    input: &str,
+    bar: Option<&ProgressBar>,
+    cancel: Option<&CancelToken>,
+) -> Result<Solved, AocError> {
+    let _span = info_span!(
+        "run",
+        day = solver.day,
+        part = solver.part,
+        implementation = solver.name
+    )
+    .entered();
+    // Alternatives are plain functions, only the main implementation can be timed in phases
+    let day = get_day(solver.day).filter(|_| solver.name == DEFAULT_IMPLEMENTATION);
+    // Solvers that report their progress are also the ones that can be cancelled
+    let cancellable = cancel.map(|token| {
+        let bar: &dyn Progress = bar.map_or(&NoProgress, |bar| bar);
+        Cancellable::new(bar, token.clone())
+    });
+    let progress: Option<&dyn Progress> = match (&cancellable, bar) {
+        (Some(cancellable), _) => Some(cancellable),
+        (None, bar) => bar.map(|bar| bar as &dyn Progress),
+    };
+    let ((answer, duration, phases), peak_memory) =
+        measure_peak(|| match (solver.solve_with_progress, progress, day) {
+            (Some(solve_with_progress), Some(progress), _) => {
+                let start = Instant::now();
+                let answer = solve_with_progress(input, progress);
+                let end = Instant::now();
+                (answer, end - start, None)
+            }
+            (_, _, Some(day)) => match day.solve(solver.part, input) {
+                Ok((answer, phases)) => (Ok(answer), phases.total(), Some(phases)),
+                Err(error) => (Err(error), Duration::ZERO, None),
+            },
+            _ => {
+                let (answer, duration) = time_solution(solver.solve, input);
+                (answer, duration, None)
+            }
+        });
+    Ok(Solved {
+        answer: answer?,
+        duration,
+        phases,
+        peak_memory,
+    })
+}
+
+/// Time the solver, turning a panic into an error rather than letting it take down everything else
+/// being run. `todo!()` panics too, but that's reported as not being implemented yet
+fn time_catching_panics(
+    solver: &Implementation,
+    input: &str,
+    bar: Option<&ProgressBar>,
+    cancel: Option<&CancelToken>,
+) -> Result<Solved, SolveError> {
+    let solved = catch_unwind(AssertUnwindSafe(|| {
+        time_with_progress(solver, input, bar, cancel)
+    }))
+    .map_err(panic_error)?;
+    Ok(solved?)
+}
+
+/// `todo!()` panics too, but that's reported as not being implemented yet
+fn panic_error(payload: Box<dyn Any + Send>) -> SolveError {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+    if message.is_some_and(|message| message.starts_with("not yet implemented")) {
+        SolveError::Unimplemented
+    } else {
+        SolveError::Panicked
+    }
+}
+
+/// Time the solver, showing a progress bar if asked and it's one that can report its progress.
+///
+/// With a timeout the solver runs on its own thread. If it doesn't finish in time it's cancelled,
+/// which solvers that report their progress notice and stop. Any other is abandoned (there's no way
+/// to stop it from outside) and left to be killed when the process exits.
+pub fn time_solver(
+    solver: &'static Implementation,
+    input: &str,
+    timeout: Option<Duration>,
+    show_progress: bool,
+) -> Result<Solved, SolveError> {
+    let bar = solver
+        .solve_with_progress
+        .filter(|_| show_progress)
+        .map(|_| progress_bar());
+
+    let outcome = match timeout {
+        None => time_catching_panics(solver, input, bar.as_ref(), None),
+        Some(timeout) => {
+            let (sender, receiver) = channel();
+            let input = input.to_string();
+            let thread_bar = bar.clone();
+            let cancel = CancelToken::new();
+            let thread_cancel = cancel.clone();
+            thread::spawn(move || {
+                // Nobody is listening any more if we've timed out, which is fine
+                let _ = sender.send(time_catching_panics(
+                    solver,
+                    &input,
+                    thread_bar.as_ref(),
+                    Some(&thread_cancel),
+                ));
+            });
+            receiver
+                .recv_timeout(timeout)
+                .unwrap_or_else(|error| match error {
+                    RecvTimeoutError::Timeout => {
+                        cancel.cancel();
+                        Err(SolveError::TimedOut(timeout))
+                    }
+                    RecvTimeoutError::Disconnected => Err(SolveError::Panicked),
+                })
+        }
+    };
+
+    if let Some(bar) = bar {
+        bar.finish();
+    }
+    outcome
+}
+
+/// What the thread solving a day in [time_parts] tells the runner
+enum DayEvent {
+    Solved(usize, Result<(Answer, PhaseTimes), AocError>),
+    Finished { peak_memory: Option<usize> },
+    Panicked(SolveError),
+}
+
+/// Time the main solver for each of `parts` of `day`, parsing the input once for all of them rather
+/// than once each. Each part's phases include that parse, and the peak memory is for the whole day.
+///
+/// A timeout is for all of the parts together, and it or a panic fails whichever parts hadn't been
+/// solved yet. Days that report their progress are cancelled when it runs out, the rest are
+/// abandoned like in [time_solver]
+pub fn time_parts(
+    day: usize,
+    parts: &[usize],
+    input: &str,
+    timeout: Option<Duration>,
+) -> Vec<Result<Solved, SolveError>> {
+    match get_day(day) {
+        Some(solver) => time_day(solver, parts, input, timeout),
+        None => parts
+            .iter()
+            .map(|_| Err(SolveError::Unimplemented))
+            .collect(),
+    }
+}
+
+fn time_day(
+    solver: &'static dyn Solver,
+    parts: &[usize],
+    input: &str,
+    timeout: Option<Duration>,
+) -> Vec<Result<Solved, SolveError>> {
+    let (sender, receiver) = channel();
+    let cancel = CancelToken::new();
+    let solve = {
+        let parts = parts.to_vec();
+        let cancel = cancel.clone();
+        move |input: &str| {
+            let _span = info_span!("run", day = solver.day()).entered();
+            let progress = Cancellable::new(&NoProgress, cancel);
+            let (solved, peak_memory) = measure_peak(|| {
+                catch_unwind(AssertUnwindSafe(|| {
+                    solver.solve_parts(&parts, input, &progress, &mut |part, result| {
+                        // Nobody is listening any more if we've timed out, which is fine
+                        let _ = sender.send(DayEvent::Solved(part, result));
+                    })
+                }))
+            });
+            let _ = sender.send(match solved {
+                Ok(()) => DayEvent::Finished { peak_memory },
+                Err(payload) => DayEvent::Panicked(panic_error(payload)),
+            });
+        }
+    };
+    match timeout {
+        None => solve(input),
+        Some(_) => {
+            let input = input.to_string();
+            thread::spawn(move || solve(&input));
+        }
+    }
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut outcomes: Vec<Option<Result<Solved, SolveError>>> = vec![None; parts.len()];
+    let (failed, peak_memory) = loop {
+        let event = match deadline {
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+        };
+        match event {
+            Ok(DayEvent::Solved(part, result)) => {
+                let index = parts.iter().position(|p| *p == part);
+                let solved = result.map(|(answer, phases)| Solved {
+                    answer,
+                    duration: phases.total(),
+                    phases: Some(phases),
+                    peak_memory: None,
+                });
+                outcomes[index.expect("only the parts asked for are solved")] =
+                    Some(solved.map_err(SolveError::from));
+            }
+            Ok(DayEvent::Finished { peak_memory }) => break (SolveError::Panicked, peak_memory),
+            Ok(DayEvent::Panicked(error)) => break (error, None),
+            Err(RecvTimeoutError::Timeout) => {
+                cancel.cancel();
+                break (SolveError::TimedOut(timeout.unwrap_or_default()), None);
+            }
+            Err(RecvTimeoutError::Disconnected) => break (SolveError::Panicked, None),
+        }
+    };
+    outcomes
+        .into_iter()
+        .map(|outcome| match outcome {
+            Some(Ok(solved)) => Ok(Solved {
+                peak_memory,
+                ..solved
+            }),
+            Some(Err(error)) => Err(error),
+            None => Err(failed.clone()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::{get_solver, Status};
+    use advent_of_code_2024::day12::Day12;
+    use itertools::Itertools;
+    use std::sync::mpsc::Sender;
+
+    fn sleepy(_input: &str) -> Result<Answer, AocError> {
+        thread::sleep(Duration::from_secs(5));
+        Ok("zzz".into())
+    }
+
+    fn panicky(_input: &str) -> Result<Answer, AocError> {
+        panic!("not today")
+    }
+
+    static SLEEPY: Implementation = Implementation::new(0, 1, sleepy, Status::Todo);
+    fn unfinished(_input: &str) -> Result<Answer, AocError> {
+        todo!()
+    }
+
+    static PANICKY: Implementation = Implementation::new(0, 2, panicky, Status::Todo);
+    static UNFINISHED: Implementation = Implementation::new(0, 2, unfinished, Status::Todo);
+
+    #[test]
+    fn test_time_solver() {
+        let solver = get_solver(1, 1).unwrap();
+        let solved = time_solver(solver, "1abc2", None, true).unwrap();
+        assert_eq!(solved.answer, "12");
+        assert!(solved.phases.is_some());
+        let solved = time_solver(solver, "1abc2", Some(Duration::from_secs(5)), true).unwrap();
+        assert_eq!(solved.answer, "12");
+    }
+
+    #[test]
+    fn test_time_solver_phases() {
+        let solver = get_solver(9, 1).unwrap();
+        let solved = time_solver(
+            solver,
+            crate::examples::get_example(9, 1).unwrap().input,
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(solved.answer, "114");
+        assert_eq!(
+            solved.phases.map(|phases| phases.total()),
+            Some(solved.duration)
+        );
+    }
+
+    #[test]
+    fn test_time_solver_timeout() {
+        let timeout = Duration::from_millis(10);
+        assert_eq!(
+            time_solver(&SLEEPY, "", Some(timeout), true),
+            Err(SolveError::TimedOut(timeout))
+        );
+    }
+
+    #[test]
+    fn test_time_solver_panic() {
+        assert_eq!(
+            time_solver(&PANICKY, "", Some(Duration::from_secs(5)), true),
+            Err(SolveError::Panicked)
+        );
+        assert_eq!(
+            time_solver(&PANICKY, "", None, true),
+            Err(SolveError::Panicked)
+        );
+    }
+
+    #[test]
+    fn test_time_solver_unimplemented() {
+        assert_eq!(
+            time_solver(&UNFINISHED, "", None, true),
+            Err(SolveError::Unimplemented)
+        );
+        assert_eq!(
+            time_solver(&UNFINISHED, "", Some(Duration::from_secs(5)), true),
+            Err(SolveError::Unimplemented)
+        );
+    }
+
+    #[test]
+    fn test_time_parts() {
+        let input = crate::examples::get_example(9, 1).unwrap().input;
+        for timeout in [None, Some(Duration::from_secs(5))] {
+            let outcomes = time_parts(9, &[1, 2], input, timeout);
+            let solved: Vec<_> = outcomes.into_iter().map(Result::unwrap).collect();
+            assert_eq!(solved[0].answer, "114");
+            assert_eq!(solved[1].answer, "2");
+            let parse = |solved: &Solved| solved.phases.unwrap().parse;
+            assert_eq!(parse(&solved[0]), parse(&solved[1]));
+        }
+    }
+
+    #[test]
+    fn test_time_parts_fails_each_part() {
+        let input = crate::examples::get_example(1, 2).unwrap().input;
+        let outcomes = time_parts(1, &[1, 2], input, None);
+        assert!(matches!(outcomes[0], Err(SolveError::Failed(_))));
+        assert_eq!(outcomes[1].as_ref().unwrap().answer, "281");
+        assert_eq!(
+            time_parts(26, &[1], "", None),
+            vec![Err(SolveError::Unimplemented)]
+        );
+    }
+
+    /// Day 12, also telling whoever's watching what each part came to
+    struct Watched(Sender<Result<(Answer, PhaseTimes), AocError>>);
+
+    impl Solver for Watched {
+        fn day(&self) -> usize {
+            12
+        }
+
+        fn solve_parts(
+            &self,
+            parts: &[usize],
+            input: &str,
+            progress: &dyn Progress,
+            solved: &mut dyn FnMut(usize, Result<(Answer, PhaseTimes), AocError>),
+        ) {
+            Day12.solve_parts(parts, input, progress, &mut |part, result| {
+                let _ = self.0.send(result.clone());
+                solved(part, result);
+            });
+        }
+    }
+
+    #[test]
+    fn test_time_parts_cancels() {
+        let input = (0..100_000)
+            .map(|row| {
+                let unknowns: String = (0..20)
+                    .map(|bit| if row >> bit & 1 == 1 { '?' } else { '.' })
+                    .collect();
+                format!("{unknowns}#? 1,1")
+            })
+            .join("\n");
+        let (sender, receiver) = channel();
+        let watched = Box::leak(Box::new(Watched(sender)));
+        let timeout = Duration::from_millis(10);
+        assert_eq!(
+            time_day(watched, &[1, 2], &input, Some(timeout)),
+            vec![Err(SolveError::TimedOut(timeout)); 2]
+        );
+        for _ in 0..2 {
+            let stopped = receiver.recv_timeout(Duration::from_secs(10));
+            assert_eq!(stopped, Ok(Err(AocError::Cancelled)));
+        }
+    }
+
+    #[test]
+    fn test_time_solver_bad_input() {
+        let solver = get_solver(2, 1).unwrap();
+        let error = time_solver(solver, "nonsense", None, true).unwrap_err();
+        assert!(matches!(error, SolveError::Failed(AocError::Parse(_))));
+        assert!(error
+            .to_string()
+            .starts_with("failed: couldn't parse the input"));
+    }
+}
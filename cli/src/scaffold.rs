@@ -0,0 +1,98 @@
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use thiserror::Error;
+
+const TEMPLATE: &str = include_str!("../../templates/day.rs.template");
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ScaffoldError {
+    #[error("day {0} isn't a day of advent, expected 1 to 25")]
+    InvalidDay(usize),
+    #[error("{0} already exists")]
+    AlreadyExists(PathBuf),
+}
+
+fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../core/src")
+}
+
+fn day_number(line: &str, prefix: &str) -> Option<usize> {
+    line.strip_prefix(prefix)?.get(..2)?.parse().ok()
+}
+
+/// Insert `new_lines` before the first line with a later day, or after the last line for any day
+fn insert_in_order(
+    source: &str,
+    day: usize,
+    new_lines: &[String],
+    day_of: impl Fn(&str) -> Option<usize>,
+) -> String {
+    let mut lines: Vec<_> = source.lines().map(str::to_string).collect();
+    let position = lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|existing| existing > day))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| day_of(line).is_some())
+                .map(|last| last + 1)
+        })
+        .expect("source should already list at least one day");
+    lines.splice(position..position, new_lines.iter().cloned());
+    lines.join("\n") + "\n"
+}
+
+fn add_module(lib: &str, day: usize) -> String {
+    let module = format!("pub mod day{day:0>2};");
+    if lib.lines().any(|line| line == module) {
+        return lib.to_string();
+    }
+    insert_in_order(lib, day, &[module], |line| day_number(line, "pub mod day"))
+}
+
+fn update_file(path: &Path, update: impl Fn(&str) -> String) -> Result<()> {
+    let source =
+        read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    write(path, update(&source)).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Create `core/src/dayXX.rs` from the template and add it to `core/src/lib.rs`, it registers its
+/// own solvers
+pub fn new_day(day: usize) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day).into());
+    }
+    let src = src_dir();
+    let path = src.join(format!("day{day:0>2}.rs"));
+    if path.exists() {
+        return Err(ScaffoldError::AlreadyExists(path).into());
+    }
+
+    let source = TEMPLATE
+        .replace("DayXX", &format!("Day{day:0>2}"))
+        .replace("DAY_NUMBER", &day.to_string());
+    write(&path, source).with_context(|| format!("failed to write {}", path.display()))?;
+    update_file(&src.join("lib.rs"), |lib| add_module(lib, day))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_add_module() {
+        let lib = "pub mod config;\npub mod day01;\npub mod day03;\npub mod examples;\n";
+        assert_eq!(
+            add_module(lib, 2),
+            "pub mod config;\npub mod day01;\npub mod day02;\npub mod day03;\npub mod examples;\n"
+        );
+        assert_eq!(
+            add_module(lib, 4),
+            "pub mod config;\npub mod day01;\npub mod day03;\npub mod day04;\npub mod examples;\n"
+        );
+        assert_eq!(add_module(lib, 3), lib);
+    }
+}
@@ -0,0 +1,50 @@
+//! Day 1 part 2 scanning each line once for digits and digit words, against parsing from every
+//! position in it (`--impl nom`)
+
+use advent_of_code_2024::day01;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::Random;
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `lines` lines of letters with digits and digit words scattered through them, each with at
+/// least one digit
+fn generated_document(lines: usize) -> String {
+    let mut random = Random::new(0x2545_f491);
+    (0..lines)
+        .map(|_| {
+            let mut line = String::new();
+            let digit_at = random.below(20);
+            for token in 0..20 {
+                match random.below(10) {
+                    _ if token == digit_at => line.push(char::from(b'1' + random.below(9) as u8)),
+                    0..=6 => line.push(char::from(b'a' + random.below(26) as u8)),
+                    7 | 8 => line.push_str(DIGIT_WORDS[random.below(9) as usize]),
+                    _ => line.push(char::from(b'1' + random.below(9) as u8)),
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn part2(c: &mut Criterion) {
+    let input = generated_document(10_000);
+    assert_eq!(day01::part2(&input), day01::part2_nom(&input));
+
+    let mut group = c.benchmark_group("day01 part 2");
+    group.bench_function("scanner", |b| b.iter(|| day01::part2(black_box(&input))));
+    group.bench_function("nom", |b| b.iter(|| day01::part2_nom(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, part2);
+criterion_main!(benches);
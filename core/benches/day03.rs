@@ -0,0 +1,30 @@
+//! Day 3 finding the numbers around each symbol from an index of where the numbers are, against
+//! scanning every number for each symbol (`--impl scan`)
+
+use advent_of_code_2024::day03;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::synthetic_schematic;
+
+fn parts(c: &mut Criterion) {
+    let input = synthetic_schematic(400);
+    assert_eq!(day03::part1(&input), day03::part1_scan(&input));
+    assert_eq!(day03::part2(&input), day03::part2_scan(&input));
+
+    let mut group = c.benchmark_group("day03 part 1");
+    group.bench_function("indexed", |b| b.iter(|| day03::part1(black_box(&input))));
+    group.bench_function("scan", |b| b.iter(|| day03::part1_scan(black_box(&input))));
+    group.finish();
+
+    let mut group = c.benchmark_group("day03 part 2");
+    group.bench_function("indexed", |b| b.iter(|| day03::part2(black_box(&input))));
+    group.bench_function("scan", |b| b.iter(|| day03::part2_scan(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, parts);
+criterion_main!(benches);
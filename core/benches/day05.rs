@@ -0,0 +1,70 @@
+//! Day 5 folding the almanac into one map from seeds to locations (`--impl combined`), against
+//! passing intervals of seeds through the maps one at a time
+
+use advent_of_code_2024::day05;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::Random;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// How far the seeds and each map's numbers go, about as far as they do in a real almanac
+const SPAN: u64 = 4_000_000_000;
+
+/// An almanac with `ranges` ranges in each map, none of them overlapping, and `seeds` ranges of
+/// seeds
+fn generated_almanac(ranges: u64, seeds: u64) -> String {
+    let mut random = Random::new(0x9e37_79b9);
+    let length = SPAN / ranges;
+    let seeds = (0..seeds)
+        .map(|_| format!("{} {}", random.below(SPAN), random.below(length) + 1))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let maps = MAPS.map(|name| {
+        let lines = (0..ranges)
+            .map(|range| {
+                let source = range * length + random.below(length / 2);
+                let destination = random.below(SPAN);
+                format!("{destination} {source} {}", random.below(length / 2) + 1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("{name} map:\n{lines}")
+    });
+    format!("seeds: {seeds}\n\n{}", maps.join("\n\n"))
+}
+
+fn parts(c: &mut Criterion) {
+    let input = generated_almanac(200, 20);
+    assert_eq!(day05::part1(&input), day05::part1_combined(&input));
+    assert_eq!(day05::part2(&input), day05::part2_combined(&input));
+
+    let mut group = c.benchmark_group("day05 part 1");
+    group.bench_function("maps", |b| b.iter(|| day05::part1(black_box(&input))));
+    group.bench_function("combined", |b| {
+        b.iter(|| day05::part1_combined(black_box(&input)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("day05 part 2");
+    group.bench_function("intervals", |b| b.iter(|| day05::part2(black_box(&input))));
+    group.bench_function("combined", |b| {
+        b.iter(|| day05::part2_combined(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parts);
+criterion_main!(benches);
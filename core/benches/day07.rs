@@ -0,0 +1,85 @@
+//! Day 7 ranking hands by one packed number each, against counting their cards in a `HashMap` and
+//! comparing tuples
+
+use std::collections::HashMap;
+
+use advent_of_code_2024::day07::{Day07, Hand, HandType, Rules};
+use advent_of_code_2024::solver::Day;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::generated_hands;
+
+/// How hands used to be ranked, counting cards in a `HashMap` and comparing tuples
+fn strength_by_hashmap(rules: &Rules, hand: &Hand) -> (HandType, [u8; 5]) {
+    let mut occurrences = HashMap::new();
+    let mut wilds = 0;
+    for card in hand.cards() {
+        if Some(card) == rules.wild {
+            wilds += 1;
+        } else {
+            *occurrences.entry(card).or_insert(0) += 1;
+        }
+    }
+    let mut counts: Vec<u32> = occurrences.into_values().collect();
+    counts.sort_by(|a, b| b.cmp(a));
+    match counts.first_mut() {
+        Some(most) => *most += wilds,
+        None => counts.push(wilds),
+    }
+    let hand_type = match counts[..] {
+        [5] => HandType::FiveOfAKind,
+        [4, 1] => HandType::FourOfAKind,
+        [3, 2] => HandType::FullHouse,
+        [3, 1, 1] => HandType::ThreeOfAKind,
+        [2, 2, 1] => HandType::TwoPair,
+        [2, 1, 1, 1] => HandType::OnePair,
+        _ => HandType::HighCard,
+    };
+    (hand_type, hand.cards().map(|card| rules.card_rank(card)))
+}
+
+/// Each bid multiplied by its rank, ranking the hands with [strength_by_hashmap]
+fn total_winnings_by_hashmap(rules: &Rules, hands_and_bids: &[(Hand, u64)]) -> u64 {
+    let mut strengths: Vec<_> = hands_and_bids
+        .iter()
+        .map(|(hand, bid)| (strength_by_hashmap(rules, hand), *bid))
+        .collect();
+    strengths.sort_unstable();
+    strengths
+        .into_iter()
+        .zip(1..)
+        .map(|((_, bid), rank)| rank * bid)
+        .sum()
+}
+
+fn ranking(c: &mut Criterion) {
+    let hands = Day07::parse(&generated_hands(100_000)).unwrap();
+    let aces_low = Rules {
+        aces_low: true,
+        ..Rules::JOKERS
+    };
+    for rules in [Rules::STANDARD, Rules::JOKERS, aces_low] {
+        assert_eq!(
+            rules.total_winnings(&hands),
+            total_winnings_by_hashmap(&rules, &hands),
+            "{rules:?}"
+        );
+    }
+
+    let rules = Rules::JOKERS;
+    let mut group = c.benchmark_group("day07 ranking");
+    group.bench_function("packed key", |b| {
+        b.iter(|| rules.total_winnings(black_box(&hands)))
+    });
+    group.bench_function("hashmap", |b| {
+        b.iter(|| total_winnings_by_hashmap(&rules, black_box(&hands)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, ranking);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+//! Day 9 working out the values either side of each history from binomial coefficients
+//! (`--impl binomial`), against taking the differences until they're all 0
+
+use advent_of_code_2024::day09;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::Random;
+
+/// `count` histories of `length` values of cubics, about twice as long as a real input's. Much
+/// longer and the binomial coefficients don't fit in an `i64`
+fn generated_histories(count: usize, length: i64) -> String {
+    let mut random = Random::new(0x9e37_79b9);
+    (0..count)
+        .map(|_| {
+            let coefficients: Vec<_> = (0..4).map(|_| random.below(7) as i64 - 3).collect();
+            (0..length)
+                .map(|x| coefficients.iter().rev().fold(0, |sum, c| sum * x + c))
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parts(c: &mut Criterion) {
+    let input = generated_histories(2000, 40);
+    assert_eq!(day09::part1(&input), day09::part1_binomial(&input));
+    assert_eq!(day09::part2(&input), day09::part2_binomial(&input));
+
+    let mut group = c.benchmark_group("day09 part 1");
+    group.bench_function("differences", |b| {
+        b.iter(|| day09::part1(black_box(&input)))
+    });
+    group.bench_function("binomial", |b| {
+        b.iter(|| day09::part1_binomial(black_box(&input)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("day09 part 2");
+    group.bench_function("differences", |b| {
+        b.iter(|| day09::part2(black_box(&input)))
+    });
+    group.bench_function("binomial", |b| {
+        b.iter(|| day09::part2_binomial(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parts);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Day 12 part 1 trying every arrangement of a row (`--impl brute_force`), against fitting the
+//! groups in a condition at a time, for rows of different lengths. Where trying every arrangement
+//! stops being faster is where the default `12.brute_force_length` comes from
+
+use advent_of_code_2024::config::{set_config, Config};
+use advent_of_code_2024::day12;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::{generated_row, Random};
+
+/// `count` rows of `length` springs
+fn generated_rows(count: usize, length: usize) -> String {
+    let mut random = Random::new(0x1405_7b7e);
+    (0..count)
+        .map(|_| generated_row(&mut random, length))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn part1(c: &mut Criterion) {
+    // Only ever fit the groups a condition at a time in part 1, so it can be compared
+    let mut config = Config::default();
+    config.set_day_param("12.brute_force_length=0".parse().unwrap());
+    set_config(config);
+
+    let mut group = c.benchmark_group("day12 part 1");
+    for length in [2, 4, 6, 8, 10, 12, 14, 16] {
+        let input = generated_rows(1000, length);
+        assert_eq!(day12::part1(&input), day12::part1_brute_force(&input));
+        group.bench_with_input(BenchmarkId::new("fits", length), &input, |b, input| {
+            b.iter(|| day12::part1(black_box(input)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("brute_force", length),
+            &input,
+            |b, input| b.iter(|| day12::part1_brute_force(black_box(input))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, part1);
+criterion_main!(benches);
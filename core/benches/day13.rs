@@ -0,0 +1,47 @@
+//! Day 13 comparing each map's rows and columns as bitmasks, against comparing them cell by cell
+//! (`--impl grid`). Some of the maps are too wide for a mask, which are compared cell by cell
+//! either way
+
+use advent_of_code_2024::day13;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+#[allow(dead_code)]
+mod testing;
+
+use testing::{generated_map, Random};
+
+/// `count` maps, most up to 20 cells each way like a real input's, every tenth 65 to 96 cells wide
+fn generated_maps(count: usize) -> String {
+    let mut random = Random::new(0x2545_f491);
+    (0..count)
+        .map(|map| {
+            let width = match map % 10 {
+                0 => random.below(32) as usize + 65,
+                _ => random.below(16) as usize + 5,
+            };
+            let height = random.below(16) as usize + 5;
+            generated_map(&mut random, width, height)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn parts(c: &mut Criterion) {
+    let input = generated_maps(5000);
+    assert_eq!(day13::part1(&input), day13::part1_grid(&input));
+    assert_eq!(day13::part2(&input), day13::part2_grid(&input));
+
+    let mut group = c.benchmark_group("day13 part 1");
+    group.bench_function("masks", |b| b.iter(|| day13::part1(black_box(&input))));
+    group.bench_function("grid", |b| b.iter(|| day13::part1_grid(black_box(&input))));
+    group.finish();
+
+    let mut group = c.benchmark_group("day13 part 2");
+    group.bench_function("masks", |b| b.iter(|| day13::part2(black_box(&input))));
+    group.bench_function("grid", |b| b.iter(|| day13::part2_grid(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, parts);
+criterion_main!(benches);
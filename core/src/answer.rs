@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Numbers worked out on the way to an answer that are worth seeing, eg how long a cycle was, by
+/// name
+pub type Stats = BTreeMap<String, Answer>;
+
+/// What a part gives back. Most answers are numbers, which are kept as numbers so they can be
+/// compared with a known answer numerically, and show up as numbers in JSON
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    U64(u64),
+    I64(i64),
+    Text(String),
+    /// An answer along with [Stats] on how it was found. This is written as just the answer, a
+    /// [RunReport](crate::report::RunReport) puts the stats beside it
+    #[serde(serialize_with = "serialize_without_stats", skip_deserializing)]
+    WithStats(Box<Answer>, Stats),
+}
+
+fn serialize_without_stats<S: Serializer>(
+    answer: &Answer,
+    _stats: &Stats,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    answer.serialize(serializer)
+}
+
+impl Answer {
+    /// The answer as a number, if it is one
+    pub fn number(&self) -> Option<i128> {
+        match self {
+            Answer::U64(n) => Some(i128::from(*n)),
+            Answer::I64(n) => Some(i128::from(*n)),
+            Answer::Text(_) => None,
+            Answer::WithStats(answer, _) => answer.number(),
+        }
+    }
+
+    /// Whether this is the answer written as `expected`. Numbers are compared as numbers, so
+    /// `"0042"` or `" 42"` still match 42
+    pub fn matches(&self, expected: &str) -> bool {
+        self.compare(expected).is_ok()
+    }
+
+    /// Check this is the answer written as `expected`, the same as [Answer::matches], but saying
+    /// how it's different if it isn't. Whitespace around either isn't counted
+    pub fn compare(&self, expected: &str) -> Result<(), Mismatch> {
+        let expected = expected.trim();
+        let matched = match (self, expected.parse::<i128>()) {
+            (Answer::WithStats(answer, _), _) => return answer.compare(expected),
+            (Answer::Text(text), _) => text.trim() == expected,
+            (number, Ok(expected)) => number.number() == Some(expected),
+            (_, Err(_)) => false,
+        };
+        if matched {
+            Ok(())
+        } else {
+            Err(Mismatch {
+                expected: expected.to_string(),
+                got: self.to_string().trim().to_string(),
+            })
+        }
+    }
+    /// Add a stat on how the answer was found
+    pub fn with_stat(self, name: impl Into<String>, value: impl Into<Answer>) -> Self {
+        let (answer, mut stats) = match self {
+            Answer::WithStats(answer, stats) => (answer, stats),
+            answer => (Box::new(answer), Stats::new()),
+        };
+        stats.insert(name.into(), value.into());
+        Answer::WithStats(answer, stats)
+    }
+
+    pub fn stats(&self) -> Option<&Stats> {
+        match self {
+            Answer::WithStats(_, stats) => Some(stats),
+            _ => None,
+        }
+    }
+
+    /// Just the answer, eg to compare with another implementation's that has different stats
+    pub fn without_stats(self) -> Self {
+        match self {
+            Answer::WithStats(answer, _) => *answer,
+            answer => answer,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::U64(n) => n.fmt(f),
+            Answer::I64(n) => n.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+            Answer::WithStats(answer, _) => answer.fmt(f),
+        }
+    }
+}
+
+/// An answer that isn't the one expected, which shows both, and how far off it is when they're both
+/// numbers, eg `expected 42, got 45 (3 too high)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub expected: String,
+    pub got: String,
+}
+
+impl Mismatch {
+    /// How much higher the answer got is than the one expected, if they're both numbers
+    pub fn difference(&self) -> Option<i128> {
+        Some(self.got.parse::<i128>().ok()? - self.expected.parse::<i128>().ok()?)
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Text is quoted so it's clear where it starts and ends, numbers aren't
+        let show = |text: &str| match text.parse::<i128>() {
+            Ok(number) if number.to_string() == text => text.to_string(),
+            Ok(number) => format!("{text:?} ({number})"),
+            Err(_) => format!("{text:?}"),
+        };
+        write!(
+            f,
+            "expected {}, got {}",
+            show(&self.expected),
+            show(&self.got)
+        )?;
+        match self.difference() {
+            Some(difference) if difference > 0 => write!(f, " ({difference} too high)"),
+            Some(difference) => write!(f, " ({} too low)", difference.unsigned_abs()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Anything that looks like a number is read as one, so answers read back from a file compare the
+/// same as those just solved
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(n) = s.parse() {
+            Answer::U64(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::I64(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),+) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::U64(n as u64)
+            }
+        })+
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),+) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::I64(n as i64)
+            }
+        })+
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::from(42u64).matches("42"));
+        assert!(Answer::from(42u64).matches(" 0042\n"));
+        assert!(!Answer::from(42u64).matches("43"));
+        assert!(Answer::from(-7i64).matches("-7"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(!Answer::from("abc").matches("42"));
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::from(42u64).compare(" 0042\n"), Ok(()));
+        assert_eq!(Answer::from("abc\n").compare("abc"), Ok(()));
+
+        let mismatch = Answer::from(45u64).compare("42").unwrap_err();
+        assert_eq!(mismatch.difference(), Some(3));
+        assert_eq!(mismatch.to_string(), "expected 42, got 45 (3 too high)");
+        let mismatch = Answer::from(-7i64).compare("0042").unwrap_err();
+        assert_eq!(mismatch.difference(), Some(-49));
+        assert_eq!(
+            mismatch.to_string(),
+            r#"expected "0042" (42), got -7 (49 too low)"#
+        );
+        let mismatch = Answer::from("abc").compare("42").unwrap_err();
+        assert_eq!(mismatch.difference(), None);
+        assert_eq!(mismatch.to_string(), r#"expected 42, got "abc""#);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("42".parse(), Ok(Answer::U64(42)));
+        assert_eq!("-42".parse(), Ok(Answer::I64(-42)));
+        assert_eq!("4-2".parse(), Ok(Answer::Text("4-2".to_string())));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let answers = vec![Answer::U64(42), Answer::I64(-1), Answer::from("abc")];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r#"[42,-1,"abc"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_with_stats() {
+        let answer = Answer::from(64u64)
+            .with_stat("cycle_start", 3u64)
+            .with_stat("cycle_length", 7u64);
+        assert!(answer.matches("64"));
+        assert_eq!(answer.to_string(), "64");
+        assert_eq!(serde_json::to_string(&answer).unwrap(), "64");
+        assert_eq!(
+            answer.stats().unwrap().keys().collect::<Vec<_>>(),
+            vec!["cycle_length", "cycle_start"]
+        );
+        assert_eq!(answer.without_stats(), Answer::U64(64));
+    }
+}
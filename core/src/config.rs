@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::error::AocError;
+use crate::report::Format;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The year these solutions are for, and whose inputs can live directly in the inputs directory
+pub const DEFAULT_YEAR: usize = 2023;
+
+/// Defaults read from `aoc.toml`, any flags passed on the command line take precedence, eg:
+///
+/// ```toml
+/// inputs = "../aoc-inputs"
+/// year = 2023
+/// session_file = ".aoc-session"
+/// format = "json"
+/// threads = 4
+///
+/// [day11]
+/// expansion = 100
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Where to find (and download) inputs
+    pub inputs: PathBuf,
+    /// Which year's puzzles to get inputs for
+    pub year: usize,
+    /// A file containing the adventofcode.com session cookie, used if AOC_SESSION isn't set
+    pub session_file: Option<PathBuf>,
+    pub format: Option<Format>,
+    /// How many threads rayon should use
+    pub threads: Option<usize>,
+    /// Parameters for individual days, keyed by `dayXX`
+    #[serde(flatten)]
+    days: HashMap<String, toml::Table>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            inputs: PathBuf::from("inputs"),
+            year: DEFAULT_YEAR,
+            session_file: None,
+            format: None,
+            threads: None,
+            days: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Read the config from `path`, falling back on the defaults if there's no such file
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Where a day's input lives, `<inputs>/<year>/dXX.txt` if there's a directory for the year.
+    /// Otherwise 2023's inputs can be directly in `<inputs>`, like they were before other years
+    pub fn input_path(&self, day: usize) -> PathBuf {
+        let file_name = format!("d{day:0>2}.txt");
+        let year_dir = self.inputs.join(self.year.to_string());
+        if year_dir.is_dir() || self.year != DEFAULT_YEAR {
+            year_dir.join(file_name)
+        } else {
+            self.inputs.join(file_name)
+        }
+    }
+
+    /// Set a day parameter, replacing any from the file
+    pub fn set_day_param(&mut self, param: DayParam) {
+        self.days
+            .entry(format!("day{:0>2}", param.day))
+            .or_default()
+            .insert(param.name, param.value);
+    }
+
+    /// Every parameter set for a day, from the file or the command line
+    pub fn day_params(&self, day: usize) -> Option<&toml::Table> {
+        self.days.get(&format!("day{day:0>2}"))
+    }
+
+    /// A day parameter if it's been set, or an error if it isn't a `T`
+    fn day_param<T: DeserializeOwned>(
+        &self,
+        day: usize,
+        name: &str,
+    ) -> Result<Option<T>, AocError> {
+        let Some(value) = self.day_params(day).and_then(|params| params.get(name)) else {
+            return Ok(None);
+        };
+        value.clone().try_into().map(Some).map_err(|error| {
+            AocError::Param(format!("{day}.{name}: {}", error.to_string().trim_end()))
+        })
+    }
+}
+
+/// A day parameter given on the command line as `<day>.<name>=<value>`, eg `2.red=20`. The value
+/// is read as TOML, so numbers are numbers, and anything that isn't valid TOML is a string
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayParam {
+    pub day: usize,
+    pub name: String,
+    pub value: toml::Value,
+}
+
+impl FromStr for DayParam {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let usage = || format!("{s:?} should be <day>.<name>=<value>, eg 2.red=20");
+        let (key, value) = s.split_once('=').ok_or_else(usage)?;
+        let (day, name) = key.split_once('.').ok_or_else(usage)?;
+        let day = day.trim().parse().map_err(|_| usage())?;
+        let value = toml::from_str::<toml::Table>(&format!("value = {value}"))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+        Ok(Self {
+            day,
+            name: name.trim().to_string(),
+            value,
+        })
+    }
+}
+
+/// Make `config` the one returned by [config], this can only be done once, before it's first read
+pub fn set_config(config: Config) {
+    CONFIG
+        .set(config)
+        .expect("config should only be set once, before it's used");
+}
+
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// Look up a parameter a day lets you override, eg how many steps to take. It's an error for it to
+/// be set to something that isn't a `T`, rather than quietly using the default
+pub fn day_param<T: DeserializeOwned>(day: usize, name: &str) -> Result<Option<T>, AocError> {
+    config().day_param(day, name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+inputs = "elsewhere"
+format = "json"
+
+[day11]
+expansion = 100
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.inputs, PathBuf::from("elsewhere"));
+        assert_eq!(config.year, DEFAULT_YEAR);
+        assert_eq!(config.format, Some(Format::Json));
+        assert_eq!(config.threads, None);
+        assert_eq!(config.day_param(11, "expansion"), Ok(Some(100)));
+        assert_eq!(config.day_param::<usize>(11, "steps"), Ok(None));
+        assert_eq!(config.day_param::<usize>(12, "expansion"), Ok(None));
+    }
+
+    #[test]
+    fn test_input_path() {
+        let inputs = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let mut config = Config {
+            inputs: inputs.clone(),
+            ..Config::default()
+        };
+        assert_eq!(config.input_path(1), inputs.join("d01.txt"));
+
+        config.year = 2022;
+        assert_eq!(config.input_path(25), inputs.join("2022/d25.txt"));
+
+        config.year = DEFAULT_YEAR;
+        std::fs::create_dir_all(inputs.join("2023")).unwrap();
+        assert_eq!(config.input_path(1), inputs.join("2023/d01.txt"));
+        std::fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn test_set_day_param() {
+        let mut config = Config::default();
+        config.set_day_param("2.red=20".parse().unwrap());
+        config.set_day_param("21.part1_steps = 6".parse().unwrap());
+        config.set_day_param("2.label=not toml".parse().unwrap());
+        assert_eq!(config.day_param(2, "red"), Ok(Some(20)));
+        assert_eq!(config.day_param(21, "part1_steps"), Ok(Some(6)));
+        assert_eq!(
+            config.day_param(2, "label"),
+            Ok(Some("not toml".to_string()))
+        );
+        assert!("2red=20".parse::<DayParam>().is_err());
+        assert!("two.red=20".parse::<DayParam>().is_err());
+    }
+
+    #[test]
+    fn test_wrong_type_day_param() {
+        let mut config = Config::default();
+        config.set_day_param("2.red=abc".parse().unwrap());
+        assert_eq!(
+            config.day_param::<u32>(2, "red"),
+            Err(AocError::Param(
+                r#"2.red: invalid type: string "abc", expected u32"#.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
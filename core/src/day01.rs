@@ -0,0 +1,282 @@
+use nom::sequence::tuple;
+use nom::{branch::alt, bytes::complete::tag, bytes::complete::take, combinator::value, IResult};
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution;
+use crate::solver::Day;
+
+fn no_number(line: &str) -> AocError {
+    AocError::parse(format!("there's no number in {line:?}"))
+}
+
+fn first_number_char(input: &str) -> Result<usize, AocError> {
+    input
+        .chars()
+        .find(|c| c.is_numeric())
+        .and_then(|c| (c as usize).checked_sub(48))
+        .ok_or_else(|| no_number(input))
+}
+
+fn last_number_char(input: &str) -> Result<usize, AocError> {
+    input
+        .chars()
+        .rev()
+        .find(|c| c.is_numeric())
+        .and_then(|c| (c as usize).checked_sub(48))
+        .ok_or_else(|| no_number(input))
+}
+
+/// The digits 1 to 9 spelled out, for part 2
+const DIGIT_WORDS: [&[u8]; 9] = [
+    b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
+];
+
+/// The digits in a line, written either as digits or words, found in a single pass over it. For
+/// each digit word it keeps how much of the word the line so far ends with, so words that share
+/// letters like `oneight` are both found
+pub struct Digits<'a> {
+    bytes: std::slice::Iter<'a, u8>,
+    matched: [usize; 9],
+}
+
+impl<'a> Digits<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self {
+            bytes: line.as_bytes().iter(),
+            matched: [0; 9],
+        }
+    }
+}
+
+/// The longest start of `word` that it also ends with, other than all of it. That's how much of a
+/// digit word is still matched after the next letter doesn't fit, eg `nin` then `n` is `n`
+fn overlap(word: &[u8]) -> usize {
+    (1..word.len())
+        .rev()
+        .find(|&len| word[..len] == word[word.len() - len..])
+        .unwrap_or(0)
+}
+
+impl Iterator for Digits<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        for &byte in self.bytes.by_ref() {
+            if byte.is_ascii_digit() {
+                self.matched = [0; 9];
+                return Some(usize::from(byte - b'0'));
+            }
+            let mut found = None;
+            for (digit, (word, matched)) in DIGIT_WORDS.iter().zip(&mut self.matched).enumerate() {
+                while *matched > 0 && word[*matched] != byte {
+                    *matched = overlap(&word[..*matched]);
+                }
+                if word[*matched] == byte {
+                    *matched += 1;
+                }
+                if *matched == word.len() {
+                    found = Some(digit + 1);
+                    *matched = overlap(word);
+                }
+            }
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
+/// How a line's calibration value was found, reading digit words as part 2 does
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    pub line: &'a str,
+    pub first_digit: usize,
+    pub last_digit: usize,
+    /// The first and last digit as a two digit number
+    pub value: usize,
+}
+
+impl<'a> Calibration<'a> {
+    pub fn new(line: &'a str) -> Result<Self, AocError> {
+        let mut digits = Digits::new(line);
+        let first_digit = digits.next().ok_or_else(|| no_number(line))?;
+        let last_digit = digits.last().unwrap_or(first_digit);
+        Ok(Self {
+            line,
+            first_digit,
+            last_digit,
+            value: first_digit * 10 + last_digit,
+        })
+    }
+}
+
+/// The [Calibration] of each line, to see which digits were picked rather than just the total
+pub fn calibrations<'a, I>(lines: I) -> impl Iterator<Item = Result<Calibration<'a>, AocError>> + 'a
+where
+    I: IntoIterator<Item = &'a str>,
+    I::IntoIter: 'a,
+{
+    lines.into_iter().map(Calibration::new)
+}
+
+/// Just the value of each line's [Calibration]
+pub fn calibration_values<'a, I>(lines: I) -> impl Iterator<Item = Result<usize, AocError>> + 'a
+where
+    I: IntoIterator<Item = &'a str>,
+    I::IntoIter: 'a,
+{
+    calibrations(lines).map(|calibration| Ok(calibration?.value))
+}
+
+fn each_number(input: &str) -> Result<Vec<usize>, AocError> {
+    let mut v = Vec::new();
+    for p in 0..input.len() {
+        let (_, (_, option)) = tuple((take(p), parse_numeric))(input)?;
+        if let Some(num) = option {
+            v.push(num);
+        }
+    }
+    Ok(v)
+}
+
+fn parse_numeric(input: &str) -> IResult<&str, Option<usize>> {
+    alt((
+        value(Some(1), alt((tag("1"), tag("one")))),
+        value(Some(2), alt((tag("2"), tag("two")))),
+        value(Some(3), alt((tag("3"), tag("three")))),
+        value(Some(4), alt((tag("4"), tag("four")))),
+        value(Some(5), alt((tag("5"), tag("five")))),
+        value(Some(6), alt((tag("6"), tag("six")))),
+        value(Some(7), alt((tag("7"), tag("seven")))),
+        value(Some(8), alt((tag("8"), tag("eight")))),
+        value(Some(9), alt((tag("9"), tag("nine")))),
+        value(None, take(1usize)),
+    ))(input)
+}
+
+/// Parses from every position in each line in turn, which is how part 2 was first solved
+pub fn part2_nom(input: &str) -> Result<Answer, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let v = each_number(line)?;
+            match (v.first(), v.last()) {
+                (Some(a), Some(b)) => Ok((a * 10) + b),
+                _ => Err(no_number(line)),
+            }
+        })
+        .sum::<Result<usize, AocError>>()
+        .map(Answer::from)
+}
+
+pub struct Day01;
+
+impl Day for Day01 {
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    /// The lines of the calibration document, the parts don't agree on what the numbers in them are
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        lines
+            .iter()
+            .map(|line| Ok((first_number_char(line)? * 10) + last_number_char(line)?))
+            .sum::<Result<usize, AocError>>()
+            .map(Answer::from)
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        calibration_values(lines.iter().copied())
+            .sum::<Result<usize, AocError>>()
+            .map(Answer::from)
+    }
+}
+
+solution! {
+    Day01 {
+        "d01_part1.txt" => part1 "142";
+        "d01_part2.txt" => part2 "281";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part2_nom(2, Solved).named("nom"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day01::part1(&Day01::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day01::part2(&Day01::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_numeric() {
+        assert_eq!(parse_numeric("1"), Ok(((""), Some(1))));
+        assert_eq!(parse_numeric("a1"), Ok((("1"), None)));
+        assert_eq!(parse_numeric("one2"), Ok((("2"), Some(1))));
+    }
+
+    #[test]
+    fn test_each_number() {
+        assert_eq!(each_number("oneight"), Ok(vec![1, 8]));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(Digits::new("oneight").collect::<Vec<_>>(), vec![1, 8]);
+        assert_eq!(Digits::new("ninine").collect::<Vec<_>>(), vec![9]);
+        assert_eq!(
+            Digits::new("sevenine2threeight").collect::<Vec<_>>(),
+            vec![7, 9, 2, 3, 8]
+        );
+        assert_eq!(Digits::new("x1twone").collect::<Vec<_>>(), vec![1, 2, 1]);
+        for line in include_str!("examples/d01_part2.txt").lines() {
+            assert_eq!(
+                Digits::new(line).collect::<Vec<_>>(),
+                each_number(line).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibration_values() {
+        let values: Result<Vec<_>, _> = calibration_values(["two1nine", "7", "eightwo"]).collect();
+        assert_eq!(values, Ok(vec![29, 77, 82]));
+        assert!(calibration_values(["abc"]).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_calibrations() {
+        let input = include_str!("examples/d01_part2.txt");
+        let calibrations: Vec<_> = calibrations(input.lines()).take(2).collect();
+        assert_eq!(
+            calibrations,
+            vec![
+                Ok(Calibration {
+                    line: "two1nine",
+                    first_digit: 2,
+                    last_digit: 9,
+                    value: 29
+                }),
+                Ok(Calibration {
+                    line: "eightwothree",
+                    first_digit: 8,
+                    last_digit: 3,
+                    value: 83
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,426 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::space0;
+use nom::combinator::all_consuming;
+use nom::sequence::tuple;
+use nom::IResult;
+use std::cmp::max;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::solution;
+use crate::solver::Day;
+
+/// How many cubes of one colour were shown
+#[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
+pub enum Color {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Red(red) => write!(f, "{red} red"),
+            Color::Green(green) => write!(f, "{green} green"),
+            Color::Blue(blue) => write!(f, "{blue} blue"),
+        }
+    }
+}
+
+/// How many cubes of each colour, either shown at once or in the bag
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Set {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+/// The bag part 1 asks about, unless the `red`, `green` or `blue` day parameters say otherwise
+const BAG: Set = Set::new(12, 13, 14);
+
+impl From<Vec<Color>> for Set {
+    fn from(value: Vec<Color>) -> Self {
+        let mut set = Set::default();
+        for color in value {
+            match color {
+                Color::Red(red) => set.red = red,
+                Color::Green(green) => set.green = green,
+                Color::Blue(blue) => set.blue = blue,
+            }
+        }
+        set
+    }
+}
+
+impl Set {
+    pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    fn colors(&self) -> [Color; 3] {
+        [
+            Color::Red(self.red),
+            Color::Green(self.green),
+            Color::Blue(self.blue),
+        ]
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+/// Why a game couldn't have been played with a bag: one of its reveals showed more of a colour
+/// than the bag has
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Impossible {
+    pub game: u32,
+    /// Which of the game's reveals it was, counting from 0
+    pub reveal: usize,
+    pub shown: Color,
+    pub in_bag: Color,
+}
+
+impl Display for Impossible {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "game {} showed {} in reveal {}, but the bag only has {}",
+            self.game,
+            self.shown,
+            self.reveal + 1,
+            self.in_bag
+        )
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Game {
+    number: u32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    pub fn new(number: u32, sets: Vec<Set>) -> Self {
+        Game { number, sets }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// What was shown each time cubes were taken from the bag
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+
+    pub fn is_possible(&self, bag: &Set) -> bool {
+        self.check(bag).is_ok()
+    }
+
+    /// Whether the game could have been played with `bag`, and if not the first reveal that shows
+    /// it couldn't
+    pub fn check(&self, bag: &Set) -> Result<(), Impossible> {
+        for (reveal, set) in self.sets.iter().enumerate() {
+            let too_many = set
+                .colors()
+                .into_iter()
+                .zip(bag.colors())
+                .find(|(shown, in_bag)| shown > in_bag);
+            if let Some((shown, in_bag)) = too_many {
+                return Err(Impossible {
+                    game: self.number,
+                    reveal,
+                    shown,
+                    in_bag,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn min_set(&self) -> Set {
+        self.sets.iter().fold(Set::default(), |acc, cur| {
+            Set::new(
+                max(acc.red, cur.red),
+                max(acc.green, cur.green),
+                max(acc.blue, cur.blue),
+            )
+        })
+    }
+}
+
+/// Check every game against `bag`, eg to find which could have been played with it and why the
+/// rest couldn't
+pub fn check_games<'a>(
+    games: &'a [Game],
+    bag: &'a Set,
+) -> impl Iterator<Item = (&'a Game, Result<(), Impossible>)> + 'a {
+    games.iter().map(move |game| (game, game.check(bag)))
+}
+
+/// The bag to check the games against, from the day's parameters
+fn bag() -> Result<Set, AocError> {
+    Ok(Set::new(
+        day_param(2, "red")?.unwrap_or(BAG.red),
+        day_param(2, "green")?.unwrap_or(BAG.green),
+        day_param(2, "blue")?.unwrap_or(BAG.blue),
+    ))
+}
+
+/// A line that isn't a game, saying where and what the problem is
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum GameError {
+    #[error("line {line} should start with \"Game <number>: \", not {start:?}")]
+    Number { line: usize, start: String },
+    #[error("line {line}, game {game}: {token:?} isn't a number of red, green or blue cubes")]
+    Color {
+        line: usize,
+        game: u32,
+        token: String,
+    },
+}
+
+impl From<GameError> for AocError {
+    fn from(error: GameError) -> Self {
+        AocError::parse(error)
+    }
+}
+
+/// ```rust
+/// assert_eq!(true, true);
+/// ```
+fn parse_red(input: &str) -> IResult<&str, Color> {
+    let (remainder, (red, _, _)) = tuple((complete::u32, space0, tag("red")))(input)?;
+    Ok((remainder, Color::Red(red)))
+}
+
+fn parse_green(input: &str) -> IResult<&str, Color> {
+    let (remainder, (green, _, _)) = tuple((complete::u32, space0, tag("green")))(input)?;
+    Ok((remainder, Color::Green(green)))
+}
+
+fn parse_blue(input: &str) -> IResult<&str, Color> {
+    let (remainder, (blue, _, _)) = tuple((complete::u32, space0, tag("blue")))(input)?;
+    Ok((remainder, Color::Blue(blue)))
+}
+
+fn parse_color(input: &str) -> IResult<&str, Color> {
+    alt((parse_red, parse_green, parse_blue))(input)
+}
+
+/// The cubes shown in one reveal, or the first part of it that isn't a colour
+fn parse_set(input: &str) -> Result<Set, &str> {
+    input
+        .split(", ")
+        .map(|token| {
+            all_consuming(parse_color)(token)
+                .map(|(_, color)| color)
+                .map_err(|_| token)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Set::from)
+}
+
+fn parse_game_number(input: &str) -> IResult<&str, u32> {
+    let (remainder, (_, num, _)) = tuple((tag("Game "), complete::u32, tag(": ")))(input)?;
+    Ok((remainder, num))
+}
+
+/// The game on line number `line`
+fn parse_game(line: usize, input: &str) -> Result<Game, GameError> {
+    let (reveals, number) = parse_game_number(input).map_err(|_| GameError::Number {
+        line,
+        start: input.chars().take(10).collect(),
+    })?;
+    let sets = reveals
+        .split("; ")
+        .map(|reveal| {
+            parse_set(reveal).map_err(|token| GameError::Color {
+                line,
+                game: number,
+                token: token.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Game::new(number, sets))
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>, AocError> {
+    Ok(input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_game(index + 1, line))
+        .collect::<Result<_, _>>()?)
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_games(input)
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let bag = bag()?;
+        Ok(check_games(games, &bag)
+            .filter_map(|(game, checked)| match checked {
+                Ok(()) => Some(game.number),
+                Err(impossible) => {
+                    debug!("{impossible}");
+                    None
+                }
+            })
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(games
+            .iter()
+            .map(|game| game.min_set())
+            .map(|set| set.power())
+            .sum::<u32>()
+            .into())
+    }
+}
+
+solution! {
+    Day02 {
+        "d02.txt" => part1 "8", part2 "2286";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day02::part1(&Day02::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day02::part2(&Day02::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_game_is_possible() {
+        let set_1 = Set::new(1, 1, 1);
+        let set_2 = Set::new(1, 2, 3);
+        let set_3 = Set::new(3, 2, 1);
+
+        let game_1 = Game::new(1, vec![set_1]);
+        assert!(game_1.is_possible(&set_2));
+
+        let game_2 = Game::new(2, vec![set_1, set_2]);
+        assert!(game_2.is_possible(&set_2));
+
+        // Not possible for game 3 to been made with set 3
+        let game_3 = Game::new(3, vec![set_1, set_2]);
+        assert!(!game_3.is_possible(&set_3));
+    }
+
+    #[test]
+    fn test_check_games() {
+        let games = Day02::parse(include_str!("examples/d02.txt")).unwrap();
+        let impossible: Vec<_> = check_games(&games, &BAG)
+            .filter_map(|(_, checked)| checked.err())
+            .collect();
+        assert_eq!(
+            impossible,
+            vec![
+                Impossible {
+                    game: 3,
+                    reveal: 0,
+                    shown: Color::Red(20),
+                    in_bag: Color::Red(12)
+                },
+                Impossible {
+                    game: 4,
+                    reveal: 2,
+                    shown: Color::Red(14),
+                    in_bag: Color::Red(12)
+                },
+            ]
+        );
+        assert_eq!(
+            impossible[0].to_string(),
+            "game 3 showed 20 red in reveal 1, but the bag only has 12 red"
+        );
+        let possible = check_games(&games, &Set::new(20, 20, 20))
+            .filter(|(_, checked)| checked.is_ok())
+            .count();
+        assert_eq!(possible, 5);
+    }
+
+    #[test]
+    fn test_parse_color() {
+        let red = "3 red";
+        let green = "2 green";
+        let blue = "1 blue";
+
+        assert_eq!(parse_color(red), Ok(("", Color::Red(3))));
+        assert_eq!(parse_color(green), Ok(("", Color::Green(2))));
+        assert_eq!(parse_color(blue), Ok(("", Color::Blue(1))));
+    }
+
+    #[test]
+    fn test_parse_set() {
+        let set_1 = "3 red, 2 green, 1 blue";
+        let set_2 = "3 red, 2 green";
+        let set_3 = "2 green, 3 red";
+
+        assert_eq!(parse_set(set_1), Ok(Set::new(3, 2, 1)));
+        assert_eq!(parse_set(set_2), Ok(Set::new(3, 2, 0)));
+        assert_eq!(parse_set(set_3), Ok(Set::new(3, 2, 0)));
+        assert_eq!(parse_set("2 green, 3 purple"), Err("3 purple"));
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let parsed_game = parse_game(1, game).unwrap();
+
+        let game_number = 1;
+        let set_1 = Set::new(4, 0, 3);
+        let set_2 = Set::new(1, 2, 6);
+        let set_3 = Set::new(0, 2, 0);
+
+        assert_eq!(parsed_game.number, game_number);
+        assert!(parsed_game.sets.contains(&set_1));
+        assert!(parsed_game.sets.contains(&set_2));
+        assert!(parsed_game.sets.contains(&set_3));
+    }
+
+    #[test]
+    fn test_parse_games_errors() {
+        assert_eq!(
+            parse_games("Game 1: 3 blue\nGame 2: 1 red, 2 grene; 3 blue").unwrap_err(),
+            AocError::parse(GameError::Color {
+                line: 2,
+                game: 2,
+                token: "2 grene".to_string()
+            })
+        );
+        assert_eq!(
+            parse_game(3, "Gaem 3: 1 red").unwrap_err().to_string(),
+            r#"line 3 should start with "Game <number>: ", not "Gaem 3: 1 ""#
+        );
+        assert_eq!(parse_games("Game 1: 3 blue\n\n").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,354 @@
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::{self, Pos};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Copy, Clone, Debug)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Number {
+    position: Position,
+    value: usize,
+    len: usize,
+}
+
+impl Number {
+    pub fn is_adjacent(&self, other: Position) -> bool {
+        other.x >= self.position.x.saturating_sub(1)
+            && other.x <= self.position.x.saturating_add(self.len)
+            && other.y >= self.position.y.saturating_sub(1)
+            && other.y <= self.position.y.saturating_add(1)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Symbol {
+    position: Position,
+    symbol: char,
+}
+
+/// What a cell of the schematic is
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mark {
+    Blank,
+    /// A digit of a number next to a symbol
+    Part,
+    /// A digit of a number that isn't next to any symbol
+    NotPart,
+    Symbol,
+    /// A symbol that's a gear, by whichever [GearRule]
+    Gear,
+}
+
+/// How a gear's numbers make its ratio
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+impl Combine {
+    fn apply(self, numbers: impl Iterator<Item = usize>) -> usize {
+        match self {
+            Combine::Product => numbers.product(),
+            Combine::Sum => numbers.sum(),
+        }
+    }
+}
+
+/// What counts as a gear: a `symbol` next to exactly `numbers` numbers, whose ratio is what
+/// `combine` makes of them. Part 2's gears are the [Default], a `*` next to two numbers multiplied
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbol: char,
+    pub numbers: usize,
+    pub combine: Combine,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        Self {
+            symbol: '*',
+            numbers: 2,
+            combine: Combine::Product,
+        }
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct Grid {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// Which of `numbers`, if any, covers each cell, to look up what's next to a symbol
+    number_at: grid::Grid<Option<usize>>,
+}
+
+impl Grid {
+    /// Which of the numbers are next to `position`, diagonals included, each only once
+    fn numbers_around(&self, position: Position) -> impl Iterator<Item = usize> + '_ {
+        let columns = position.x.saturating_sub(1)..=position.x + 1;
+        (position.y.saturating_sub(1)..=position.y + 1)
+            .flat_map(move |row| columns.clone().map(move |column| Pos::new(row, column)))
+            .filter_map(|pos| *self.number_at.get(pos)?)
+            // A number's cells are all on one row and next to each other
+            .dedup()
+    }
+
+    pub fn get_missing_engine_part(&self) -> usize {
+        let mut is_part = vec![false; self.numbers.len()];
+        for symbol in &self.symbols {
+            for number in self.numbers_around(symbol.position) {
+                is_part[number] = true;
+            }
+        }
+        self.numbers
+            .iter()
+            .zip(is_part)
+            .filter(|(_, is_part)| *is_part)
+            .map(|(n, _)| n.value)
+            .sum()
+    }
+
+    pub fn get_gear_ratios(&self) -> Vec<usize> {
+        self.gear_ratios(&GearRule::default())
+    }
+
+    /// The ratio of each gear, by what `rule` says a gear is and how its numbers make a ratio
+    pub fn gear_ratios(&self, rule: &GearRule) -> Vec<usize> {
+        self.symbols
+            .iter()
+            .filter(|s| s.symbol == rule.symbol)
+            .map(|s| self.numbers_around(s.position).collect::<Vec<_>>())
+            .filter(|n| n.len() == rule.numbers)
+            .map(|n| rule.combine.apply(n.iter().map(|&n| self.numbers[n].value)))
+            .collect()
+    }
+
+    /// What each cell of the schematic turned out to be, for drawing it to see why a number was
+    /// or wasn't counted
+    pub fn marks(&self, rule: &GearRule) -> grid::Grid<Mark> {
+        let mut marks =
+            grid::Grid::filled(self.number_at.width(), self.number_at.height(), Mark::Blank);
+        let mut is_part = vec![false; self.numbers.len()];
+        for symbol in &self.symbols {
+            let around: Vec<_> = self.numbers_around(symbol.position).collect();
+            for &number in &around {
+                is_part[number] = true;
+            }
+            let is_gear = symbol.symbol == rule.symbol && around.len() == rule.numbers;
+            let Position { x, y } = symbol.position;
+            marks[Pos::new(y, x)] = if is_gear { Mark::Gear } else { Mark::Symbol };
+        }
+        for (number, is_part) in self.numbers.iter().zip(is_part) {
+            let Position { x, y } = number.position;
+            for x in x..x + number.len {
+                marks[Pos::new(y, x)] = if is_part { Mark::Part } else { Mark::NotPart };
+            }
+        }
+        marks
+    }
+
+    /// Checks every number against every symbol, which is how the parts were first solved
+    fn get_missing_engine_part_by_scanning(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|n| self.symbols.iter().any(|s| n.is_adjacent(s.position)))
+            .map(|n| n.value)
+            .sum()
+    }
+
+    fn get_gear_ratios_by_scanning(&self) -> Vec<usize> {
+        self.symbols
+            .iter()
+            .filter(|s| s.symbol == '*')
+            .map(|s| {
+                self.numbers
+                    .iter()
+                    .filter(|n| n.is_adjacent(s.position))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|n| n.len() == 2)
+            .map(|n| n[0].value * n[1].value)
+            .collect()
+    }
+}
+
+fn fill_grid(input: &str) -> Result<Grid, AocError> {
+    let mut grid = Grid::default();
+
+    for (y, line) in input.lines().enumerate() {
+        let mut iter = line.chars().enumerate().peekable();
+        while let Some((x, char)) = iter.next() {
+            if char == '.' {
+                continue;
+            }
+
+            let position = Position { x, y };
+
+            if char.is_numeric() {
+                let mut number = String::new();
+                number.push(char);
+
+                while iter.peek().map(|(_, c)| c.is_numeric()) == Some(true) {
+                    number.push(iter.next().map(|(_, c)| c).unwrap())
+                }
+                let len = number.len();
+                let number = Number {
+                    position,
+                    value: number
+                        .parse()
+                        .map_err(|_| AocError::parse(format!("{number} isn't a number")))?,
+                    len,
+                };
+                grid.numbers.push(number);
+            } else {
+                let symbol = Symbol {
+                    position,
+                    symbol: char,
+                };
+                grid.symbols.push(symbol);
+            }
+        }
+    }
+    let width = input.lines().map(|line| line.chars().count()).max();
+    let height = input.lines().count();
+    grid.number_at = grid::Grid::filled(width.unwrap_or_default(), height, None);
+    for (index, number) in grid.numbers.iter().enumerate() {
+        let Position { x, y } = number.position;
+        for x in x..x + number.len {
+            grid.number_at[Pos::new(y, x)] = Some(index);
+        }
+    }
+    Ok(grid)
+}
+
+pub struct Day03;
+
+impl Day for Day03 {
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Parsed<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        fill_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(grid.get_missing_engine_part().into())
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(grid.get_gear_ratios().iter().sum::<usize>().into())
+    }
+}
+
+solution! {
+    Day03 {
+        "d03.txt" => part1 "4361", part2 "467835";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_scan(1, Solved).named("scan"),
+    part2_scan(2, Solved).named("scan"),
+}
+
+pub fn part1_scan(input: &str) -> Result<Answer, AocError> {
+    Ok(fill_grid(input)?
+        .get_missing_engine_part_by_scanning()
+        .into())
+}
+
+pub fn part2_scan(input: &str) -> Result<Answer, AocError> {
+    Ok(fill_grid(input)?
+        .get_gear_ratios_by_scanning()
+        .iter()
+        .sum::<usize>()
+        .into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day03::part1(&Day03::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day03::part2(&Day03::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::synthetic_schematic;
+
+    #[test]
+    fn test_numbers_around() {
+        let grid = fill_grid(include_str!("examples/d03.txt")).unwrap();
+        let values = |position| {
+            grid.numbers_around(position)
+                .map(|number| grid.numbers[number].value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(Position { x: 3, y: 1 }), vec![467, 35]);
+        assert_eq!(values(Position { x: 0, y: 0 }), vec![467]);
+        assert_eq!(values(Position { x: 9, y: 9 }), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_gear_ratios() {
+        let grid = Day03::parse(include_str!("examples/d03.txt")).unwrap();
+        assert_eq!(grid.get_gear_ratios(), vec![16345, 451490]);
+        let rule = GearRule {
+            combine: Combine::Sum,
+            ..GearRule::default()
+        };
+        assert_eq!(grid.gear_ratios(&rule), vec![502, 1353]);
+        let rule = GearRule {
+            numbers: 1,
+            ..GearRule::default()
+        };
+        assert_eq!(grid.gear_ratios(&rule), vec![617]);
+        let rule = GearRule {
+            symbol: '$',
+            numbers: 1,
+            combine: Combine::Sum,
+        };
+        assert_eq!(grid.gear_ratios(&rule), vec![664]);
+    }
+
+    #[test]
+    fn test_marks() {
+        let grid = Day03::parse(include_str!("examples/d03.txt")).unwrap();
+        let marks = grid.marks(&GearRule::default());
+        let row = |row| {
+            (0..marks.width())
+                .map(|column| match marks[Pos::new(row, column)] {
+                    Mark::Blank => '.',
+                    Mark::Part => 'P',
+                    Mark::NotPart => 'N',
+                    Mark::Symbol => 'S',
+                    Mark::Gear => 'G',
+                })
+                .collect::<String>()
+        };
+        assert_eq!(row(0), "PPP..NNN..");
+        assert_eq!(row(1), "...G......");
+        assert_eq!(row(4), "PPPS......");
+    }
+
+    #[test]
+    fn test_indexed_agrees_with_scanning() {
+        let grid = fill_grid(&synthetic_schematic(200)).unwrap();
+        assert_eq!(
+            grid.get_missing_engine_part(),
+            grid.get_missing_engine_part_by_scanning()
+        );
+        assert_eq!(grid.get_gear_ratios(), grid.get_gear_ratios_by_scanning());
+    }
+}
@@ -0,0 +1,228 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{self, space0, space1};
+use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::IResult;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::parse::{finish, lines, numbers};
+use crate::solution;
+use crate::solver::Day;
+
+/// A scratchcard, which can be written out as JSON for other tools to read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Card {
+    pub number: u32,
+    pub winning_numbers: Vec<u32>,
+    pub card_numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn new(number: u32, winning_numbers: Vec<u32>, card_numbers: Vec<u32>) -> Self {
+        Self {
+            number,
+            winning_numbers,
+            card_numbers,
+        }
+    }
+
+    /// What part 1 says the card is worth, a point for the first match then doubled for each other
+    pub fn score(&self) -> usize {
+        let matches = self.num_matches();
+        if matches > 0 {
+            2usize.pow(matches as u32 - 1)
+        } else {
+            0
+        }
+    }
+
+    /// How many of the winning numbers the card has, which is how many of the next cards it wins
+    pub fn num_matches(&self) -> usize {
+        self.winning_numbers
+            .iter()
+            .filter(|w| self.card_numbers.contains(w))
+            .count()
+    }
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(space0, numbers(complete::u32))(input)
+}
+
+fn parse_card(card: &str) -> IResult<&str, Card> {
+    let (remainder, (number, (winning_numbers, card_numbers))) = tuple((
+        delimited(
+            tuple((tag("Card"), space1)),
+            complete::u32,
+            tuple((tag(":"), space1)),
+        ),
+        separated_pair(parse_numbers, tag(" | "), parse_numbers),
+    ))(card)?;
+
+    Ok((remainder, Card::new(number, winning_numbers, card_numbers)))
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, AocError> {
+    finish(lines(parse_card), input)
+}
+
+/// The first attempt at part 2, copying every card won one at a time. Much slower, but still
+/// correct, run it with `--impl queue`
+pub fn part2_queue(input: &str) -> Result<Answer, AocError> {
+    // How many cards did we process
+    let mut card_count = 0;
+
+    // We'll keep a static collection of cards to copy
+    let original_cards = parse_cards(input)?;
+
+    // And use a queue to process each card we work with
+    let mut to_process: Vec<_> = original_cards.iter().collect();
+
+    while let Some(c) = to_process.pop() {
+        card_count += 1;
+        let matches = c.num_matches();
+        for card_num_minus_1 in (0..matches).map(|i| i + (c.number as usize)) {
+            to_process.push(&original_cards[card_num_minus_1]);
+        }
+    }
+
+    Ok(card_count.into())
+}
+
+/// How many of each card there are once every card won has been won, counting rather than copying.
+/// Cards only win copies of cards after them, so by the time a card is reached it's known how many
+/// of it there are, and each of those wins one of each of the next few cards
+pub fn card_counts(cards: &[Card]) -> Vec<usize> {
+    let mut counts = vec![1; cards.len()];
+    for (index, card) in cards.iter().enumerate() {
+        let won = index + 1..(index + 1 + card.num_matches()).min(cards.len());
+        for copied in won {
+            counts[copied] += counts[index];
+        }
+    }
+    counts
+}
+
+/// How part 2 went for one card
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardTally {
+    pub number: u32,
+    pub matches: usize,
+    /// How many of the card there were in the end, the original and every copy won
+    pub copies: usize,
+}
+
+/// How many numbers each card matched and how many of it there ended up being
+pub fn card_tallies(cards: &[Card]) -> Vec<CardTally> {
+    cards
+        .iter()
+        .zip(card_counts(cards))
+        .map(|(card, copies)| CardTally {
+            number: card.number,
+            matches: card.num_matches(),
+            copies,
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Parsed<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(cards.iter().map(Card::score).sum::<usize>().into())
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(card_counts(cards).iter().sum::<usize>().into())
+    }
+}
+
+solution! {
+    Day04 {
+        "d04.txt" => part1 "13", part2 "30";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part2_queue(2, Solved).named("queue"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day04::part1(&Day04::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day04::part2(&Day04::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_card_score() {
+        let card = Card {
+            number: 1,
+            winning_numbers: vec![41, 48, 83, 86, 17],
+            card_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        };
+        assert_eq!(card.score(), 8);
+    }
+
+    /// `count` cards, each winning as many of the cards after it as `matches` says
+    fn generated_cards(count: usize, matches: impl Fn(usize) -> usize) -> String {
+        (1..=count)
+            .map(|number| {
+                let matches = matches(number);
+                let card_numbers = (1..=matches).chain(50..58 - matches).join(" ");
+                format!("Card {number}: 1 2 3 4 5 | {card_numbers}")
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn test_card_tallies() {
+        let cards = Day04::parse(include_str!("examples/d04.txt")).unwrap();
+        let tallies = card_tallies(&cards);
+        let copies: Vec<_> = tallies.iter().map(|tally| tally.copies).collect();
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(
+            serde_json::to_string(&tallies[1]).unwrap(),
+            r#"{"number":2,"matches":2,"copies":2}"#
+        );
+        let json = serde_json::to_string(&cards[0]).unwrap();
+        assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), cards[0]);
+    }
+
+    #[test]
+    fn test_counting_agrees_with_queue() {
+        // Every third card wins the two after it, so the queue doesn't take forever
+        let input = generated_cards(10_000, |number| {
+            if number % 3 == 1 && number < 10_000 {
+                2
+            } else {
+                0
+            }
+        });
+        assert_eq!(part2(&input).unwrap(), "16666");
+        assert_eq!(part2_queue(&input).unwrap(), "16666");
+    }
+
+    #[test]
+    fn test_counting_dense() {
+        // Every card wins the next, so there are `n` of the `n`th card. The queue would copy all
+        // 50 million of them one at a time
+        let input = generated_cards(10_000, |number| usize::from(number < 10_000));
+        assert_eq!(part2(&input).unwrap(), "50005000");
+    }
+}
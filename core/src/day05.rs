@@ -0,0 +1,954 @@
+use derive_more::From;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{self, newline, space1};
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, terminated, tuple};
+use nom::IResult;
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use thiserror::Error;
+use tracing::{trace, warn};
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::interval::Interval;
+use crate::parse::{finish, numbers, sections};
+use crate::progress::{NoProgress, Progress};
+use crate::solution;
+use crate::solver::Day;
+
+// Just making one place for all number types I can change later
+pub type Number = u64;
+
+/// Which step of the way from seed to location a [SeedMap] is for
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum MapType {
+    SeedToSoil,
+    SoilToFertilizer,
+    FertilizerToWater,
+    WaterToLight,
+    LightToTemperature,
+    TemperatureToHumidity,
+    HumidityToLocation,
+}
+
+impl MapType {
+    /// Every map type, in the order a seed goes through them
+    pub const ALL: [MapType; 7] = [
+        MapType::SeedToSoil,
+        MapType::SoilToFertilizer,
+        MapType::FertilizerToWater,
+        MapType::WaterToLight,
+        MapType::LightToTemperature,
+        MapType::TemperatureToHumidity,
+        MapType::HumidityToLocation,
+    ];
+}
+
+/// As the almanac names it, eg `seed-to-soil`
+impl Display for MapType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MapType::SeedToSoil => "seed-to-soil",
+            MapType::SoilToFertilizer => "soil-to-fertilizer",
+            MapType::FertilizerToWater => "fertilizer-to-water",
+            MapType::WaterToLight => "water-to-light",
+            MapType::LightToTemperature => "light-to-temperature",
+            MapType::TemperatureToHumidity => "temperature-to-humidity",
+            MapType::HumidityToLocation => "humidity-to-location",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One line of a map, moving `range` numbers from `source_start` on to `destination_start` on
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RangeMap {
+    source: Interval,
+    destination: Number,
+}
+
+impl RangeMap {
+    /// Note the almanac lists the destination first, this takes the source first
+    pub fn new(source_start: Number, destination_start: Number, range: Number) -> Self {
+        RangeMap {
+            source: Interval::new(source_start, source_start + range),
+            destination: destination_start,
+        }
+    }
+
+    fn contains(&self, number: Number) -> bool {
+        self.source.contains(number)
+    }
+
+    /// Where `number` goes, which is where it is if it isn't in the source
+    pub fn apply(&self, number: Number) -> Number {
+        if self.contains(number) {
+            number - self.source.start + self.destination
+        } else {
+            number
+        }
+    }
+
+    /// Which number this range moves to `number`, if any
+    fn source_of(&self, number: Number) -> Option<Number> {
+        let destination = Interval::new(self.destination, self.destination + self.source.len());
+        destination
+            .contains(number)
+            .then(|| number - self.destination + self.source.start)
+    }
+
+    /// Where the numbers go, all of which have to be in the source
+    fn apply_interval(&self, interval: Interval) -> Interval {
+        Interval::new(self.apply(interval.start), self.apply(interval.end - 1) + 1)
+    }
+}
+
+/// All the ranges for one step of the way from seed to location
+#[derive(Debug, PartialEq, Clone)]
+pub struct SeedMap {
+    map_type: MapType,
+    ranges: Vec<RangeMap>,
+}
+
+impl SeedMap {
+    pub fn new(map_type: MapType, ranges: Vec<RangeMap>) -> Self {
+        Self { map_type, ranges }
+    }
+
+    pub fn map_type(&self) -> MapType {
+        self.map_type
+    }
+
+    /// Where `number` goes with the first range that has it, which is where it is if none do
+    pub fn apply(&self, number: Number) -> Number {
+        if let Some(range) = self.ranges.iter().find(|r| r.contains(number)) {
+            range.apply(number)
+        } else {
+            number
+        }
+    }
+
+    /// Every number that [SeedMap::apply] takes to `number`. Ranges can overlap, so only the
+    /// numbers the first range to have them moves count, and `number` itself counts if no range
+    /// has it
+    pub fn sources(&self, number: Number) -> Vec<Number> {
+        let moved = self
+            .ranges
+            .iter()
+            .filter_map(|range| range.source_of(number))
+            .filter(|&source| self.apply(source) == number);
+        let unmoved = (!self.ranges.iter().any(|range| range.contains(number))).then_some(number);
+        moved.chain(unmoved).unique().collect()
+    }
+
+    /// Every pair of ranges whose sources overlap, the first of which has the numbers in both
+    pub fn overlapping_ranges(&self) -> impl Iterator<Item = (&RangeMap, &RangeMap)> {
+        self.ranges
+            .iter()
+            .tuple_combinations()
+            .filter(|(first, second)| first.source.overlaps(&second.source))
+    }
+
+    /// Where every number in `intervals` goes, split up where the ranges start and end. Numbers in
+    /// a range move with it and the rest stay where they are
+    pub fn apply_intervals(&self, intervals: Vec<Interval>) -> Vec<Interval> {
+        let mut unmapped = intervals;
+        let mut mapped = vec![];
+        for range in &self.ranges {
+            let mut outside = vec![];
+            for interval in unmapped {
+                let (below, rest) = interval.split_at(range.source.start);
+                let (inside, above) = rest
+                    .map(|rest| rest.split_at(range.source.end))
+                    .unwrap_or_default();
+                outside.extend(below.into_iter().chain(above));
+                mapped.extend(inside.map(|inside| range.apply_interval(inside)));
+            }
+            unmapped = outside;
+        }
+        Interval::merge(mapped.into_iter().chain(unmapped))
+    }
+}
+
+/// The maps taking a seed all the way to the location to plant it
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    seed_to_soil: SeedMap,
+    soil_to_fertilizer: SeedMap,
+    fertilizer_to_water: SeedMap,
+    water_to_light: SeedMap,
+    light_to_temperature: SeedMap,
+    temperature_to_humidity: SeedMap,
+    humidity_to_location: SeedMap,
+}
+
+impl Almanac {
+    /// An almanac from one map of each [MapType], in any order. A map type that's missing or
+    /// given more than once is an error. Ranges that overlap within a map are allowed, the first
+    /// one listed has the numbers in both, but they're probably a mistake so they're warned about
+    pub fn new(maps: Vec<SeedMap>) -> Result<Self, AlmanacError> {
+        let mut found: [Option<SeedMap>; 7] = Default::default();
+        for map in maps {
+            let slot = &mut found[map.map_type as usize];
+            if slot.is_some() {
+                return Err(AlmanacError::Duplicate(map.map_type));
+            }
+            for (first, second) in map.overlapping_ranges() {
+                warn!(
+                    "the {} map's ranges {first:?} and {second:?} overlap, {first:?} is used",
+                    map.map_type
+                );
+            }
+            *slot = Some(map);
+        }
+        let mut take = |map_type: MapType| {
+            found[map_type as usize]
+                .take()
+                .ok_or(AlmanacError::Missing(map_type))
+        };
+        Ok(Almanac {
+            seed_to_soil: take(MapType::SeedToSoil)?,
+            soil_to_fertilizer: take(MapType::SoilToFertilizer)?,
+            fertilizer_to_water: take(MapType::FertilizerToWater)?,
+            water_to_light: take(MapType::WaterToLight)?,
+            light_to_temperature: take(MapType::LightToTemperature)?,
+            temperature_to_humidity: take(MapType::TemperatureToHumidity)?,
+            humidity_to_location: take(MapType::HumidityToLocation)?,
+        })
+    }
+
+    /// Where to plant `seed`, having gone through every map
+    pub fn location(&self, seed: Number) -> Number {
+        self.maps()
+            .into_iter()
+            .fold(seed, |number, map| map.apply(number))
+    }
+
+    /// Every seed planted at `location`, going back through the maps
+    pub fn seeds_at(&self, location: Number) -> Vec<Number> {
+        self.maps()
+            .into_iter()
+            .rev()
+            .fold(vec![location], |numbers, map| {
+                numbers
+                    .into_iter()
+                    .flat_map(|number| map.sources(number))
+                    .collect()
+            })
+    }
+
+    /// The maps in the order a seed goes through them
+    pub fn maps(&self) -> [&SeedMap; 7] {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+    }
+}
+
+/// Why the maps parsed can't make an [Almanac]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AlmanacError {
+    #[error("there's no {0} map")]
+    Missing(MapType),
+    #[error("there's more than one {0} map")]
+    Duplicate(MapType),
+}
+
+impl From<AlmanacError> for AocError {
+    fn from(error: AlmanacError) -> Self {
+        AocError::parse(error)
+    }
+}
+
+/// Every map in an [Almanac] folded into one, taking a seed straight to its location. It's split
+/// into pieces of seeds that all move the same distance, in order and covering every seed
+#[derive(Debug, PartialEq, Clone)]
+pub struct CombinedMap {
+    /// Where each piece's seeds start and where the first of them ends up
+    pieces: Vec<(Interval, Number)>,
+}
+
+impl CombinedMap {
+    /// One map's pieces. Where its ranges overlap the first one listed has the numbers, the same
+    /// as [SeedMap::apply], and anything none of them have stays where it is
+    fn from_seed_map(map: &SeedMap) -> Self {
+        let mut unmapped = vec![Interval::new(0, Number::MAX)];
+        let mut pieces = vec![];
+        for range in &map.ranges {
+            let mut outside = vec![];
+            for interval in unmapped {
+                let (below, rest) = interval.split_at(range.source.start);
+                let (inside, above) = rest
+                    .map(|rest| rest.split_at(range.source.end))
+                    .unwrap_or_default();
+                outside.extend(below.into_iter().chain(above));
+                pieces.extend(inside.map(|inside| (inside, range.apply(inside.start))));
+            }
+            unmapped = outside;
+        }
+        pieces.extend(
+            unmapped
+                .into_iter()
+                .map(|interval| (interval, interval.start)),
+        );
+        pieces.sort();
+        Self { pieces }
+    }
+
+    /// Every map in the almanac, one after another in the order a seed goes through them
+    pub fn new(almanac: &Almanac) -> Self {
+        almanac
+            .maps()
+            .into_iter()
+            .map(Self::from_seed_map)
+            .reduce(|combined, next| combined.then(&next))
+            .expect("an almanac always has maps")
+    }
+
+    /// This map followed by `next`, each of this map's pieces split up where it lands on more than
+    /// one of `next`'s
+    fn then(&self, next: &CombinedMap) -> Self {
+        let mut pieces = vec![];
+        for &(source, destination) in &self.pieces {
+            let landing = Interval::new(destination, destination + source.len());
+            let first = next.piece_index(landing.start);
+            for &(next_source, next_destination) in &next.pieces[first..] {
+                let Some(part) = landing.intersect(&next_source) else {
+                    break;
+                };
+                let start = source.start + (part.start - landing.start);
+                pieces.push((
+                    Interval::new(start, start + part.len()),
+                    next_destination + (part.start - next_source.start),
+                ));
+            }
+        }
+        pieces.sort();
+        Self { pieces }
+    }
+
+    /// Which piece has `number`
+    fn piece_index(&self, number: Number) -> usize {
+        self.pieces
+            .partition_point(|(source, _)| source.end <= number)
+    }
+
+    /// Where to plant `seed`, the same as [Almanac::location]
+    pub fn location(&self, seed: Number) -> Number {
+        match self.pieces.get(self.piece_index(seed)) {
+            Some((source, destination)) if source.contains(seed) => {
+                seed - source.start + destination
+            }
+            _ => seed,
+        }
+    }
+
+    /// The nearest location any seed in `seeds` is planted at. Each piece moves its seeds the
+    /// same distance, so the nearest is the first seed in a piece
+    pub fn nearest_location(&self, seeds: Interval) -> Option<Number> {
+        self.pieces[self.piece_index(seeds.start)..]
+            .iter()
+            .map_while(|(source, destination)| {
+                let part = seeds.intersect(source)?;
+                Some(part.start - source.start + destination)
+            })
+            .min()
+    }
+
+    /// How many pieces the almanac folded down to
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+}
+
+/// The numbers on the seeds line, which part 1 reads as seeds and part 2 as ranges of them
+#[derive(Debug, PartialEq, From)]
+pub struct SeedsV(Vec<Number>);
+
+#[derive(Debug, PartialEq)]
+struct SeedsR(Range<Number>);
+
+type NumberIterator = dyn Iterator<Item = Number>;
+
+trait Seeds {
+    fn seed_iter(&self) -> Box<NumberIterator>;
+
+    fn nearest_seed_according_to_almanac<'a>(&'a self, almanac: &'a Almanac) -> Option<Number> {
+        self.seed_iter().map(|seed| almanac.location(seed)).min()
+    }
+}
+
+impl Seeds for SeedsV {
+    fn seed_iter(&self) -> Box<NumberIterator> {
+        Box::new(self.0.clone().into_iter())
+    }
+}
+
+impl Seeds for SeedsR {
+    fn seed_iter(&self) -> Box<NumberIterator> {
+        Box::new(self.0.clone())
+    }
+}
+
+impl From<&SeedsV> for Vec<SeedsR> {
+    fn from(seeds: &SeedsV) -> Self {
+        seeds
+            .0
+            .iter()
+            .copied()
+            .tuples()
+            .map(|(start, size)| SeedsR(start..(start + size)))
+            .collect()
+    }
+}
+
+fn parse_map_type(input: &str) -> IResult<&str, MapType> {
+    alt((
+        value(MapType::SeedToSoil, tag("seed-to-soil")),
+        value(MapType::SoilToFertilizer, tag("soil-to-fertilizer")),
+        value(MapType::FertilizerToWater, tag("fertilizer-to-water")),
+        value(MapType::WaterToLight, tag("water-to-light")),
+        value(MapType::LightToTemperature, tag("light-to-temperature")),
+        value(
+            MapType::TemperatureToHumidity,
+            tag("temperature-to-humidity"),
+        ),
+        value(MapType::HumidityToLocation, tag("humidity-to-location")),
+    ))(input)
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, SeedsV> {
+    let (remainder, seeds) = delimited(tag("seeds: "), numbers(complete::u64), newline)(input)?;
+    Ok((remainder, SeedsV(seeds)))
+}
+
+fn parse_range_map(input: &str) -> IResult<&str, RangeMap> {
+    let (remainder, (dest, _, source, _, range)) =
+        tuple((complete::u64, space1, complete::u64, space1, complete::u64))(input)?;
+    Ok((remainder, RangeMap::new(source, dest, range)))
+}
+
+fn parse_seed_map(input: &str) -> IResult<&str, SeedMap> {
+    let (remainder, (map_type, ranges)) = tuple((
+        terminated(parse_map_type, tuple((tag(" map:"), newline))),
+        separated_list1(newline, parse_range_map),
+    ))(input)?;
+    Ok((remainder, SeedMap::new(map_type, ranges)))
+}
+
+fn parse_almanac(input: &str) -> IResult<&str, (SeedsV, Vec<SeedMap>)> {
+    let (remainder, (seeds, _, maps)) =
+        tuple((parse_seeds, newline, sections(parse_seed_map)))(input)?;
+    Ok((remainder, (seeds, maps)))
+}
+
+fn read_almanac(input: &str) -> Result<(SeedsV, Almanac), AocError> {
+    let (seeds, maps) = finish(parse_almanac, input)?;
+    Ok((seeds, Almanac::new(maps)?))
+}
+
+fn no_seeds() -> AocError {
+    AocError::no_answer("there are no seeds")
+}
+
+/// The seeds as part 2 reads them, pairs of where a range of seeds starts and how many are in it
+fn seed_intervals(seeds: &SeedsV) -> impl Iterator<Item = Interval> + '_ {
+    seeds
+        .0
+        .iter()
+        .tuples()
+        .map(|(&start, &size)| Interval::new(start, start + size))
+}
+
+/// Part 2 reads the seeds as pairs of where a range of seeds starts and how many are in it. Rather
+/// than look up every seed, each map moves whole intervals of them at once
+fn nearest_seed_in_intervals(seeds: &SeedsV, almanac: &Almanac) -> Result<Answer, AocError> {
+    let locations = almanac
+        .maps()
+        .into_iter()
+        .fold(Interval::merge(seed_intervals(seeds)), |intervals, map| {
+            map.apply_intervals(intervals)
+        });
+    locations
+        .first()
+        .map(|nearest| Answer::from(nearest.start))
+        .ok_or_else(no_seeds)
+}
+
+/// Folds the almanac into a [CombinedMap] first, then looks up each seed with it
+pub fn part1_combined(input: &str) -> Result<Answer, AocError> {
+    let (seeds, almanac) = read_almanac(input)?;
+    let combined = CombinedMap::new(&almanac);
+    seeds
+        .seed_iter()
+        .map(|seed| combined.location(seed))
+        .min()
+        .map(Answer::from)
+        .ok_or_else(no_seeds)
+}
+
+/// Folds the almanac into a [CombinedMap] first, then finds the nearest location for each range of
+/// seeds without going through the maps one by one
+pub fn part2_combined(input: &str) -> Result<Answer, AocError> {
+    let (seeds, almanac) = read_almanac(input)?;
+    let combined = CombinedMap::new(&almanac);
+    seed_intervals(&seeds)
+        .filter_map(|seeds| combined.nearest_location(seeds))
+        .min()
+        .map(Answer::from)
+        .ok_or_else(no_seeds)
+}
+
+pub fn part2_reverse_with_progress(
+    input: &str,
+    progress: &dyn Progress,
+) -> Result<Answer, AocError> {
+    let (seeds, almanac) = read_almanac(input)?;
+    nearest_seed_in_reverse(&seeds, &almanac, progress)
+}
+
+/// Works backwards from the locations instead, trying each from 0 up until one has a seed that's in
+/// one of the ranges. It can't be further than the first seed's location, so that's as far as it
+/// looks
+fn nearest_seed_in_reverse(
+    seeds: &SeedsV,
+    almanac: &Almanac,
+    progress: &dyn Progress,
+) -> Result<Answer, AocError> {
+    let seeds = Interval::merge(seed_intervals(seeds));
+    let furthest = almanac.location(seeds.first().ok_or_else(no_seeds)?.start);
+    progress.set_length(furthest + 1);
+    for location in 0..=furthest {
+        if location > 0 && location % REVERSE_PROGRESS_STEP == 0 {
+            progress.inc(REVERSE_PROGRESS_STEP);
+            progress.check_cancelled()?;
+        }
+        let planted = almanac
+            .seeds_at(location)
+            .into_iter()
+            .any(|seed| seeds.iter().any(|seeds| seeds.contains(seed)));
+        if planted {
+            return Ok(location.into());
+        }
+    }
+    Err(AocError::no_answer("no location has a seed"))
+}
+
+/// How many locations [nearest_seed_in_reverse] tries between reporting progress
+const REVERSE_PROGRESS_STEP: Number = 1 << 16;
+
+/// Works backwards from the nearest location, see [nearest_seed_in_reverse]
+pub fn part2_reverse(input: &str) -> Result<Answer, AocError> {
+    part2_reverse_with_progress(input, &NoProgress)
+}
+
+pub fn part2_with_progress(input: &str, progress: &dyn Progress) -> Result<Answer, AocError> {
+    let (seeds, almanac) = read_almanac(input)?;
+    nearest_seed_in_ranges(&seeds, &almanac, progress)
+}
+
+/// Looks up every seed in every range, which is how part 2 was first solved
+pub fn part2_brute_force(input: &str) -> Result<Answer, AocError> {
+    part2_with_progress(input, &NoProgress)
+}
+
+fn nearest_seed_in_ranges(
+    seeds: &SeedsV,
+    almanac: &Almanac,
+    progress: &dyn Progress,
+) -> Result<Answer, AocError> {
+    let seed_ranges = Vec::from(seeds);
+    progress.set_length(seed_ranges.len() as u64);
+    let nearest = seed_ranges
+        .into_par_iter()
+        .map(|seeds| {
+            // A range can have billions of seeds in, so it's checked seed by seed
+            let nearest = seeds
+                .seed_iter()
+                .take_while(|_| !progress.is_cancelled())
+                .map(|seed| almanac.location(seed))
+                .min();
+            trace!(nearest, "searched a range of seeds");
+            progress.inc(1);
+            nearest
+        })
+        .flatten()
+        .min();
+    progress.check_cancelled()?;
+    nearest.map(Answer::from).ok_or_else(no_seeds)
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+    const DAY: usize = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Parsed<'a> = (SeedsV, Almanac);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        read_almanac(input)
+    }
+
+    fn part1((seeds, almanac): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        seeds
+            .nearest_seed_according_to_almanac(almanac)
+            .map(Answer::from)
+            .ok_or_else(no_seeds)
+    }
+
+    fn part2((seeds, almanac): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        nearest_seed_in_intervals(seeds, almanac)
+    }
+}
+
+solution! {
+    Day05 {
+        "d05.txt" => part1 "35", part2 "46";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_combined(1, Solved).named("combined"),
+    part2_combined(2, Solved).named("combined"),
+    part2_brute_force(2, Solved)
+        .named("brute_force")
+        .with_progress(part2_with_progress),
+    part2_reverse(2, Solved)
+        .named("reverse")
+        .with_progress(part2_reverse_with_progress),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day05::part1(&Day05::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day05::part2(&Day05::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::Random;
+
+    #[test]
+    fn test_parse_map_type() {
+        assert_eq!(
+            parse_map_type("seed-to-soil map:"),
+            Ok((" map:", MapType::SeedToSoil))
+        );
+        assert_eq!(
+            parse_map_type("soil-to-fertilizer map:"),
+            Ok((" map:", MapType::SoilToFertilizer))
+        );
+        assert_eq!(
+            parse_map_type("fertilizer-to-water map:"),
+            Ok((" map:", MapType::FertilizerToWater))
+        );
+        assert_eq!(
+            parse_map_type("water-to-light map:"),
+            Ok((" map:", MapType::WaterToLight))
+        );
+        assert_eq!(
+            parse_map_type("light-to-temperature map:"),
+            Ok((" map:", MapType::LightToTemperature))
+        );
+        assert_eq!(
+            parse_map_type("temperature-to-humidity map:"),
+            Ok((" map:", MapType::TemperatureToHumidity))
+        );
+        assert_eq!(
+            parse_map_type("humidity-to-location map:"),
+            Ok((" map:", MapType::HumidityToLocation))
+        );
+    }
+
+    #[test]
+    fn test_parse_seeds() {
+        let input = "seeds: 79 14 55 13
+some other stuff";
+        assert_eq!(
+            parse_seeds(input),
+            Ok(("some other stuff", SeedsV(vec![79u64, 14, 55, 13])))
+        )
+    }
+
+    #[test]
+    fn test_parse_range_map() {
+        let input = "50 98 2
+52 50 48";
+        assert_eq!(
+            parse_range_map(input),
+            Ok(("\n52 50 48", RangeMap::new(98, 50, 2)))
+        )
+    }
+
+    #[test]
+    fn test_parse_seed_map() {
+        let input = "light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:";
+        assert_eq!(
+            parse_seed_map(input),
+            Ok((
+                "\n\ntemperature-to-humidity map:",
+                SeedMap {
+                    map_type: MapType::LightToTemperature,
+                    ranges: vec![
+                        RangeMap::new(77, 45, 23),
+                        RangeMap::new(45, 81, 19),
+                        RangeMap::new(64, 68, 13),
+                    ]
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37";
+        // Theoretically, this either works or panics
+        let (remainder, _) = parse_almanac(input).unwrap();
+        assert_eq!(remainder, "");
+    }
+
+    #[test]
+    fn test_range() {
+        let range = RangeMap::new(98, 50, 2);
+
+        // In range
+        assert!(range.contains(99));
+        assert_eq!(range.apply(99), 51);
+
+        // Out of range
+        assert!(!range.contains(100));
+        assert_eq!(range.apply(100), 100);
+
+        assert_eq!(
+            range.apply_interval(Interval::new(98, 100)),
+            Interval::new(50, 52)
+        );
+    }
+
+    #[test]
+    fn test_almanac() {
+        let (_, almanac) = Day05::parse(include_str!("examples/d05.txt")).unwrap();
+        assert_eq!(almanac.location(79), 82);
+        assert_eq!(almanac.maps()[0].map_type(), MapType::SeedToSoil);
+        let soil = SeedMap::new(MapType::SeedToSoil, vec![RangeMap::new(98, 50, 2)]);
+        assert_eq!(soil.apply(99), 51);
+        assert_eq!(
+            Almanac::new(vec![soil]),
+            Err(AlmanacError::Missing(MapType::SoilToFertilizer))
+        );
+    }
+
+    #[test]
+    fn test_almanac_sections() {
+        let input = include_str!("examples/d05.txt");
+        let (seeds, sections) = input.split_once("\n\n").unwrap();
+        let sections: Vec<_> = sections.trim_end().split("\n\n").collect();
+
+        let reversed = format!("{seeds}\n\n{}\n", sections.iter().rev().join("\n\n"));
+        assert_eq!(Day05::parse(&reversed), Day05::parse(input));
+
+        let missing = format!("{seeds}\n\n{}\n", sections[1..].join("\n\n"));
+        assert_eq!(
+            Day05::parse(&missing).unwrap_err().to_string(),
+            "couldn't parse the input: there's no seed-to-soil map"
+        );
+
+        let duplicated = format!("{seeds}\n\n{}\n\n{}\n", sections.join("\n\n"), sections[2]);
+        assert_eq!(
+            Day05::parse(&duplicated).unwrap_err().to_string(),
+            "couldn't parse the input: there's more than one fertilizer-to-water map"
+        );
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        let map = SeedMap::new(
+            MapType::SeedToSoil,
+            vec![
+                RangeMap::new(10, 100, 10),
+                RangeMap::new(20, 200, 10),
+                RangeMap::new(15, 300, 10),
+            ],
+        );
+        let overlapping: Vec<_> = map.overlapping_ranges().collect();
+        assert_eq!(
+            overlapping,
+            vec![
+                (&map.ranges[0], &map.ranges[2]),
+                (&map.ranges[1], &map.ranges[2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_intervals() {
+        let seed_map = SeedMap {
+            map_type: MapType::SeedToSoil,
+            ranges: vec![RangeMap::new(98, 50, 2), RangeMap::new(50, 52, 48)],
+        };
+        assert_eq!(
+            seed_map.apply_intervals(vec![Interval::new(79, 93), Interval::new(96, 102)]),
+            vec![
+                Interval::new(50, 52),
+                Interval::new(81, 95),
+                Interval::new(98, 102)
+            ]
+        );
+    }
+
+    /// Seeds and an almanac with up to `ranges` ranges per map, all somewhere below `span`
+    fn generated_almanac(random: &mut Random, ranges: u64, span: u64) -> (SeedsV, Almanac) {
+        let size = span / 4;
+        let maps = MapType::ALL
+            .iter()
+            .map(|&map_type| {
+                let ranges = (0..=random.below(ranges))
+                    .map(|_| {
+                        let source = random.below(span);
+                        let destination = random.below(span);
+                        RangeMap::new(source, destination, random.below(size) + 1)
+                    })
+                    .collect();
+                SeedMap::new(map_type, ranges)
+            })
+            .collect();
+        let seeds = (0..3)
+            .flat_map(|_| [random.below(span), random.below(size) + 1])
+            .collect::<Vec<_>>();
+        (SeedsV::from(seeds), Almanac::new(maps).unwrap())
+    }
+
+    #[test]
+    fn test_intervals_agree_with_brute_force() {
+        let mut random = Random::new(0x9e37_79b9);
+        for _ in 0..50 {
+            let (seeds, almanac) = generated_almanac(&mut random, 5, 200);
+            assert_eq!(
+                nearest_seed_in_intervals(&seeds, &almanac),
+                nearest_seed_in_ranges(&seeds, &almanac, &NoProgress),
+                "{almanac:?} {seeds:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_combined_map() {
+        let (_, almanac) = read_almanac(include_str!("examples/d05.txt")).unwrap();
+        let combined = CombinedMap::new(&almanac);
+        for seed in [79, 14, 55, 13, 82, 0, 99, 100, 1000] {
+            assert_eq!(combined.location(seed), almanac.location(seed), "{seed}");
+        }
+        assert_eq!(combined.nearest_location(Interval::new(79, 93)), Some(46));
+
+        // The first range listed has the numbers where ranges overlap
+        let overlapping = SeedMap::new(
+            MapType::SeedToSoil,
+            vec![RangeMap::new(10, 100, 10), RangeMap::new(5, 200, 10)],
+        );
+        let combined = CombinedMap::from_seed_map(&overlapping);
+        assert_eq!(combined.location(12), 102);
+        assert_eq!(combined.location(7), 202);
+        assert_eq!(combined.location(20), 20);
+    }
+
+    #[test]
+    fn test_combined_agrees_with_maps() {
+        let mut random = Random::new(0x2545_f491);
+        for _ in 0..50 {
+            let (seeds, almanac) = generated_almanac(&mut random, 5, 200);
+            let combined = CombinedMap::new(&almanac);
+            for seed in 0..250 {
+                assert_eq!(combined.location(seed), almanac.location(seed), "{seed}");
+            }
+            let nearest = seed_intervals(&seeds)
+                .filter_map(|seeds| combined.nearest_location(seeds))
+                .min()
+                .map(Answer::from)
+                .ok_or_else(no_seeds);
+            assert_eq!(nearest, nearest_seed_in_intervals(&seeds, &almanac));
+        }
+    }
+
+    #[test]
+    fn test_seeds_at() {
+        let (_, almanac) = read_almanac(include_str!("examples/d05.txt")).unwrap();
+        for seed in [79, 14, 55, 13, 82, 0, 99, 100] {
+            assert!(almanac.seeds_at(almanac.location(seed)).contains(&seed));
+        }
+        let overlapping = SeedMap::new(
+            MapType::SeedToSoil,
+            vec![RangeMap::new(10, 100, 10), RangeMap::new(5, 100, 10)],
+        );
+        assert_eq!(overlapping.sources(102), vec![12, 7, 102]);
+        assert_eq!(overlapping.sources(100), vec![10, 5, 100]);
+        assert_eq!(overlapping.sources(12), Vec::<Number>::new());
+        assert_eq!(overlapping.sources(30), vec![30]);
+    }
+
+    #[test]
+    fn test_reverse_agrees_with_intervals() {
+        let mut random = Random::new(0x1405_7b7e);
+        for _ in 0..50 {
+            let (seeds, almanac) = generated_almanac(&mut random, 5, 200);
+            assert_eq!(
+                nearest_seed_in_reverse(&seeds, &almanac, &NoProgress),
+                nearest_seed_in_intervals(&seeds, &almanac),
+                "{almanac:?} {seeds:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{self, newline, space1};
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::parse::{finish, numbers};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, PartialEq)]
+pub struct TimeAndDistance {
+    time: u64,
+    distance: u64,
+}
+
+impl TimeAndDistance {
+    /// How far the boat goes holding the button for `held` milliseconds, as a `u128` so it can't
+    /// overflow even for the longest races
+    fn distance_travelled(&self, held: u64) -> u128 {
+        u128::from(self.time.saturating_sub(held)) * u128::from(held)
+    }
+
+    fn wins(&self, held: u64) -> bool {
+        self.distance_travelled(held) > u128::from(self.distance)
+    }
+
+    /// How many ways there are to hold the button and beat the record. The distance is
+    /// `held * (time - held)`, so the holds that beat it are the ones between the roots of
+    /// `held² - time * held + distance = 0`. The integer square root only gets near the first of
+    /// them, the exact distances settle which side of it the first win is
+    fn winning_possibilities(&self) -> u64 {
+        let time = u128::from(self.time);
+        let Some(discriminant) = (time * time).checked_sub(4 * u128::from(self.distance)) else {
+            return 0;
+        };
+        let mut first = ((time - discriminant.isqrt()) / 2) as u64;
+        while first > 0 && self.wins(first - 1) {
+            first -= 1;
+        }
+        while !self.wins(first) {
+            // Holding for half the time goes furthest
+            if first >= self.time / 2 {
+                return 0;
+            }
+            first += 1;
+        }
+        // Holding for `held` goes as far as holding for `time - held`
+        let last = self.time - first;
+        last - first + 1
+    }
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(space1, numbers(complete::u64))(input)
+}
+
+fn parse_time(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(tag("Time:"), parse_numbers)(input)
+}
+
+fn parse_distance(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(tag("Distance:"), parse_numbers)(input)
+}
+
+fn input_into_time_and_distance(input: &str) -> Result<Vec<TimeAndDistance>, AocError> {
+    let (times, distances) = finish(separated_pair(parse_time, newline, parse_distance), input)?;
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| TimeAndDistance { time, distance })
+        .collect())
+}
+
+/// The kerning was wrong, it's really one race with the digits of all of the times run together,
+/// and likewise for the distances
+fn join_races(races: &[TimeAndDistance]) -> Result<TimeAndDistance, AocError> {
+    let join = |numbers: Vec<u64>| {
+        numbers
+            .into_iter()
+            .try_fold(0u64, |joined, n| {
+                let digits = n.checked_ilog10().unwrap_or_default() + 1;
+                joined.checked_mul(10u64.pow(digits))?.checked_add(n)
+            })
+            .ok_or_else(|| AocError::parse("the joined up numbers are too big"))
+    };
+    Ok(TimeAndDistance {
+        time: join(races.iter().map(|race| race.time).collect())?,
+        distance: join(races.iter().map(|race| race.distance).collect())?,
+    })
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Parsed<'a> = Vec<TimeAndDistance>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        input_into_time_and_distance(input)
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(races
+            .iter()
+            .map(|race| race.winning_possibilities())
+            .product::<u64>()
+            .into())
+    }
+
+    fn part2(races: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(join_races(races)?.winning_possibilities().into())
+    }
+}
+
+solution! {
+    Day06 {
+        "d06.txt" => part1 "288", part2 "71503";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day06::part1(&Day06::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day06::part2(&Day06::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        let input = "  7  15   30";
+        assert_eq!(parse_numbers(input).unwrap().1, vec![7, 15, 30])
+    }
+
+    #[test]
+    fn test_parsers() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!(
+            input_into_time_and_distance(input),
+            Ok(vec![
+                TimeAndDistance {
+                    time: 7,
+                    distance: 9
+                },
+                TimeAndDistance {
+                    time: 15,
+                    distance: 40
+                },
+                TimeAndDistance {
+                    time: 30,
+                    distance: 200
+                },
+            ])
+        )
+    }
+
+    /// Tries every way of holding the button
+    fn winning_possibilities_by_counting(race: &TimeAndDistance) -> u64 {
+        (0..=race.time).filter(|&held| race.wins(held)).count() as u64
+    }
+
+    #[test]
+    fn test_winning_possibilities() {
+        for time in 0..60 {
+            for distance in 0..(time * time / 4 + 3) {
+                let race = TimeAndDistance { time, distance };
+                assert_eq!(
+                    race.winning_possibilities(),
+                    winning_possibilities_by_counting(&race),
+                    "{race:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_race() {
+        let race = TimeAndDistance {
+            time: u64::MAX,
+            distance: u64::MAX,
+        };
+        assert_eq!(race.winning_possibilities(), u64::MAX - 3);
+        let race = TimeAndDistance {
+            time: 4_000_000_000,
+            distance: 4_000_000_000_000_000_000,
+        };
+        assert_eq!(race.winning_possibilities(), 0);
+        let race = TimeAndDistance {
+            time: 4_000_000_000,
+            distance: 3_999_999_999_999_999_999,
+        };
+        assert_eq!(race.winning_possibilities(), 1);
+    }
+
+    #[test]
+    fn test_join_races() {
+        let races =
+            input_into_time_and_distance("Time:      7  15   30\nDistance:  9  40  200").unwrap();
+        assert_eq!(
+            join_races(&races),
+            Ok(TimeAndDistance {
+                time: 71530,
+                distance: 940200
+            })
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use nom::character::complete;
+use nom::character::complete::space1;
+use nom::combinator::map_opt;
+use nom::multi::fill;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::cmp::Ordering;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::parse::{finish, lines};
+use crate::solution;
+use crate::solver::Day;
+
+/// A card's face, in order from lowest to highest without any [Rules] changing that
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CardValue {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl CardValue {
+    /// The card written as `c`, eg `T` for [CardValue::Ten]
+    pub fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '2' => CardValue::Two,
+            '3' => CardValue::Three,
+            '4' => CardValue::Four,
+            '5' => CardValue::Five,
+            '6' => CardValue::Six,
+            '7' => CardValue::Seven,
+            '8' => CardValue::Eight,
+            '9' => CardValue::Nine,
+            'T' => CardValue::Ten,
+            'J' => CardValue::Jack,
+            'Q' => CardValue::Queen,
+            'K' => CardValue::King,
+            'A' => CardValue::Ace,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Hand([CardValue; 5]);
+
+impl Hand {
+    pub fn new(cards: [CardValue; 5]) -> Self {
+        Self(cards)
+    }
+
+    pub fn cards(&self) -> [CardValue; 5] {
+        self.0
+    }
+}
+
+/// How hands are ranked. The hand's type comes first, with any wild cards counting as whichever
+/// card makes it best, then the cards one by one. A wild card is the lowest card when comparing
+/// them one by one, and aces can be low too
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rules {
+    pub wild: Option<CardValue>,
+    pub aces_low: bool,
+}
+
+impl Rules {
+    /// Part 1's rules, no wild cards and aces high
+    pub const STANDARD: Rules = Rules {
+        wild: None,
+        aces_low: false,
+    };
+
+    /// Part 2's rules, where the `J`s are jokers
+    pub const JOKERS: Rules = Rules {
+        wild: Some(CardValue::Jack),
+        aces_low: false,
+    };
+
+    pub fn hand_type(&self, hand: &Hand) -> HandType {
+        let mut counts = [0u8; 13];
+        let mut wilds = 0;
+        for card in hand.0 {
+            if Some(card) == self.wild {
+                wilds += 1;
+            } else {
+                counts[card as usize] += 1;
+            }
+        }
+        // Only the two biggest groups matter, and the wild cards join the biggest
+        let (mut most, mut second) = (0, 0);
+        for count in counts {
+            if count > most {
+                (most, second) = (count, most);
+            } else if count > second {
+                second = count;
+            }
+        }
+        match (most + wilds, second) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// Where `card` comes when comparing cards one by one, at most 14
+    pub fn card_rank(&self, card: CardValue) -> u8 {
+        if Some(card) == self.wild {
+            0
+        } else if card == CardValue::Ace && self.aces_low {
+            1
+        } else {
+            card as u8 + 2
+        }
+    }
+
+    /// Everything the hand is ranked on packed into one number, so that comparing two hands'
+    /// keys compares the hands. The type is in the top bits, then four bits for each card's rank
+    pub fn sort_key(&self, hand: &Hand) -> u32 {
+        hand.0
+            .iter()
+            .fold(self.hand_type(hand) as u32, |key, &card| {
+                key << 4 | u32::from(self.card_rank(card))
+            })
+    }
+
+    pub fn compare(&self, a: &Hand, b: &Hand) -> Ordering {
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+
+    /// Each hand's bid multiplied by its rank, weakest first, all added up
+    pub fn total_winnings(&self, hands_and_bids: &[(Hand, u64)]) -> u64 {
+        let mut keys: Vec<_> = hands_and_bids
+            .iter()
+            .map(|(hand, bid)| (self.sort_key(hand), *bid))
+            .collect();
+        keys.sort_unstable();
+        keys.into_iter()
+            .zip(1..)
+            .map(|((_, bid), rank)| rank * bid)
+            .sum()
+    }
+
+    /// `default` changed by the day's parameters. `wild` is the card that's wild, or `none`, and
+    /// `aces_low` whether aces are low
+    fn from_params(default: Rules) -> Result<Rules, AocError> {
+        let wild = match day_param::<String>(7, "wild")? {
+            None => default.wild,
+            Some(wild) => Self::wild_from_param(&wild)?,
+        };
+        let aces_low = Self::aces_low_from_param(default)?;
+        Ok(Rules { wild, aces_low })
+    }
+
+    /// The card the `wild` parameter names, or no card for `none`
+    fn wild_from_param(wild: &str) -> Result<Option<CardValue>, AocError> {
+        if wild.is_empty() || wild == "none" {
+            return Ok(None);
+        }
+        wild.parse::<char>()
+            .ok()
+            .and_then(CardValue::from_char)
+            .map(Some)
+            .ok_or_else(|| AocError::Param(format!("7.wild: {wild:?} isn't a card")))
+    }
+
+    /// Whether `default` has aces low, changed by the `aces_low` parameter
+    fn aces_low_from_param(default: Rules) -> Result<bool, AocError> {
+        Ok(day_param(7, "aces_low")?.unwrap_or(default.aces_low))
+    }
+}
+
+fn parse_card(input: &str) -> IResult<&str, CardValue> {
+    map_opt(complete::anychar, CardValue::from_char)(input)
+}
+
+fn parse_hand(input: &str) -> IResult<&str, Hand> {
+    let mut buf = [CardValue::Two; 5];
+    let (r, ()) = fill(parse_card, &mut buf)(input)?;
+    Ok((r, Hand(buf)))
+}
+
+fn parse_hand_and_bid(input: &str) -> IResult<&str, (Hand, u64)> {
+    separated_pair(parse_hand, space1, complete::u64)(input)
+}
+
+fn parse_hands_and_bids(input: &str) -> Result<Vec<(Hand, u64)>, AocError> {
+    finish(lines(parse_hand_and_bid), input)
+}
+
+pub struct Day07;
+
+impl Day for Day07 {
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Parsed<'a> = Vec<(Hand, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_hands_and_bids(input)
+    }
+
+    fn part1(hands_and_bids: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        // Nothing's wild in part 1, whatever the `wild` parameter says
+        let rules = Rules {
+            aces_low: Rules::aces_low_from_param(Rules::STANDARD)?,
+            ..Rules::STANDARD
+        };
+        Ok(rules.total_winnings(hands_and_bids).into())
+    }
+
+    fn part2(hands_and_bids: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let rules = Rules::from_params(Rules::JOKERS)?;
+        Ok(rules.total_winnings(hands_and_bids).into())
+    }
+}
+
+solution! {
+    Day07 {
+        "d07.txt" => part1 "6440", part2 "5905";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day07::part1(&Day07::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day07::part2(&Day07::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::generated_hands;
+    use itertools::Itertools;
+
+    /// What [Rules::sort_key] packs into a number, as a tuple
+    fn strength(rules: &Rules, hand: &Hand) -> (HandType, [u8; 5]) {
+        (
+            rules.hand_type(hand),
+            hand.0.map(|card| rules.card_rank(card)),
+        )
+    }
+
+    #[test]
+    fn test_sort_key_agrees_with_tuples() {
+        let hands = Day07::parse(&generated_hands(5000)).unwrap();
+        let aces_low = Rules {
+            aces_low: true,
+            ..Rules::JOKERS
+        };
+        for rules in [Rules::STANDARD, Rules::JOKERS, aces_low] {
+            for ((a, _), (b, _)) in hands.iter().tuple_windows() {
+                assert_eq!(
+                    rules.compare(a, b),
+                    strength(&rules, a).cmp(&strength(&rules, b)),
+                    "{a:?} {b:?}"
+                );
+            }
+        }
+    }
+
+    mod parts {
+
+        use super::*;
+        #[test]
+        fn test_card_value_order() {
+            assert!(CardValue::Ace > CardValue::Two)
+        }
+
+        #[test]
+        fn test_hand_type_order() {
+            assert!(HandType::FiveOfAKind > HandType::FourOfAKind)
+        }
+
+        #[test]
+        fn test_parse_card() {
+            assert_eq!(parse_card("32T3K 765"), Ok(("2T3K 765", CardValue::Three)))
+        }
+
+        #[test]
+        fn test_parse_hand() {
+            assert_eq!(
+                parse_hand("32T3K 765"),
+                Ok((
+                    " 765",
+                    Hand([
+                        CardValue::Three,
+                        CardValue::Two,
+                        CardValue::Ten,
+                        CardValue::Three,
+                        CardValue::King
+                    ])
+                ))
+            )
+        }
+
+        #[test]
+        fn test_parse_hand_and_bid() {
+            assert_eq!(
+                parse_hand_and_bid("32T3K 765"),
+                Ok((
+                    "",
+                    (
+                        Hand([
+                            CardValue::Three,
+                            CardValue::Two,
+                            CardValue::Ten,
+                            CardValue::Three,
+                            CardValue::King
+                        ]),
+                        765
+                    )
+                ))
+            )
+        }
+
+        #[test]
+        fn test_hand_order() {
+            let hand1 = parse_hand("KK677").unwrap().1;
+            let hand2 = parse_hand("KTJJT").unwrap().1;
+            assert_eq!(Rules::STANDARD.hand_type(&hand1), HandType::TwoPair);
+            assert_eq!(Rules::STANDARD.hand_type(&hand2), HandType::TwoPair);
+            assert_eq!(Rules::STANDARD.compare(&hand1, &hand2), Ordering::Greater);
+        }
+
+        #[test]
+        fn test_wild_hand_order() {
+            let hand1 = parse_hand("QQQQ2").unwrap().1;
+            let hand2 = parse_hand("JKKK2").unwrap().1;
+            assert_eq!(Rules::JOKERS.compare(&hand1, &hand2), Ordering::Greater);
+            assert_eq!(Rules::JOKERS.hand_type(&hand2), HandType::FourOfAKind);
+            let jokers = parse_hand("JJJJJ").unwrap().1;
+            assert_eq!(Rules::JOKERS.hand_type(&jokers), HandType::FiveOfAKind);
+        }
+
+        #[test]
+        fn test_rules() {
+            let hands = Day07::parse(include_str!("examples/d07.txt")).unwrap();
+            assert_eq!(Rules::STANDARD.total_winnings(&hands), 6440);
+            assert_eq!(Rules::JOKERS.total_winnings(&hands), 5905);
+
+            let aces_low = Rules {
+                aces_low: true,
+                ..Rules::STANDARD
+            };
+            let aces = parse_hand("A2345").unwrap().1;
+            let twos = parse_hand("23456").unwrap().1;
+            assert_eq!(aces_low.compare(&aces, &twos), Ordering::Less);
+            assert_eq!(Rules::STANDARD.compare(&aces, &twos), Ordering::Greater);
+
+            let queens_wild = Rules {
+                wild: Some(CardValue::Queen),
+                ..Rules::STANDARD
+            };
+            let hand = parse_hand("QQ2KJ").unwrap().1;
+            assert_eq!(queens_wild.hand_type(&hand), HandType::ThreeOfAKind);
+            assert_eq!(Rules::JOKERS.hand_type(&hand), HandType::ThreeOfAKind);
+            assert_eq!(Rules::STANDARD.hand_type(&hand), HandType::OnePair);
+        }
+
+        #[test]
+        fn test_wild_from_param() {
+            assert_eq!(Rules::wild_from_param("Q"), Ok(Some(CardValue::Queen)));
+            assert_eq!(Rules::wild_from_param("none"), Ok(None));
+            assert_eq!(Rules::wild_from_param(""), Ok(None));
+            assert_eq!(
+                Rules::wild_from_param("X"),
+                Err(AocError::Param(r#"7.wild: "X" isn't a card"#.to_string()))
+            );
+            assert_eq!(
+                Rules::wild_from_param("QK"),
+                Err(AocError::Param(r#"7.wild: "QK" isn't a card"#.to_string()))
+            );
+        }
+    }
+}
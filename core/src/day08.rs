@@ -0,0 +1,522 @@
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, alphanumeric1, newline};
+use nom::sequence::{delimited, pair, separated_pair, terminated, tuple};
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::intern::{Id, Interner};
+use crate::maths::{crt, lcm_all};
+use crate::parse::{finish, lines};
+use crate::solution;
+use crate::solver::Day;
+
+/// Where to go from a node, labelled by name when parsed and by [Id] once on the [Network]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MapTo<L> {
+    pub left: L,
+    pub right: L,
+}
+
+/// The map, with each node's turnings found by its [Id]
+pub struct Network<'a> {
+    labels: Interner<'a>,
+    nodes: Vec<MapTo<Id>>,
+}
+
+impl<'a> Network<'a> {
+    /// Nodes are interned first so node `n` has the id `n`, anything turned to after that isn't on
+    /// the map
+    fn new(mappings: Vec<(&'a str, MapTo<&'a str>)>) -> Result<Self, AocError> {
+        let mut labels = Interner::new();
+        for (node, _) in &mappings {
+            labels.intern(node);
+        }
+        let nodes = mappings.len();
+        let mut turn_to = |label| {
+            let id = labels.intern(label);
+            (id.index() < nodes)
+                .then_some(id)
+                .ok_or_else(|| AocError::parse(format!("{label} isn't on the map")))
+        };
+        let nodes = mappings
+            .into_iter()
+            .map(|(_, MapTo { left, right })| {
+                Ok(MapTo {
+                    left: turn_to(left)?,
+                    right: turn_to(right)?,
+                })
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(Self { labels, nodes })
+    }
+
+    /// Every node with where it turns to, in the order they're listed
+    pub fn turnings(&self) -> impl Iterator<Item = (&'a str, MapTo<&'a str>)> + '_ {
+        self.labels.ids().take(self.nodes.len()).map(|id| {
+            let MapTo { left, right } = self.nodes[id.index()];
+            let label = |id| self.labels.label(id);
+            (
+                label(id),
+                MapTo {
+                    left: label(left),
+                    right: label(right),
+                },
+            )
+        })
+    }
+
+    fn node(&self, label: &str) -> Result<Id, AocError> {
+        self.labels
+            .get(label)
+            .ok_or_else(|| AocError::parse(format!("{label} isn't on the map")))
+    }
+
+    /// Whether a ghost stops at `pos`, see [is_finish]
+    fn at_finish(&self, pos: Id) -> bool {
+        is_finish(self.labels.label(pos))
+    }
+
+    fn next_pos(&self, current_pos: Id, instruction: char) -> Result<Id, AocError> {
+        let next_choice = self.nodes[current_pos.index()];
+        match instruction {
+            'L' => Ok(next_choice.left),
+            'R' => Ok(next_choice.right),
+            _ => Err(AocError::parse(format!(
+                "unexpected instruction {instruction}"
+            ))),
+        }
+    }
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, &str> {
+    terminated(alpha1, tuple((newline, newline)))(input)
+}
+
+fn parse_map_to(input: &str) -> IResult<&str, MapTo<&str>> {
+    let (r, (left, right)) = delimited(
+        tag("("),
+        separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+        tag(")"),
+    )(input)?;
+    Ok((r, MapTo { left, right }))
+}
+
+fn parse_mapping(input: &str) -> IResult<&str, (&str, MapTo<&str>)> {
+    separated_pair(alphanumeric1, tag(" = "), parse_map_to)(input)
+}
+
+fn parse_input(input: &str) -> Result<(&str, Network<'_>), AocError> {
+    let (instructions, mappings) = finish(pair(parse_instructions, lines(parse_mapping)), input)?;
+    Ok((instructions, Network::new(mappings)?))
+}
+
+/// How many steps it takes to get from `start` to a position that's `finished`. Once it's
+/// somewhere it's been before at the same point in the instructions it's going round in circles,
+/// so if it hasn't finished by then it never will
+fn steps_until(
+    start: Id,
+    map: &Network,
+    instructions: &str,
+    finished: impl Fn(Id) -> bool,
+) -> Result<usize, AocError> {
+    Ghost::walk(start, map, instructions, finished)?
+        .first_exit()
+        .ok_or_else(|| {
+            AocError::no_answer(format!(
+                "following the instructions from {} goes round in circles without finishing",
+                map.labels.label(start)
+            ))
+        })
+}
+
+/// Where a ghost stops, any node ending in `Z`
+pub fn is_finish(pos: &str) -> bool {
+    pos.ends_with('Z')
+}
+
+/// Where a ghost starts, any node ending in `A`
+pub fn is_start(pos: &str) -> bool {
+    pos.ends_with('A')
+}
+
+fn get_first_exit(start: Id, map: &Network, instructions: &str) -> Result<usize, AocError> {
+    steps_until(start, map, instructions, |pos| map.at_finish(pos))
+}
+
+/// Where a ghost's walk goes once it's following the instructions round and round. It's somewhere
+/// it's been before at the same point in the instructions after `prefix + cycle` steps, so from
+/// `prefix` steps on it repeats every `cycle` steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ghost {
+    prefix: usize,
+    cycle: usize,
+    /// How many steps it's taken each time it's at an exit, up until it repeats
+    exits: Vec<usize>,
+}
+
+impl Ghost {
+    /// Follows the instructions from `start` until it's back in the same place at the same point
+    /// in the instructions, noting each step it's somewhere `finished`
+    fn walk(
+        start: Id,
+        map: &Network,
+        instructions: &str,
+        finished: impl Fn(Id) -> bool,
+    ) -> Result<Self, AocError> {
+        let instructions: Vec<char> = instructions.chars().collect();
+        if instructions.is_empty() {
+            return Err(AocError::parse("there are no instructions"));
+        }
+        let mut seen = vec![None; map.nodes.len() * instructions.len()];
+        let mut exits = vec![];
+        let mut pos = start;
+        for step in 0.. {
+            let instruction = step % instructions.len();
+            let state = pos.index() * instructions.len() + instruction;
+            if let Some(first) = seen[state] {
+                return Ok(Self {
+                    prefix: first,
+                    cycle: step - first,
+                    exits,
+                });
+            }
+            seen[state] = Some(step);
+            if finished(pos) {
+                exits.push(step);
+            }
+            pos = map.next_pos(pos, instructions[instruction])?;
+        }
+        unreachable!("there are only so many places to be")
+    }
+
+    /// The exits it only passes once, on the way to the cycle
+    fn exits_before_cycle(&self) -> impl Iterator<Item = usize> + '_ {
+        self.exits
+            .iter()
+            .copied()
+            .filter(|&exit| exit < self.prefix)
+    }
+
+    /// The exits it passes every `cycle` steps
+    fn exits_in_cycle(&self) -> impl Iterator<Item = usize> + '_ {
+        self.exits
+            .iter()
+            .copied()
+            .filter(|&exit| exit >= self.prefix)
+    }
+
+    /// The first step, after starting, that it's at an exit on. Starting on one only counts if it
+    /// comes back round to it
+    fn first_exit(&self) -> Option<usize> {
+        self.exits
+            .iter()
+            .copied()
+            .find(|&exit| exit > 0)
+            .or_else(|| self.exits_in_cycle().next().map(|exit| exit + self.cycle))
+    }
+
+    fn at_exit(&self, step: usize) -> bool {
+        if step < self.prefix {
+            self.exits.contains(&step)
+        } else {
+            let step = self.prefix + (step - self.prefix) % self.cycle;
+            self.exits.contains(&step)
+        }
+    }
+}
+
+/// The first step, after starting, that every ghost is at an exit on. Before the longest prefix
+/// that's checked step by step, after it every ghost is on its cycle so each choice of one of
+/// their cycle's exits is a set of remainders for [crt] to line up
+fn all_at_exits(ghosts: &[Ghost]) -> Option<usize> {
+    let longest_prefix = ghosts.iter().map(|ghost| ghost.prefix).max()?;
+    let first = &ghosts[0];
+    let before_cycles = first
+        .exits_before_cycle()
+        .chain(
+            first
+                .exits_in_cycle()
+                .flat_map(|exit| (exit..longest_prefix).step_by(first.cycle)),
+        )
+        .filter(|&step| step > 0 && step < longest_prefix)
+        .filter(|&step| ghosts.iter().all(|ghost| ghost.at_exit(step)))
+        .min();
+    if before_cycles.is_some() {
+        return before_cycles;
+    }
+    ghosts
+        .iter()
+        .map(|ghost| {
+            ghost
+                .exits_in_cycle()
+                .map(|exit| (exit as i64, ghost.cycle as i64))
+                .collect::<Vec<_>>()
+        })
+        .multi_cartesian_product()
+        .filter_map(crt)
+        .map(|(remainder, modulus)| {
+            // The first time they line up that every ghost is on its cycle
+            let (remainder, modulus) = (remainder as usize, modulus as usize);
+            let earliest = longest_prefix.max(1);
+            if remainder >= earliest {
+                remainder
+            } else {
+                remainder + (earliest - remainder).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+}
+
+pub struct Day08;
+
+impl Day for Day08 {
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    /// The instructions, and the map to follow them around
+    type Parsed<'a> = (&'a str, Network<'a>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1((instructions, map): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let (start, end) = (map.node("AAA")?, map.node("ZZZ")?);
+        Ok(steps_until(start, map, instructions, |pos| pos == end)?.into())
+    }
+
+    fn part2((instructions, map): &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let ghosts = starts(map)
+            .map(|start| Ghost::walk(start, map, instructions, |pos| map.at_finish(pos)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if ghosts.is_empty() {
+            return Err(no_starts());
+        }
+        all_at_exits(&ghosts)
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_answer("the ghosts are never all at exits at once"))
+    }
+}
+
+fn starts<'a>(map: &'a Network) -> impl Iterator<Item = Id> + 'a {
+    map.labels
+        .ids()
+        .filter(|id| is_start(map.labels.label(*id)))
+}
+
+fn no_starts() -> AocError {
+    AocError::no_answer("there are no starting positions")
+}
+
+/// Assumes each ghost's first exit is the only one on its loop, and that the loop is as long as it
+/// took to get there, so they line up at the lowest common multiple. That's true of the puzzle's
+/// inputs, but not of every map
+pub fn part2_lcm(input: &str) -> Result<Answer, AocError> {
+    let (instructions, map) = Day08::parse(input)?;
+    starts(&map)
+        .map(|start| get_first_exit(start, &map, instructions))
+        .collect::<Result<Vec<_>, _>>()
+        .map(lcm_all)?
+        .map(Answer::from)
+        .ok_or_else(no_starts)
+}
+
+solution! {
+    Day08 {
+        "d08_part1.txt" => part1 "2";
+        "d08_part2.txt" => part2 "6";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part2_lcm(2, Solved).named("lcm"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day08::part1(&Day08::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day08::part2(&Day08::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Walks 11A, 11B, then 11C and 11Z over and over, and 22A then 22Z, 22B and 22C over and
+    /// over, so the first is at an exit on odd steps from 3 and the second every 3 steps from 1
+    const OFFSET_CYCLES: &str = "L
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+11Z = (11C, 11C)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+";
+
+    #[test]
+    fn test_turnings() {
+        let (_, map) = Day08::parse(OFFSET_CYCLES).unwrap();
+        let turnings: Vec<_> = map.turnings().take(2).collect();
+        assert_eq!(
+            turnings,
+            vec![
+                (
+                    "11A",
+                    MapTo {
+                        left: "11B",
+                        right: "11B"
+                    }
+                ),
+                (
+                    "11B",
+                    MapTo {
+                        left: "11C",
+                        right: "11C"
+                    }
+                ),
+            ]
+        );
+        assert_eq!(map.turnings().count(), 8);
+    }
+
+    #[test]
+    fn test_back_to_start() {
+        let input = "L
+
+AAA = (BBB, BBB)
+BBB = (AAA, AAA)
+";
+        let (instructions, map) = Day08::parse(input).unwrap();
+        let start = map.node("AAA").unwrap();
+        assert_eq!(
+            steps_until(start, &map, instructions, |pos| pos == start),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let input = "LR
+
+AAA = (BBB, BBB)
+BBB = (AAA, CCC)
+CCC = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)
+";
+        let error = Err(AocError::no_answer(
+            "following the instructions from AAA goes round in circles without finishing",
+        ));
+        assert_eq!(part1(input), error);
+        let input = input.replace("ZZZ", "11Z");
+        assert_eq!(part2_lcm(&input), error);
+        assert_eq!(
+            part2(&input),
+            Err(AocError::no_answer(
+                "the ghosts are never all at exits at once"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ghost_walk() {
+        let (instructions, map) = Day08::parse(OFFSET_CYCLES).unwrap();
+        let ghosts: Vec<_> = starts(&map)
+            .map(|start| Ghost::walk(start, &map, instructions, |pos| map.at_finish(pos)).unwrap())
+            .collect();
+        assert_eq!(
+            ghosts,
+            vec![
+                Ghost {
+                    prefix: 2,
+                    cycle: 2,
+                    exits: vec![3]
+                },
+                Ghost {
+                    prefix: 1,
+                    cycle: 3,
+                    exits: vec![1]
+                },
+            ]
+        );
+        assert!(ghosts[0].at_exit(9));
+        assert!(!ghosts[0].at_exit(1));
+    }
+
+    #[test]
+    fn test_offset_cycles() {
+        assert_eq!(part2(OFFSET_CYCLES).unwrap(), "7");
+        assert_eq!(part2_lcm(OFFSET_CYCLES).unwrap(), "3");
+    }
+
+    #[test]
+    fn test_exits_before_cycles() {
+        // 33A only passes an exit once, on the way to going round 33B
+        let input = "LR
+
+33A = (33Z, 33Z)
+33Z = (33B, 33B)
+33B = (33B, 33B)
+44A = (44Z, 44B)
+44B = (44Z, 44Z)
+44Z = (44B, 44B)
+";
+        assert_eq!(part2(input).unwrap(), "1");
+        let input = input.replace("33A = (33Z, 33Z)", "33A = (33B, 33B)");
+        assert_eq!(
+            part2(&input),
+            Err(AocError::no_answer(
+                "the ghosts are never all at exits at once"
+            ))
+        );
+    }
+
+    mod parts {
+        use super::*;
+
+        #[test]
+        fn test_parse_instructions() {
+            let input = "RL
+
+AAA = (BBB, CCC)";
+            assert_eq!(parse_instructions(input), Ok(("AAA = (BBB, CCC)", "RL")))
+        }
+        #[test]
+        fn test_parse_map_to() {
+            let input = "(BBB, CCC)\n";
+            assert_eq!(
+                parse_map_to(input),
+                Ok((
+                    "\n",
+                    MapTo {
+                        left: "BBB",
+                        right: "CCC"
+                    }
+                ))
+            )
+        }
+        #[test]
+        fn test_parse_mapping() {
+            let input = "AAA = (BBB, CCC)
+BBB = (DDD, EEE)";
+            assert_eq!(
+                parse_mapping(input),
+                Ok((
+                    "\nBBB = (DDD, EEE)",
+                    (
+                        "AAA",
+                        MapTo {
+                            left: "BBB",
+                            right: "CCC"
+                        }
+                    )
+                ))
+            )
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use nom::character::complete;
+use nom::character::complete::newline;
+use nom::multi::separated_list1;
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::parse::{finish, numbers};
+use crate::solution;
+use crate::solver::Day;
+
+type Number = i64;
+
+fn next_sequence(v: &[Number]) -> Vec<Number> {
+    let mut output = Vec::with_capacity(v.len() - 1);
+    let mut iter = v.iter().peekable();
+    while let (Some(a), Some(b)) = (iter.next(), iter.peek()) {
+        output.push(*b - a)
+    }
+    output
+}
+
+fn next_sequences_rec(mut v: Vec<Vec<Number>>) -> Vec<Vec<Number>> {
+    let last = v
+        .last()
+        .and_then(|last| (!is_end_sequence(last)).then_some(last));
+
+    if let Some(last) = last {
+        v.push(next_sequence(last));
+        next_sequences_rec(v)
+    } else {
+        v
+    }
+}
+
+fn is_end_sequence(v: &[Number]) -> bool {
+    v.iter().all(|i| *i == 0)
+}
+
+fn empty_history() -> AocError {
+    AocError::no_answer("an empty history has no values to extrapolate from")
+}
+
+/// The differences with a 0 added to the last of them, which is where predicting starts
+fn predictions_start(
+    v: &mut [Vec<Number>],
+) -> Result<(&mut Vec<Number>, &mut [Vec<Number>]), AocError> {
+    let (end, rest) = v.split_last_mut().ok_or_else(empty_history)?;
+    if !is_end_sequence(end) {
+        return Err(AocError::no_answer(
+            "the last sequence of differences must be all zeros",
+        ));
+    }
+    end.push(0);
+    Ok((end, rest))
+}
+
+fn add_predictions(v: &mut [Vec<Number>]) -> Result<(), AocError> {
+    let (mut prev, rest) = predictions_start(v)?;
+    for next in rest.iter_mut().rev() {
+        let (Some(prev_last), Some(next_last)) = (prev.last(), next.last()) else {
+            return Err(empty_history());
+        };
+        next.push(prev_last + next_last);
+        prev = next;
+    }
+    Ok(())
+}
+
+fn add_predictions_back(v: &mut [Vec<Number>]) -> Result<(), AocError> {
+    let (mut prev, rest) = predictions_start(v)?;
+    for next in rest.iter_mut().rev() {
+        let (Some(prev_first), Some(next_first)) = (prev.first(), next.first()) else {
+            return Err(empty_history());
+        };
+        next.insert(0, next_first - prev_first);
+        prev = next;
+    }
+    Ok(())
+}
+
+/// The binomial coefficients for `n`, `n choose 0` to `n choose n`
+fn binomial_coefficients(n: usize) -> impl Iterator<Item = Number> {
+    (0..=n).scan(1, move |coefficient, k| {
+        let this = *coefficient;
+        *coefficient = *coefficient * (n - k) as Number / (k as Number + 1);
+        Some(this)
+    })
+}
+
+/// The value after `sequence`, without building the differences. Taking the differences `n` times
+/// for a sequence of `n` leaves nothing, so the value after is the one whose `n`th difference with
+/// the rest is 0, the sum of `-(-1)^(n - i) * (n choose i) * sequence[i]`
+fn next_value(sequence: &[Number]) -> Number {
+    let n = sequence.len();
+    sequence
+        .iter()
+        .zip(binomial_coefficients(n))
+        .enumerate()
+        .map(|(i, (value, coefficient))| {
+            let sign = if (n - i) % 2 == 1 { 1 } else { -1 };
+            sign * coefficient * value
+        })
+        .sum()
+}
+
+/// The value before `sequence`, which is the value after it backwards
+fn previous_value(sequence: &[Number]) -> Number {
+    let n = sequence.len();
+    sequence
+        .iter()
+        .zip(binomial_coefficients(n).skip(1))
+        .enumerate()
+        .map(|(i, (value, coefficient))| {
+            let sign = if i % 2 == 0 { 1 } else { -1 };
+            sign * coefficient * value
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<Number>>> {
+    separated_list1(newline, numbers(complete::i64))(input)
+}
+
+pub struct Day09;
+
+impl Day for Day09 {
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Parsed<'a> = Vec<Vec<Number>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_input, input)
+    }
+
+    fn part1(vectors: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        vectors
+            .iter()
+            .map(|line| {
+                let mut predictions = next_sequences_rec(vec![non_empty(line)?.to_vec()]);
+                add_predictions(&mut predictions)?;
+                predictions[0].last().copied().ok_or_else(empty_history)
+            })
+            .sum::<Result<Number, _>>()
+            .map(Answer::from)
+    }
+
+    fn part2(vectors: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        vectors
+            .iter()
+            .map(|line| {
+                let mut predictions = next_sequences_rec(vec![non_empty(line)?.to_vec()]);
+                add_predictions_back(&mut predictions)?;
+                predictions[0].first().copied().ok_or_else(empty_history)
+            })
+            .sum::<Result<Number, _>>()
+            .map(Answer::from)
+    }
+}
+
+solution! {
+    Day09 {
+        "d09.txt" => part1 "114", part2 "2";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_binomial(1, Solved).named("binomial"),
+    part2_binomial(2, Solved).named("binomial"),
+}
+
+/// The binomial coefficients would happily extrapolate nothing to 0, the differences can't
+fn non_empty(sequence: &[Number]) -> Result<&[Number], AocError> {
+    (!sequence.is_empty())
+        .then_some(sequence)
+        .ok_or_else(empty_history)
+}
+
+/// Works out each next value from the binomial coefficients rather than the differences
+pub fn part1_binomial(input: &str) -> Result<Answer, AocError> {
+    let sequences = Day09::parse(input)?;
+    sequences
+        .iter()
+        .map(|sequence| non_empty(sequence).map(next_value))
+        .sum::<Result<Number, _>>()
+        .map(Answer::from)
+}
+
+/// Works out each previous value from the binomial coefficients rather than the differences
+pub fn part2_binomial(input: &str) -> Result<Answer, AocError> {
+    let sequences = Day09::parse(input)?;
+    sequences
+        .iter()
+        .map(|sequence| non_empty(sequence).map(previous_value))
+        .sum::<Result<Number, _>>()
+        .map(Answer::from)
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day09::part1(&Day09::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day09::part2(&Day09::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::Random;
+
+    /// `count` sequences of `length` values of polynomials of up to `length - 1` degrees, with
+    /// coefficients that are the same every run
+    fn generated_sequences(count: usize, length: usize) -> Vec<Vec<Number>> {
+        let mut random = Random::new(0x9e37_79b9);
+        (0..count)
+            .map(|_| {
+                // Keeping the degree down keeps the values a sensible size
+                let coefficients: Vec<_> = (0..length.min(6))
+                    .map(|_| random.below(7) as Number - 3)
+                    .collect();
+                (0..length as Number)
+                    .map(|x| coefficients.iter().rev().fold(0, |sum, c| sum * x + c))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn next_by_differences(sequence: &[Number]) -> Number {
+        let mut sequences = next_sequences_rec(vec![sequence.to_vec()]);
+        add_predictions(&mut sequences).unwrap();
+        *sequences[0].last().unwrap()
+    }
+
+    fn previous_by_differences(sequence: &[Number]) -> Number {
+        let mut sequences = next_sequences_rec(vec![sequence.to_vec()]);
+        add_predictions_back(&mut sequences).unwrap();
+        sequences[0][0]
+    }
+
+    #[test]
+    fn test_binomial_coefficients() {
+        assert_eq!(
+            binomial_coefficients(5).collect::<Vec<_>>(),
+            vec![1, 5, 10, 10, 5, 1]
+        );
+    }
+
+    #[test]
+    fn test_binomial_agrees_with_differences() {
+        assert_eq!(next_value(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(previous_value(&[10, 13, 16, 21, 30, 45]), 5);
+        for length in 1..=21 {
+            for sequence in generated_sequences(20, length) {
+                assert_eq!(
+                    next_value(&sequence),
+                    next_by_differences(&sequence),
+                    "{sequence:?}"
+                );
+                assert_eq!(
+                    previous_value(&sequence),
+                    previous_by_differences(&sequence),
+                    "{sequence:?}"
+                );
+            }
+        }
+        // Not a polynomial, so every difference is needed
+        let sequence = [3, -1, 4, -1, 5, -9, 2, -6];
+        assert_eq!(next_value(&sequence), next_by_differences(&sequence));
+        assert_eq!(
+            previous_value(&sequence),
+            previous_by_differences(&sequence)
+        );
+    }
+
+    mod parts {
+        use super::*;
+
+        #[test]
+        fn test_next_sequence() {
+            let seq1 = vec![1, 3, 6, 10, 15, 21];
+            let seq2 = vec![2, 3, 4, 5, 6];
+            let seq3 = vec![1, 1, 1, 1];
+            let seq4 = vec![0, 0, 0];
+
+            assert_eq!(next_sequence(&seq1), seq2);
+            assert_eq!(next_sequence(&seq2), seq3);
+            assert_eq!(next_sequence(&seq3), seq4);
+        }
+
+        #[test]
+        fn test_next_req() {
+            let seq1 = vec![1, 3, 6, 10, 15, 21];
+            let seq2 = vec![2, 3, 4, 5, 6];
+            let seq3 = vec![1, 1, 1, 1];
+            let seq4 = vec![0, 0, 0];
+
+            assert_eq!(
+                next_sequences_rec(vec![seq1.clone()]),
+                vec![seq1, seq2, seq3, seq4]
+            );
+        }
+
+        #[test]
+        fn test_add_predictions() {
+            let seq1 = vec![1, 3, 6, 10, 15, 21];
+            let seq2 = vec![2, 3, 4, 5, 6];
+            let seq3 = vec![1, 1, 1, 1];
+            let seq4 = vec![0, 0, 0];
+
+            let p_seq1 = vec![1, 3, 6, 10, 15, 21, 28];
+            let p_seq2 = vec![2, 3, 4, 5, 6, 7];
+            let p_seq3 = vec![1, 1, 1, 1, 1];
+            let p_seq4 = vec![0, 0, 0, 0];
+
+            let mut sequence = vec![seq1, seq2, seq3, seq4];
+            let expected_sequence = vec![p_seq1, p_seq2, p_seq3, p_seq4];
+            add_predictions(&mut sequence).unwrap();
+
+            assert_eq!(sequence, expected_sequence);
+        }
+
+        #[test]
+        fn test_add_predictions_back() {
+            let seq1 = vec![10, 13, 16, 21, 30, 45];
+            let seq2 = vec![3, 3, 5, 9, 15];
+            let seq3 = vec![0, 2, 4, 6];
+            let seq4 = vec![2, 2, 2, 2];
+            let seq5 = vec![0, 0, 0];
+
+            let p_seq1 = vec![5, 10, 13, 16, 21, 30, 45];
+            let p_seq2 = vec![5, 3, 3, 5, 9, 15];
+            let p_seq3 = vec![-2, 0, 2, 4, 6];
+            let p_seq4 = vec![2, 2, 2, 2, 2];
+            let p_seq5 = vec![0, 0, 0, 0];
+
+            let mut sequence = vec![seq1, seq2, seq3, seq4, seq5];
+            let expected_sequence = vec![p_seq1, p_seq2, p_seq3, p_seq4, p_seq5];
+            add_predictions_back(&mut sequence).unwrap();
+
+            assert_eq!(sequence, expected_sequence);
+        }
+
+        #[test]
+        fn test_empty_history() {
+            assert_eq!(add_predictions(&mut []), Err(empty_history()));
+            assert!(add_predictions_back(&mut [vec![1, 2]]).is_err());
+
+            let histories = vec![vec![1, 2, 3], vec![]];
+            assert_eq!(Day09::part1(&histories), Err(empty_history()));
+            assert_eq!(Day09::part2(&histories), Err(empty_history()));
+            assert_eq!(non_empty(&histories[1]), Err(empty_history()));
+        }
+
+        #[test]
+        fn text_parse_input() {
+            let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+            let expected = vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![1, 3, 6, 10, 15, 21],
+                vec![10, 13, 16, 21, 30, 45],
+            ];
+
+            assert_eq!(parse_input(input).unwrap().1, expected);
+
+            let input = "0 -3 -6 -9 -12 -15
+1 -3 -6 -10 -15 -21
+10 -13 -16 -21 -30 -45";
+            let expected = vec![
+                vec![0, -3, -6, -9, -12, -15],
+                vec![1, -3, -6, -10, -15, -21],
+                vec![10, -13, -16, -21, -30, -45],
+            ];
+
+            assert_eq!(parse_input(input).unwrap().1, expected);
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use nom::combinator::map;
+use nom::IResult;
+use num::Integer;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::Direction::{self, *};
+use crate::grid::{Grid, Pos};
+use crate::parse::{char_grid, finish};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Ord)]
+pub enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl Pipe {
+    fn is_start(&self) -> bool {
+        self == &Self::Start
+    }
+
+    fn is_ground(&self) -> bool {
+        self == &Self::Ground
+    }
+
+    fn is_nw_edge(&self) -> bool {
+        match self {
+            Pipe::NS => true,
+            Pipe::EW => true,
+            Pipe::NE => false,
+            Pipe::NW => true,
+            Pipe::SW => false,
+            Pipe::SE => true,
+            Pipe::Ground => false,
+            Pipe::Start => false, // Swapped for the pipe it's on before counting
+        }
+    }
+
+    /// The two sides of the tile the pipe joins up
+    fn connects(&self) -> Option<[Direction; 2]> {
+        match self {
+            Pipe::NS => Some([Up, Down]),
+            Pipe::EW => Some([Left, Right]),
+            Pipe::NE => Some([Up, Right]),
+            Pipe::NW => Some([Up, Left]),
+            Pipe::SW => Some([Down, Left]),
+            Pipe::SE => Some([Down, Right]),
+            Pipe::Ground => None,
+            Pipe::Start => None,
+        }
+    }
+
+    // The direction you face once exiting
+    fn get_exit_direction(&self, arrive_from: Direction) -> Option<Direction> {
+        // Travelling `arrive_from` takes you in through the opposite side
+        let entered_by = arrive_from.opposite();
+        match self.connects()? {
+            [side, other] if side == entered_by => Some(other),
+            [other, side] if side == entered_by => Some(other),
+            _ => None,
+        }
+    }
+
+    /// The pipe that joins up the two sides
+    fn joining(sides: [Direction; 2]) -> Option<Self> {
+        [Pipe::NS, Pipe::EW, Pipe::NE, Pipe::NW, Pipe::SW, Pipe::SE]
+            .into_iter()
+            .find(|pipe| {
+                pipe.connects()
+                    .is_some_and(|connects| sides.iter().all(|side| connects.contains(side)))
+            })
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::NS),
+            '-' => Some(Self::EW),
+            'L' => Some(Self::NE),
+            'J' => Some(Self::NW),
+            '7' => Some(Self::SW),
+            'F' => Some(Self::SE),
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Self::NS => '|',
+            Self::EW => '-',
+            Self::NE => 'L',
+            Self::NW => 'J',
+            Self::SW => '7',
+            Self::SE => 'F',
+            Self::Ground => '.',
+            Self::Start => 'S',
+        }
+    }
+}
+
+/// What a tile of the map is, once the loop's been found
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    /// On the loop, along with the shape of pipe
+    Loop(Pipe),
+    /// On the loop where the animal started, along with the pipe it's on
+    Start(Pipe),
+    /// Not on the loop, but surrounded by it
+    Inside,
+    Outside,
+}
+
+#[derive(Debug, Clone)]
+pub struct PipeMap(Grid<Pipe>);
+
+impl PipeMap {
+    fn get_start(&self) -> Option<Pos> {
+        self.position(Pipe::is_start)
+    }
+
+    fn next_point_and_direction(
+        &self,
+        current_point: Pos,
+        direction: Direction,
+    ) -> (Option<Pos>, Option<Direction>) {
+        let Some(next_point) = self.step(current_point, direction) else {
+            return (None, None);
+        };
+        let next_pipe = self[next_point];
+        let next_direction = next_pipe.get_exit_direction(direction);
+        (Some(next_point), next_direction)
+    }
+
+    fn path_to_start(&self, point: Pos, dir: Direction) -> Option<Vec<Pos>> {
+        let mut path = Vec::new();
+
+        // Shadow
+        let mut point = point;
+        let mut dir = dir;
+
+        loop {
+            let (next_point, next_dir) = self.next_point_and_direction(point, dir);
+            // Check if the pipe has ended
+            let next_point = next_point?;
+            let pipe = self[next_point];
+            path.push(next_point);
+            if pipe.is_start() {
+                return Some(path);
+            }
+            // Check if there is somewhere to go next
+            let next_dir = next_dir?;
+            point = next_point;
+            dir = next_dir;
+        }
+    }
+
+    /// The loop through the start, walked once. It sets off towards the first neighbour with a
+    /// pipe that joins up with the start, only trying another if that one doesn't lead back
+    fn find_loop(&self) -> Result<Vec<Pos>, AocError> {
+        let start = self
+            .get_start()
+            .ok_or_else(|| AocError::parse("there's no start"))?;
+        Direction::ALL
+            .into_iter()
+            .filter(|&dir| {
+                self.step(start, dir)
+                    .and_then(|next| self[next].connects())
+                    .is_some_and(|sides| sides.contains(&dir.opposite()))
+            })
+            .find_map(|dir| self.path_to_start(start, dir))
+            .ok_or_else(|| AocError::no_answer("no pipe leads back to the start"))
+    }
+
+    /// The pipe under the start of the loop `path`, which joins the first tile on it to the last
+    /// before getting back to the start
+    fn start_pipe(&self, path: &[Pos]) -> Option<Pipe> {
+        let (&start, rest) = path.split_last()?;
+        let ends = [*rest.first()?, *rest.last()?];
+        let sides = ends.map(|end| {
+            Direction::ALL
+                .into_iter()
+                .find(|&side| self.step(start, side) == Some(end))
+        });
+        Pipe::joining([sides[0]?, sides[1]?])
+    }
+
+    /// Only the loop `path`, with the start swapped for the pipe it's on
+    fn remove_all_but_path(&self, path: Vec<Pos>) -> Result<PipeMap, AocError> {
+        let start_pipe = self
+            .start_pipe(&path)
+            .ok_or_else(|| AocError::no_answer("the loop doesn't join up at the start"))?;
+        let mut new_map = Grid::filled(self.width(), self.height(), Pipe::Ground);
+        for point in path {
+            new_map[point] = match self[point] {
+                Pipe::Start => start_pipe,
+                pipe => pipe,
+            };
+        }
+        Ok(PipeMap(new_map))
+    }
+
+    fn count_pipes_nw(&self, point: &Pos) -> usize {
+        let Pos {
+            mut row,
+            mut column,
+        } = point;
+        let mut count = 0;
+        while row > 0 && column > 0 {
+            row -= 1;
+            column -= 1;
+            if self[Pos::new(row, column)].is_nw_edge() {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// How many tiles the loop `path` goes round. Twice the area inside the path of tile centres
+    /// comes from the shoelace formula, and Pick's theorem says that's twice the tiles inside plus
+    /// the tiles on the path, less 2
+    fn tiles_inside(path: &[Pos]) -> usize {
+        let doubled_area = path
+            .iter()
+            .zip(path.iter().cycle().skip(1))
+            .map(|(a, b)| (a.row * b.column) as i64 - (b.row * a.column) as i64)
+            .sum::<i64>()
+            .unsigned_abs() as usize;
+        (doubled_area + 2).saturating_sub(path.len()) / 2
+    }
+
+    /// Every tile marked as on the loop, or inside or outside it
+    pub fn tiles(&self) -> Result<Grid<Tile>, AocError> {
+        let loop_only = self.remove_all_but_path(self.find_loop()?)?;
+        let mut tiles = Grid::filled(self.width(), self.height(), Tile::Outside);
+        for (pos, &pipe) in loop_only.iter() {
+            tiles[pos] = if self[pos].is_start() {
+                Tile::Start(pipe)
+            } else if !pipe.is_ground() {
+                Tile::Loop(pipe)
+            } else if loop_only.count_pipes_nw(&pos).is_odd() {
+                Tile::Inside
+            } else {
+                Tile::Outside
+            };
+        }
+        Ok(tiles)
+    }
+
+    fn n_points_inside_pipes(&self) -> usize {
+        // We'll simple find each ground point, then run to the left edge and see how many times
+        // it crossed a pipe. Note, this only works if there's only one specific
+        self.iter()
+            .filter(|(_point, pipe)| pipe.is_ground())
+            .filter(|(point, _pipe)| self.count_pipes_nw(point).is_odd())
+            .count()
+    }
+}
+
+impl From<Grid<Pipe>> for PipeMap {
+    fn from(value: Grid<Pipe>) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for PipeMap {
+    type Target = Grid<Pipe>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for PipeMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn parse_pipe_map(input: &str) -> IResult<&str, PipeMap> {
+    map(char_grid(Pipe::from_char), PipeMap::from)(input)
+}
+
+pub struct Day10;
+
+impl Day for Day10 {
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Parsed<'a> = PipeMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_pipe_map, input)
+    }
+
+    fn part1(pipe_map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(pipe_map.find_loop()?.len().div_ceil(2).into())
+    }
+
+    fn part2(pipe_map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let path = pipe_map.find_loop()?;
+        Ok(PipeMap::tiles_inside(&path).into())
+    }
+}
+
+/// Counts the pipes crossed going diagonally up and left from each tile that isn't on the loop,
+/// once the start's been swapped for the pipe it's on
+pub fn part2_ray_casting(input: &str) -> Result<Answer, AocError> {
+    let pipe_map = Day10::parse(input)?;
+    let path = pipe_map.find_loop()?;
+    let new_map = pipe_map.remove_all_but_path(path)?;
+    Ok(new_map.n_points_inside_pipes().into())
+}
+
+solution! {
+    Day10 {
+        "d10_part1.txt" => part1 "4";
+        "d10_part1_2.txt" => part1 "8";
+        "d10_part2.txt" => part2 "10";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part2_ray_casting(2, Solved).named("ray_casting"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day10::part1(&Day10::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day10::part2(&Day10::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parts {
+        use super::*;
+
+        // -L|F7
+        // 7S-7|
+        // L|7||
+        // -L-J|
+        // L|-JF
+        fn helper_create_pipe_map_1() -> PipeMap {
+            let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+            parse_pipe_map(input).unwrap().1
+        }
+
+        // 7-F7-
+        // .FJ|7
+        // SJLL7
+        // |F--J
+        // LJ.LJ
+        fn helper_create_pipe_map_2() -> PipeMap {
+            let input = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+            parse_pipe_map(input).unwrap().1
+        }
+
+        // .....
+        // .S-7.
+        // .|.|.
+        // .L-J.
+        // .....
+        fn helper_create_simple_pipe_map() -> PipeMap {
+            let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+            parse_pipe_map(input).unwrap().1
+        }
+
+        #[test]
+        fn test_parser() {
+            let pipe_map = helper_create_pipe_map_1();
+            assert_eq!(pipe_map.height(), 5);
+            assert_eq!(pipe_map.width(), 5);
+
+            let pipe_map = helper_create_pipe_map_2();
+            assert_eq!(pipe_map.height(), 5);
+            assert_eq!(pipe_map.width(), 5);
+
+            let pipe_map = helper_create_simple_pipe_map();
+            assert_eq!(pipe_map.height(), 5);
+            assert_eq!(pipe_map.width(), 5);
+        }
+
+        #[test]
+        fn test_find_start() {
+            let pipe_map = helper_create_pipe_map_1();
+            assert_eq!(pipe_map.get_start(), Some(Pos::new(1, 1)));
+
+            let pipe_map2 = helper_create_pipe_map_2();
+            assert_eq!(pipe_map2.get_start(), Some(Pos::new(2, 0)));
+
+            let pipe_map2 = helper_create_simple_pipe_map();
+            assert_eq!(pipe_map2.get_start(), Some(Pos::new(1, 1)));
+        }
+
+        #[test]
+        fn test_next_point_and_direction() {
+            let pipe_map = helper_create_simple_pipe_map();
+            let point = Pos::new(1, 1);
+            let direction = Right;
+            let expected_point = Pos::new(1, 2);
+            let expected_direction = Right;
+            assert_eq!(
+                pipe_map.next_point_and_direction(point, direction),
+                (Some(expected_point), Some(expected_direction))
+            )
+        }
+
+        #[test]
+        fn test_tiles_inside() {
+            let pipe_map = helper_create_simple_pipe_map();
+            let path = pipe_map.find_loop().unwrap();
+            assert_eq!(PipeMap::tiles_inside(&path), 1);
+        }
+
+        #[test]
+        fn test_start_pipe() {
+            let pipe_map = helper_create_simple_pipe_map();
+            let path = pipe_map.find_loop().unwrap();
+            assert_eq!(pipe_map.start_pipe(&path), Some(Pipe::SE));
+
+            let pipe_map = helper_create_pipe_map_2();
+            let path = pipe_map.find_loop().unwrap();
+            assert_eq!(pipe_map.start_pipe(&path), Some(Pipe::SE));
+        }
+
+        #[test]
+        fn test_tiles() {
+            let tiles = helper_create_simple_pipe_map().tiles().unwrap();
+            assert_eq!(tiles[Pos::new(1, 1)], Tile::Start(Pipe::SE));
+            assert_eq!(tiles[Pos::new(1, 2)], Tile::Loop(Pipe::EW));
+            assert_eq!(tiles[Pos::new(2, 2)], Tile::Inside);
+            assert_eq!(tiles[Pos::new(0, 0)], Tile::Outside);
+            let inside = tiles.values().filter(|tile| **tile == Tile::Inside).count();
+            assert_eq!(inside, 1);
+        }
+
+        #[test]
+        fn test_start_corner() {
+            // The start is a `7`, which counting crossings used to guess was a pipe it crossed,
+            // putting the tile to its right inside
+            let input = "......
+.F--S.
+.|..|.
+.|..|.
+.L--J.
+";
+            assert_eq!(part2(input).unwrap(), "4");
+            assert_eq!(part2_ray_casting(input).unwrap(), "4");
+        }
+
+        #[test]
+        fn test_find_loop() {
+            // The pipe above the start joins up with it but runs off the map
+            let pipe_map = parse_pipe_map(".|...\n.S-7.\n.|.|.\n.L-J.\n.....")
+                .unwrap()
+                .1;
+            let path = pipe_map.find_loop().unwrap();
+            assert_eq!(path.len(), 8);
+            assert_eq!(path.last(), Some(&Pos::new(1, 1)));
+            assert!(!path.contains(&Pos::new(0, 1)));
+        }
+
+        #[test]
+        fn test_path_to_start() {
+            let pipe_map = helper_create_simple_pipe_map();
+            let path_to_start = pipe_map.path_to_start(pipe_map.get_start().unwrap(), Right);
+            assert_eq!(path_to_start.map(|path| path.len()), Some(8))
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::character::complete::{char, newline};
+use nom::combinator::{map, value};
+use nom::multi::{many1, separated_list1};
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Clone)]
+pub struct Image(Vec<Vec<Option<usize>>>);
+
+impl Image {
+    fn width(&self) -> usize {
+        self[0].len()
+    }
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn is_row_empty(&self, row: usize) -> bool {
+        self[row].iter().all(|galaxy| galaxy.is_none())
+    }
+
+    fn is_column_empty(&self, column: usize) -> bool {
+        self.iter()
+            .map(|row| row[column])
+            .all(|galaxy| galaxy.is_none())
+    }
+
+    fn expand_row_by(&mut self, row: usize, by: usize) {
+        for _ in 0..(by - 1) {
+            self.0.insert(row, self[row].clone());
+        }
+    }
+
+    fn expand_column_by(&mut self, column: usize, by: usize) {
+        self.0.iter_mut().for_each(|row| {
+            for _ in 0..(by - 1) {
+                row.insert(column, row[column]);
+            }
+        })
+    }
+
+    fn expand_by(&mut self, by: usize) {
+        // Expand rows
+        let rows_to_expand: Vec<_> = (0..self.height())
+            .rev() // Don't forget to work backwards
+            .filter(|row| self.is_row_empty(*row))
+            .collect();
+        rows_to_expand
+            .into_iter()
+            .for_each(|row| self.expand_row_by(row, by));
+        // Expand columns
+        let columns_to_expand: Vec<_> = (0..self.width())
+            .rev() // The expansion of space is no joke
+            .filter(|column| self.is_column_empty(*column))
+            .collect();
+        columns_to_expand
+            .into_iter()
+            .for_each(|column| self.expand_column_by(column, by))
+    }
+
+    fn get_galaxies(&self) -> Vec<GalaxyLocation> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(row, data)| {
+                data.iter()
+                    .enumerate()
+                    .filter_map(|(column, galaxy)| galaxy.map(|g| (column, g)))
+                    .map(move |(column, galaxy)| GalaxyLocation::new(galaxy, row, column))
+            })
+            .collect()
+    }
+
+    /// Where each galaxy ends up once every empty row and column has grown to `expansion` of
+    /// them, found by adding up how many empty rows and columns come before it rather than
+    /// inserting them
+    fn expanded_galaxies(&self, expansion: usize) -> Vec<GalaxyLocation> {
+        let offsets = |empty: Vec<bool>| -> Vec<usize> {
+            empty
+                .into_iter()
+                .scan(0, |position, empty| {
+                    let here = *position;
+                    *position += if empty { expansion } else { 1 };
+                    Some(here)
+                })
+                .collect()
+        };
+        let rows = offsets(
+            (0..self.height())
+                .map(|row| self.is_row_empty(row))
+                .collect(),
+        );
+        let columns = offsets(
+            (0..self.width())
+                .map(|column| self.is_column_empty(column))
+                .collect(),
+        );
+        self.get_galaxies()
+            .into_iter()
+            .map(|galaxy| {
+                GalaxyLocation::new(galaxy.name, rows[galaxy.row], columns[galaxy.column])
+            })
+            .collect()
+    }
+
+    /// Every pair of galaxies, each only once, once empty rows and columns have grown to
+    /// `expansion` of them
+    pub fn galaxy_pairs(&self, expansion: usize) -> impl Iterator<Item = GalaxyPair> {
+        self.expanded_galaxies(expansion)
+            .into_iter()
+            .tuple_combinations()
+            .map(|(from, to)| GalaxyPair {
+                distance: from.distance_to(&to),
+                from: from.name,
+                to: to.name,
+            })
+    }
+
+    /// The `k` pairs of galaxies closest together, closest first
+    pub fn closest_pairs(&self, expansion: usize, k: usize) -> Vec<GalaxyPair> {
+        self.galaxy_pairs(expansion).k_smallest(k).collect()
+    }
+}
+
+/// The distance between every pair of `positions`, one axis at a time. Once they're sorted, each
+/// is `position` on from every one before it, less however far along those were
+fn sum_of_gaps(mut positions: Vec<usize>) -> usize {
+    positions.sort_unstable();
+    let mut before = 0;
+    let mut sum = 0;
+    for (i, position) in positions.into_iter().enumerate() {
+        sum += position * i - before;
+        before += position;
+    }
+    sum
+}
+
+impl From<Vec<Vec<bool>>> for Image {
+    fn from(value: Vec<Vec<bool>>) -> Self {
+        let mut count = 0;
+        Self(
+            value
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|galaxy| {
+                            galaxy.then(|| {
+                                count += 1;
+                                count
+                            })
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Deref for Image {
+    type Target = Vec<Vec<Option<usize>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.iter()
+                .map(|row| row
+                    .iter()
+                    .map(|galaxy| if galaxy.is_some() { "#" } else { "." })
+                    .collect::<String>())
+                .join("\n")
+        )
+    }
+}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GalaxyLocation {
+    name: usize,
+    row: usize,
+    column: usize,
+}
+
+impl GalaxyLocation {
+    fn new(name: usize, row: usize, column: usize) -> Self {
+        Self { name, row, column }
+    }
+
+    fn distance_to(&self, other: &GalaxyLocation) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+/// Two galaxies, by the number they were given in the image, and how far apart they are
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GalaxyPair {
+    pub distance: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+fn parse_image(input: &str) -> IResult<&str, Image> {
+    map(
+        separated_list1(
+            newline,
+            many1(alt((value(true, char('#')), value(false, char('.'))))),
+        ),
+        |raw| raw.into(),
+    )(input)
+}
+
+fn get_image_from_input(input: &str) -> Result<Image, AocError> {
+    finish(parse_image, input)
+}
+
+/// The distances between every pair of galaxies, after each empty row and column has grown to
+/// `expansion` of them. Part 1 is an expansion of 2
+pub fn sum_of_distances(image: &Image, expansion: usize) -> usize {
+    let (rows, columns) = image
+        .expanded_galaxies(expansion)
+        .into_iter()
+        .map(|galaxy| (galaxy.row, galaxy.column))
+        .unzip();
+    sum_of_gaps(rows) + sum_of_gaps(columns)
+}
+
+/// Part 1 done by inserting the empty rows and columns into the image, then measuring, which is
+/// how it was first solved. Run it with `--impl expanding`
+pub fn part1_expanding(input: &str) -> Result<Answer, AocError> {
+    let mut image = get_image_from_input(input)?;
+    image.expand_by(2);
+
+    Ok(image
+        .get_galaxies()
+        .into_iter()
+        .tuple_combinations()
+        .map(|(from, to)| from.distance_to(&to))
+        .sum::<usize>()
+        .into())
+}
+
+pub struct Day11;
+
+impl Day for Day11 {
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type Parsed<'a> = Image;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        get_image_from_input(input)
+    }
+
+    fn part1(image: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(sum_of_distances(image, 2).into())
+    }
+
+    fn part2(image: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let expansion = day_param(11, "expansion")?.unwrap_or(1_000_000);
+        Ok(sum_of_distances(image, expansion).into())
+    }
+}
+
+solution! {
+    Day11 {
+        "d11.txt" => part1 "374", part2 "82000210";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_expanding(1, Solved).named("expanding"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day11::part1(&Day11::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day11::part2(&Day11::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::Random;
+
+    mod parts {
+        use super::*;
+
+        fn get_test_image() -> Image {
+            get_image_from_input(include_str!("examples/d11.txt")).unwrap()
+        }
+
+        #[test]
+        fn test_parse_image() {
+            let image = get_test_image();
+            assert_eq!(image.len(), 10);
+            assert!(image.iter().all(|row| row.len() == 10));
+            assert_eq!(
+                image
+                    .iter()
+                    .flatten()
+                    .filter_map(|g| *g)
+                    .collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+            )
+        }
+
+        #[test]
+        fn test_image_expand() {
+            let expected_expansion = "....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......";
+            let mut image = get_test_image();
+            image.expand_by(2);
+            assert_eq!(image.to_string(), expected_expansion);
+        }
+
+        #[test]
+        fn test_get_galaxies() {
+            let galaxies = get_test_image().get_galaxies();
+            assert_eq!(galaxies.len(), 9)
+        }
+
+        #[test]
+        fn test_distance_to() {
+            let g1 = GalaxyLocation::new(1, 6, 12);
+            let g2 = GalaxyLocation::new(1, 4, 8);
+            assert_eq!(g1.distance_to(&g2), 6);
+        }
+
+        #[test]
+        fn test_closest_pairs() {
+            let image = get_test_image();
+            assert_eq!(image.galaxy_pairs(2).count(), 36);
+            // From the puzzle, 5 to 9 is 9 and 8 to 9 is 5 apart
+            let pairs: Vec<_> = image.galaxy_pairs(2).collect();
+            assert!(pairs.contains(&GalaxyPair {
+                distance: 9,
+                from: 5,
+                to: 9
+            }));
+            assert!(pairs.contains(&GalaxyPair {
+                distance: 5,
+                from: 8,
+                to: 9
+            }));
+            let closest = image.closest_pairs(2, 3);
+            assert_eq!(closest.len(), 3);
+            assert!(closest.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert!(pairs
+                .iter()
+                .all(|pair| pair.distance >= closest[2].distance || closest.contains(pair)));
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let image = get_image_from_input(include_str!("examples/d11.txt")).unwrap();
+        assert_eq!(sum_of_distances(&image, 10), 1030);
+        assert_eq!(sum_of_distances(&image, 100), 8410);
+    }
+
+    #[test]
+    fn test_part2_expansion_matches_part1() {
+        let input = include_str!("examples/d11.txt");
+        let image = get_image_from_input(input).unwrap();
+        // Doubling empty space is exactly what part 1 does
+        assert_eq!(
+            part1_expanding(input).unwrap(),
+            sum_of_distances(&image, 2).to_string()
+        );
+    }
+
+    #[test]
+    fn test_expanded_galaxies() {
+        let image = get_image_from_input(include_str!("examples/d11.txt")).unwrap();
+        let mut expanded = image.clone();
+        expanded.expand_by(2);
+        assert_eq!(image.expanded_galaxies(2), expanded.get_galaxies());
+    }
+
+    /// A `size` square image with a galaxy in about one in `sparsity` places, from a deterministic
+    /// xorshift, leaving every tenth row and column empty
+    fn generated_image(size: usize, sparsity: u64) -> Image {
+        let mut random = Random::new(0x9e37_79b9);
+        (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|column| row % 10 != 3 && column % 10 != 7 && random.below(sparsity) == 0)
+                    .collect()
+            })
+            .collect::<Vec<Vec<bool>>>()
+            .into()
+    }
+
+    /// Measures every pair of galaxies in turn
+    fn sum_of_distances_by_pairs(image: &Image, expansion: usize) -> usize {
+        image
+            .galaxy_pairs(expansion)
+            .map(|pair| pair.distance)
+            .sum()
+    }
+
+    #[test]
+    fn test_gaps_agree_with_pairs() {
+        let image = generated_image(100, 20);
+        for expansion in [1, 2, 1_000_000] {
+            assert_eq!(
+                sum_of_distances(&image, expansion),
+                sum_of_distances_by_pairs(&image, expansion)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum_of_gaps() {
+        assert_eq!(sum_of_gaps(vec![5, 1, 3]), 2 + 4 + 2);
+        assert_eq!(sum_of_gaps(vec![]), 0);
+    }
+}
@@ -0,0 +1,616 @@
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::character::complete::{self, char, newline, space1};
+use nom::combinator::{map, value};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::separated_pair;
+use nom::IResult;
+use rayon::prelude::*;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::memo::Memo;
+use crate::parse::finish;
+use crate::progress::{NoProgress, Progress};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Condition {
+    Good,
+    Bad,
+}
+
+type Groups = Vec<u64>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionReport {
+    conditions: Vec<Option<Condition>>,
+    groups: Groups,
+    good_number: u32,
+    bad_number: u32,
+}
+
+impl ConditionReport {
+    /// The conditions as bits, the first condition highest, set where they're `condition`. An empty
+    /// row is 0, rather than there being nothing to load the number from
+    fn get_number(conditions: &[Option<Condition>], condition: Condition) -> u32 {
+        conditions.iter().fold(0, |number, known| {
+            number << 1 | u32::from(*known == Some(condition))
+        })
+    }
+
+    fn new(conditions: Vec<Option<Condition>>, groups: Groups) -> Self {
+        let good_number = Self::get_number(&conditions, Condition::Good);
+        let bad_number = Self::get_number(&conditions, Condition::Bad);
+        Self {
+            conditions,
+            good_number,
+            bad_number,
+            groups,
+        }
+    }
+
+    #[cfg(test)]
+    fn could_number_fit(&self, number: u32) -> bool {
+        number_fits(number, self.good_number, self.bad_number, &self.groups)
+    }
+
+    #[cfg(test)]
+    fn find_possible_arrangements(&self) -> usize {
+        count_fitting_numbers(
+            self.conditions.len(),
+            self.good_number,
+            self.bad_number,
+            &self.groups,
+        )
+    }
+
+    /// The conditions and groups repeated five times, with an unknown condition between each copy.
+    /// That's too long to fit the conditions in a number so this is counted with [count_unfolded]
+    fn unfold(&self) -> Unfolded {
+        let conditions = vec![self.conditions.clone(); 5].join(&None);
+        let groups = self.groups.repeat(5);
+        (conditions, groups)
+    }
+}
+
+/// A report's conditions and groups once they've been unfolded
+type Unfolded = (Vec<Option<Condition>>, Groups);
+
+/// How many arrangements there are for the end of a row and the groups left to fit in it. Keyed by
+/// what's left rather than where it is, so the same ends turning up in other rows are only counted
+/// once
+type Fits<'a> = Memo<(&'a [Option<Condition>], &'a [u64]), usize>;
+
+/// Rows up to this long are counted by trying every arrangement rather than with [count_fits],
+/// unless overridden with `12.brute_force_length`. `benches/day12.rs` has trying every arrangement
+/// faster for rows up to 12 conditions, and [count_fits] faster from 14
+const BRUTE_FORCE_LENGTH: usize = 12;
+
+/// Fit the first group at the start of the conditions, or skip the first condition, remembering
+/// how many arrangements there were for what was left of the conditions and groups
+fn count_fits<'a>(
+    conditions: &'a [Option<Condition>],
+    groups: &'a [u64],
+    memo: &mut Fits<'a>,
+) -> usize {
+    let Some((&group, other_groups)) = groups.split_first() else {
+        return usize::from(!conditions.contains(&Some(Condition::Bad)));
+    };
+    let Some(first) = conditions.first() else {
+        return 0;
+    };
+    let key = (conditions, groups);
+    if let Some(count) = memo.get(&key) {
+        return *count;
+    }
+
+    let mut count = 0;
+    if first != &Some(Condition::Bad) {
+        count += count_fits(&conditions[1..], groups, memo);
+    }
+    let group = group as usize;
+    if first != &Some(Condition::Good)
+        && conditions.len() >= group
+        && !conditions[..group].contains(&Some(Condition::Good))
+        && conditions.get(group) != Some(&Some(Condition::Bad))
+    {
+        let rest = conditions.get(group + 1..).unwrap_or_default();
+        count += count_fits(rest, other_groups, memo);
+    }
+
+    memo.insert(key, count);
+    count
+}
+
+/// How much can be trimmed from one end of a row, given the cells from that end and the group
+/// nearest it. The number of cells, and whether they take the group with them, or `None` if the
+/// group can't fit
+fn trim_end(cells: &[Option<Condition>], group: usize) -> Option<(usize, bool)> {
+    let cell = *cells.first()?;
+    if cell == Some(Condition::Good) {
+        let good = cells
+            .iter()
+            .take_while(|cell| **cell == Some(Condition::Good))
+            .count();
+        return Some((good, false));
+    }
+    if cell == Some(Condition::Bad) {
+        // The group has to start here, and a good spring has to follow it
+        let fits = cells.len() >= group
+            && !cells[..group].contains(&Some(Condition::Good))
+            && cells.get(group) != Some(&Some(Condition::Bad));
+        return fits.then_some(((group + 1).min(cells.len()), true));
+    }
+    let stretch = cells
+        .iter()
+        .take_while(|cell| **cell != Some(Condition::Good))
+        .count();
+    if stretch >= group {
+        Some((0, false))
+    } else if cells[..stretch].contains(&Some(Condition::Bad)) {
+        None
+    } else {
+        // Too short for the group, so every spring in it is good
+        Some((stretch, false))
+    }
+}
+
+/// The row with what has to be true trimmed from its ends: good springs, groups pinned against a
+/// bad spring at the end, and stretches too short for the group at that end. Leaves fewer
+/// conditions for [count_fits] or trying every arrangement, or `None` if there are no
+/// arrangements at all
+fn simplify<'a>(
+    mut conditions: &'a [Option<Condition>],
+    mut groups: &'a [u64],
+) -> Option<(&'a [Option<Condition>], &'a [u64])> {
+    loop {
+        let Some((&first, _)) = groups.split_first() else {
+            return (!conditions.contains(&Some(Condition::Bad))).then_some((&[], groups));
+        };
+        let (cells, fitted) = trim_end(conditions, first as usize)?;
+        conditions = &conditions[cells..];
+        if fitted {
+            groups = &groups[1..];
+        }
+        if cells > 0 {
+            continue;
+        }
+
+        let Some((&last, _)) = groups.split_last() else {
+            continue;
+        };
+        let reversed = conditions.iter().rev().copied().collect_vec();
+        let (cells, fitted) = trim_end(&reversed, last as usize)?;
+        conditions = &conditions[..conditions.len() - cells];
+        if fitted {
+            groups = &groups[..groups.len() - 1];
+        }
+        if cells == 0 {
+            return Some((conditions, groups));
+        }
+    }
+}
+
+/// Whether the runs of set bits in `number`, from the highest, are the sizes in `groups`
+fn number_has_groups(mut number: u32, groups: &[u64]) -> bool {
+    for &group in groups.iter().rev() {
+        if number == 0 {
+            return false;
+        }
+        number >>= number.trailing_zeros();
+        let run = number.trailing_ones();
+        if u64::from(run) != group {
+            return false;
+        }
+        number = number.checked_shr(run).unwrap_or(0);
+    }
+    number == 0
+}
+
+/// Whether `number` has every bit of `bad` set and none of `good`, in groups of `groups`
+fn number_fits(number: u32, good: u32, bad: u32, groups: &[u64]) -> bool {
+    number & bad == bad && number & good == 0 && number_has_groups(number, groups)
+}
+
+/// How many numbers `length` bits long fit, one for each arrangement of the conditions. `length`
+/// has to be under 32 to count them in a `u32`
+fn count_fitting_numbers(length: usize, good: u32, bad: u32, groups: &[u64]) -> usize {
+    (0..1 << length)
+        .filter(|&number| number_fits(number, good, bad, groups))
+        .count()
+}
+
+/// Tries every arrangement of what's left once the report has been simplified
+fn count_by_trying(conditions: &[Option<Condition>], groups: &[u64]) -> usize {
+    let good = ConditionReport::get_number(conditions, Condition::Good);
+    let bad = ConditionReport::get_number(conditions, Condition::Bad);
+    count_fitting_numbers(conditions.len(), good, bad, groups)
+}
+
+/// How long a row can be and still be counted by trying every arrangement. Never 32 or more, as
+/// there's no counting to 2^32 in a `u32`
+fn brute_force_length() -> Result<usize, AocError> {
+    Ok(day_param(12, "brute_force_length")?
+        .unwrap_or(BRUTE_FORCE_LENGTH)
+        .min(31))
+}
+
+/// Rows up to `brute_force_length` are tried every way, longer ones counted a condition at a time
+/// the same way as [count_unfolded]
+fn count_folded<'a>(
+    report: &'a ConditionReport,
+    brute_force_length: usize,
+    memo: &mut Fits<'a>,
+) -> usize {
+    let Some((conditions, groups)) = simplify(&report.conditions, &report.groups) else {
+        return 0;
+    };
+    if conditions.len() <= brute_force_length {
+        count_by_trying(conditions, groups)
+    } else {
+        count_fits(conditions, groups, memo)
+    }
+}
+
+fn count_brute_force(report: &ConditionReport, _memo: &mut Fits) -> usize {
+    simplify(&report.conditions, &report.groups).map_or(0, |(conditions, groups)| {
+        count_by_trying(conditions, groups)
+    })
+}
+
+fn count_unfolded<'a>((conditions, groups): &'a Unfolded, memo: &mut Fits<'a>) -> usize {
+    simplify(conditions, groups).map_or(0, |(conditions, groups)| {
+        count_fits(conditions, groups, memo)
+    })
+}
+
+impl From<(Vec<Option<Condition>>, Groups)> for ConditionReport {
+    fn from((known_conditions, groups): (Vec<Option<Condition>>, Groups)) -> Self {
+        Self::new(known_conditions, groups)
+    }
+}
+
+fn parse_condition(input: &str) -> IResult<&str, Option<Condition>> {
+    alt((
+        value(Some(Condition::Good), char('.')),
+        value(Some(Condition::Bad), char('#')),
+        value(None, char('?')),
+    ))(input)
+}
+
+fn parse_condition_report(input: &str) -> IResult<&str, ConditionReport> {
+    map(
+        separated_pair(
+            many1(parse_condition),
+            space1,
+            separated_list1(char(','), complete::u64),
+        ),
+        |parts| parts.into(),
+    )(input)
+}
+
+fn parse_condition_reports(input: &str) -> IResult<&str, Vec<ConditionReport>> {
+    separated_list1(newline, parse_condition_report)(input)
+}
+
+fn input_to_reports(input: &str) -> Result<Vec<ConditionReport>, AocError> {
+    finish(parse_condition_reports, input)
+}
+
+pub fn part1_with_progress(input: &str, progress: &dyn Progress) -> Result<Answer, AocError> {
+    Day12::part1_with_progress(&Day12::parse(input)?, progress)
+}
+
+/// Tries every way of filling in the unknown conditions, as bits of a number, which is how part 1
+/// was first solved. There's no counting to 2^32 in a `u32`, so rows have to be shorter than that
+pub fn part1_brute_force_with_progress(
+    input: &str,
+    progress: &dyn Progress,
+) -> Result<Answer, AocError> {
+    let reports = input_to_reports(input)?;
+    if reports.iter().any(|report| report.conditions.len() >= 32) {
+        return Err(AocError::parse(
+            "a row is too long to try every arrangement",
+        ));
+    }
+    Ok(count_arrangements(&reports, count_brute_force, progress)?.into())
+}
+
+pub fn part1_brute_force(input: &str) -> Result<Answer, AocError> {
+    part1_brute_force_with_progress(input, &NoProgress)
+}
+
+pub fn part2_with_progress(input: &str, progress: &dyn Progress) -> Result<Answer, AocError> {
+    Day12::part2_with_progress(&Day12::parse(input)?, progress)
+}
+
+/// The total of `count` for every row, giving up between rows if cancelled. Rows counted on the
+/// same thread share their [Fits]
+fn count_arrangements<'a, R: Sync>(
+    rows: &'a [R],
+    count: impl Fn(&'a R, &mut Fits<'a>) -> usize + Sync,
+    progress: &dyn Progress,
+) -> Result<usize, AocError> {
+    progress.set_length(rows.len() as u64);
+    rows.par_iter()
+        .map_init(Fits::new, |memo, row| {
+            progress.check_cancelled()?;
+            let arrangements = count(row, memo);
+            progress.inc(1);
+            Ok(arrangements)
+        })
+        .sum()
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Parsed<'a> = Vec<ConditionReport>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        input_to_reports(input)
+    }
+
+    fn part1(reports: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Self::part1_with_progress(reports, &NoProgress)
+    }
+
+    fn part2(reports: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Self::part2_with_progress(reports, &NoProgress)
+    }
+
+    fn part1_with_progress(
+        reports: &Self::Parsed<'_>,
+        progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        let brute_force_length = brute_force_length()?;
+        let count = |report, memo: &mut _| count_folded(report, brute_force_length, memo);
+        Ok(count_arrangements(reports, count, progress)?.into())
+    }
+
+    fn part2_with_progress(
+        reports: &Self::Parsed<'_>,
+        progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        let unfolded = reports.iter().map(ConditionReport::unfold).collect_vec();
+        Ok(count_arrangements(&unfolded, count_unfolded, progress)?.into())
+    }
+}
+
+solution! {
+    Day12 {
+        "d12.txt" => part1 "21", part2 "525152";
+    }
+    part1(1, Solved).with_progress(part1_with_progress),
+    part2(2, Solved).with_progress(part2_with_progress),
+    part1_brute_force(1, Solved)
+        .named("brute_force")
+        .with_progress(part1_brute_force_with_progress),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day12::part1(&Day12::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day12::part2(&Day12::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::progress::{CancelToken, Cancellable};
+    use crate::testing::{self, Random};
+
+    fn input_to_report(input: &str) -> ConditionReport {
+        parse_condition_report(input).unwrap().1
+    }
+
+    fn generated_report(random: &mut Random, length: usize) -> ConditionReport {
+        input_to_report(&testing::generated_row(random, length))
+    }
+
+    mod parts {
+        use super::*;
+
+        #[test]
+        fn test_parse_condition_report() {
+            let input = ".??..??...?##. 1,1,3";
+            let report = parse_condition_report(input).unwrap().1;
+            assert_eq!(report.conditions.len(), 14);
+            assert_eq!(report.groups, vec![1, 1, 3]);
+        }
+
+        #[test]
+        fn test_condition_report_numbers() {
+            let conditions = vec![
+                Some(Condition::Good),
+                Some(Condition::Good),
+                None,
+                Some(Condition::Bad),
+            ];
+            let num = ConditionReport::new(conditions, vec![]);
+
+            assert_eq!(num.good_number, 12);
+            assert_eq!(num.bad_number, 1);
+
+            let report = input_to_report("#???????#??????.#??. 10,1,1,1");
+            assert_eq!(report.good_number, 0b00000000000000010001);
+            assert_eq!(report.bad_number, 0b10000000100000001000);
+
+            let report = ConditionReport::new(vec![], vec![]);
+            assert_eq!((report.good_number, report.bad_number), (0, 0));
+            assert_eq!(report.find_possible_arrangements(), 1);
+        }
+
+        #[test]
+        fn test_could_number_fit() {
+            let report = input_to_report(".??..??...?##. 1,1,3");
+
+            let number = 0b01000100001110;
+            assert!(report.could_number_fit(number));
+            let number = 0b00100100001110;
+            assert!(report.could_number_fit(number));
+            let number = 0b01000010001110;
+            assert!(report.could_number_fit(number));
+            let number = 0b00100010001110;
+            assert!(report.could_number_fit(number));
+        }
+
+        #[test]
+        fn test_find_possible_conditions() {
+            let report = input_to_report("???.### 1,1,3");
+            assert_eq!(report.find_possible_arrangements(), 1);
+            let report = input_to_report(".??..??...?##. 1,1,3");
+            assert_eq!(report.find_possible_arrangements(), 4);
+            let report = input_to_report("?#?#?#?#?#?#?#? 1,3,1,6");
+            assert_eq!(report.find_possible_arrangements(), 1);
+            let report = input_to_report("????.######..#####. 1,6,5");
+            assert_eq!(report.find_possible_arrangements(), 4);
+            let report = input_to_report("?###???????? 3,2,1");
+            assert_eq!(report.find_possible_arrangements(), 10);
+        }
+
+        #[test]
+        fn test_count_fits() {
+            let unfolded = [1, 16384, 1, 16, 2500, 506250];
+            let lines = include_str!("examples/d12.txt").lines();
+            for (line, unfolded) in lines.zip(unfolded) {
+                let report = input_to_report(line);
+                let unfolded_report = report.unfold();
+                let mut memo = Fits::new();
+                assert_eq!(
+                    count_fits(&report.conditions, &report.groups, &mut memo),
+                    report.find_possible_arrangements(),
+                    "{line}"
+                );
+                assert_eq!(
+                    count_unfolded(&unfolded_report, &mut memo),
+                    unfolded,
+                    "{line}"
+                );
+            }
+        }
+
+        /// Simplifying `line` leaves `to`'s conditions and groups, or no arrangements if `None`
+        fn assert_simplifies(line: &str, to: Option<&str>) {
+            let report = input_to_report(line);
+            let to = to.map(|to| match to {
+                "" => (vec![], vec![]),
+                to => {
+                    let to = input_to_report(to);
+                    (to.conditions, to.groups)
+                }
+            });
+            let simplified = simplify(&report.conditions, &report.groups)
+                .map(|(conditions, groups)| (conditions.to_vec(), groups.to_vec()));
+            assert_eq!(simplified, to, "{line}");
+        }
+
+        #[test]
+        fn test_simplify() {
+            assert_simplifies("???.### 1,1,3", Some("??? 1,1"));
+            assert_simplifies("?#?#?#?#?#?#?#? 1,3,1,6", Some("?#?#?#?#?#?#?#? 1,3,1,6"));
+            assert_simplifies("#.#?? 1,1,1", Some("? 1"));
+            assert_simplifies("..??.??#??.. 3", Some("??#?? 3"));
+            assert_simplifies("?#?.#?#.??# 2,3,1", Some("?#? 2"));
+            assert_simplifies("##.##?? 2,2", Some(""));
+            assert_simplifies("?.## 2", Some(""));
+            assert_simplifies("#.# 2", None);
+            assert_simplifies("##. 1", None);
+            assert_simplifies("?#.? 3", None);
+            assert_simplifies("???.# 1", Some(""));
+            assert_simplifies("???.## 1", None);
+            assert_simplifies("?? 3", None);
+        }
+
+        #[test]
+        fn test_simplify_agrees_with_counting() {
+            let mut random = Random::new(12);
+            for _ in 0..500 {
+                let length = random.below(12) as usize + 1;
+                let report = generated_report(&mut random, length);
+                let expected = count_fits(&report.conditions, &report.groups, &mut Fits::new());
+                for brute_force_length in [0, 31] {
+                    let counted = count_folded(&report, brute_force_length, &mut Fits::new());
+                    assert_eq!(counted, expected);
+                }
+                assert_eq!(count_brute_force(&report, &mut Fits::new()), expected);
+            }
+        }
+
+        #[test]
+        fn test_number_has_groups() {
+            assert!(number_has_groups(5, &[1, 1]));
+            assert!(number_has_groups(13, &[2, 1]));
+            assert!(number_has_groups(0, &[]));
+            assert!(number_has_groups(u32::MAX, &[32]));
+            assert!(number_has_groups(
+                0b1000_0000_0000_0000_0000_0000_0000_0110,
+                &[1, 2]
+            ));
+            assert!(!number_has_groups(13, &[1, 2]));
+            assert!(!number_has_groups(13, &[2]));
+            assert!(!number_has_groups(5, &[1, 1, 1]));
+        }
+    }
+
+    #[test]
+    fn test_count_unfolded() {
+        let counts = include_str!("examples/d12.txt")
+            .lines()
+            .map(|line| count_unfolded(&input_to_report(line).unfold(), &mut Fits::new()))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn test_shared_fits() {
+        let unfolded = include_str!("examples/d12.txt")
+            .lines()
+            .map(|line| input_to_report(line).unfold())
+            .collect::<Vec<_>>();
+        let mut memo = Fits::new();
+        let counts = unfolded
+            .iter()
+            .map(|unfolded| count_unfolded(unfolded, &mut memo))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("examples/d12.txt");
+        assert_eq!(part2(input).unwrap(), "525152")
+    }
+
+    #[test]
+    fn test_brute_force_row_length() {
+        let input = format!("{} 1", "?".repeat(32));
+        assert_eq!(
+            part1_brute_force(&input),
+            Err(AocError::parse(
+                "a row is too long to try every arrangement"
+            ))
+        );
+        assert_eq!(part1(&input).unwrap(), "32");
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let input = include_str!("examples/d12.txt");
+        let token = CancelToken::new();
+        token.cancel();
+        let progress = Cancellable::new(&NoProgress, token);
+        assert_eq!(
+            part2_with_progress(input, &progress),
+            Err(AocError::Cancelled)
+        );
+    }
+}
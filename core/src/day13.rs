@@ -0,0 +1,397 @@
+use itertools::Itertools;
+use nom::combinator::map;
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::parse::{char_grid, finish, sections};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, PartialEq)]
+pub struct RockAndAshMap(Grid<bool>);
+
+/// How many places the rows differ
+fn differences(v1: &[bool], v2: &[bool]) -> usize {
+    v1.iter().zip(v2).filter(|(a, b)| a != b).count()
+}
+
+impl RockAndAshMap {
+    /// Whether the rows either side of `row` reflect each other once exactly `smudges` cells are
+    /// cleaned up. Part 1 has none and part 2 has one
+    fn is_mirror_point(&self, row: usize, smudges: usize) -> bool {
+        if row == 0 || row >= self.0.height() {
+            return false;
+        }
+
+        // We need to work outwards from the row
+        let rows = self.0.rows().collect_vec();
+        let rows_backwards = rows[0..row].iter().rev();
+        let rows_forward = rows[row..].iter();
+
+        let mut found = 0;
+        for (back, forward) in rows_backwards.zip(rows_forward) {
+            found += differences(back, forward);
+            if found > smudges {
+                return false;
+            }
+        }
+        found == smudges
+    }
+
+    fn find_mirror_point(&self, smudges: usize) -> Option<usize> {
+        (0..self.0.height()).find(|&row| self.is_mirror_point(row, smudges))
+    }
+
+    fn transpose(&self) -> RockAndAshMap {
+        RockAndAshMap(self.0.transpose())
+    }
+
+    /// 100 times the rows above a horizontal mirror, or else the columns left of a vertical one,
+    /// with `smudges` cells needing cleaning up. 0 if there's no mirror either way
+    pub fn summary(&self, smudges: usize) -> usize {
+        self.find_mirror_point(smudges)
+            .map(|mirror| mirror * 100)
+            .or_else(|| self.transpose().find_mirror_point(smudges))
+            .unwrap_or(0)
+    }
+}
+
+/// A map's rows and columns as bits, set for rock, so a whole row can be compared at once. How
+/// many cells two rows differ in is the number of bits set in them XORed together
+#[derive(Debug, PartialEq)]
+pub struct Masks {
+    rows: Vec<u64>,
+    columns: Vec<u64>,
+}
+
+fn mask<'a>(cells: impl Iterator<Item = &'a bool>) -> u64 {
+    cells.fold(0, |mask, rock| mask << 1 | u64::from(*rock))
+}
+
+/// Where in `lines` the lines either side reflect each other once `smudges` cells are cleaned up
+fn find_mirror(lines: &[u64], smudges: usize) -> Option<usize> {
+    (1..lines.len()).find(|&line| {
+        let mut found = 0;
+        lines[..line]
+            .iter()
+            .rev()
+            .zip(&lines[line..])
+            .all(|(back, forward)| {
+                found += (back ^ forward).count_ones() as usize;
+                found <= smudges
+            })
+            && found == smudges
+    })
+}
+
+impl Masks {
+    /// The map's masks, or None if it's more than 64 cells either way and they won't fit
+    pub fn new(map: &RockAndAshMap) -> Option<Self> {
+        let grid = &map.0;
+        if grid.width() > 64 || grid.height() > 64 {
+            return None;
+        }
+        Some(Self {
+            rows: grid.rows().map(|row| mask(row.iter())).collect(),
+            columns: (0..grid.width())
+                .map(|column| mask(grid.rows().map(|row| &row[column])))
+                .collect(),
+        })
+    }
+
+    /// The same as [RockAndAshMap::summary]
+    pub fn summary(&self, smudges: usize) -> usize {
+        find_mirror(&self.rows, smudges)
+            .map(|mirror| mirror * 100)
+            .or_else(|| find_mirror(&self.columns, smudges))
+            .unwrap_or(0)
+    }
+}
+
+/// The total of every map's [summary](Masks::summary), with `smudges` in each mirror. Maps too big
+/// for [Masks] are compared cell by cell instead
+pub fn summarise(maps: &[RockAndAshMap], smudges: usize) -> usize {
+    maps.iter()
+        .map(|map| match Masks::new(map) {
+            Some(masks) => masks.summary(smudges),
+            None => map.summary(smudges),
+        })
+        .sum()
+}
+
+/// [summarise] comparing the map's rows cell by cell, and transposing it to compare columns
+fn summarise_grids(maps: &[RockAndAshMap], smudges: usize) -> usize {
+    maps.iter().map(|map| map.summary(smudges)).sum()
+}
+
+/// Both parts comparing rows cell by cell, which is how they were first solved. Run them with
+/// `--impl grid`
+pub fn part1_grid(input: &str) -> Result<Answer, AocError> {
+    let smudges = day_param(13, "part1_smudges")?.unwrap_or(0);
+    Ok(summarise_grids(&Day13::parse(input)?, smudges).into())
+}
+
+pub fn part2_grid(input: &str) -> Result<Answer, AocError> {
+    let smudges = day_param(13, "part2_smudges")?.unwrap_or(1);
+    Ok(summarise_grids(&Day13::parse(input)?, smudges).into())
+}
+
+/// Rock will be true, ash will be false
+fn rock_or_ash_from_char(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_rock_and_ash_map(input: &str) -> IResult<&str, RockAndAshMap> {
+    map(char_grid(rock_or_ash_from_char), RockAndAshMap)(input)
+}
+
+fn parse_rock_and_ash_maps(input: &str) -> IResult<&str, Vec<RockAndAshMap>> {
+    sections(parse_rock_and_ash_map)(input)
+}
+
+pub struct Day13;
+
+impl Day for Day13 {
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Parsed<'a> = Vec<RockAndAshMap>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_rock_and_ash_maps, input)
+    }
+
+    fn part1(maps: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let smudges = day_param(13, "part1_smudges")?.unwrap_or(0);
+        Ok(summarise(maps, smudges).into())
+    }
+
+    fn part2(maps: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let smudges = day_param(13, "part2_smudges")?.unwrap_or(1);
+        Ok(summarise(maps, smudges).into())
+    }
+}
+
+solution! {
+    Day13 {
+        "d13.txt" => part1 "405", part2 "400";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_grid(1, Solved).named("grid"),
+    part2_grid(2, Solved).named("grid"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day13::part1(&Day13::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day13::part2(&Day13::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::{self, Random};
+
+    fn get_test_input() -> &'static str {
+        crate::examples::get_example(13, 1).unwrap().input
+    }
+
+    fn from_rows(rows: Vec<Vec<bool>>) -> RockAndAshMap {
+        RockAndAshMap(Grid::try_from(rows).unwrap())
+    }
+
+    /// A map up to 17 cells each way
+    fn generated_map(random: &mut Random) -> RockAndAshMap {
+        let width = random.below(15) as usize + 3;
+        let height = random.below(15) as usize + 3;
+        let map = testing::generated_map(random, width, height);
+        parse_rock_and_ash_map(&map).unwrap().1
+    }
+
+    mod parsers {
+        use super::*;
+
+        #[test]
+        fn test_rock_or_ash_from_char() {
+            assert_eq!(rock_or_ash_from_char('#'), Some(true));
+            assert_eq!(rock_or_ash_from_char('.'), Some(false));
+            assert_eq!(rock_or_ash_from_char('O'), None);
+        }
+
+        #[test]
+        fn test_parse_rock_and_ash_map() {
+            let input = "#..
+.#.
+.##";
+            let expected = from_rows(vec![
+                vec![true, false, false],
+                vec![false, true, false],
+                vec![false, true, true],
+            ]);
+            assert_eq!(parse_rock_and_ash_map(input), Ok(("", expected)));
+        }
+
+        #[test]
+        fn test_parse_rock_and_ash_maps() {
+            let input = get_test_input();
+            let maps = parse_rock_and_ash_maps(input).unwrap().1;
+
+            assert_eq!(maps.len(), 2);
+
+            assert_eq!(
+                maps[0].0.rows().next().unwrap(),
+                [true, false, true, true, false, false, true, true, false]
+            );
+
+            assert_eq!(
+                maps[1].0.rows().next().unwrap(),
+                [true, false, false, false, true, true, false, false, true]
+            );
+        }
+    }
+
+    mod rock_and_ash_map {
+        use super::*;
+
+        #[test]
+        fn test_transpose() {
+            let map = from_rows(vec![
+                vec![true, false, false],
+                vec![true, false, false],
+                vec![true, false, false],
+                vec![true, false, false],
+            ]);
+            let expected = from_rows(vec![
+                vec![true, true, true, true],
+                vec![false, false, false, false],
+                vec![false, false, false, false],
+            ]);
+            assert_eq!(map.transpose(), expected);
+        }
+
+        #[test]
+        fn test_find_mirror() {
+            let map = from_rows(vec![
+                vec![true, false, true],
+                vec![true, false, false],
+                vec![true, true, false],
+                vec![true, true, false],
+                vec![true, false, false],
+            ]);
+            assert_eq!(map.find_mirror_point(0), Some(3));
+
+            let map = from_rows(vec![
+                vec![true, true, false],
+                vec![true, true, false],
+                vec![true, false, false],
+                vec![true, false, false],
+                vec![true, false, false],
+            ]);
+            assert_eq!(map.find_mirror_point(0), Some(1));
+
+            let map = from_rows(vec![
+                vec![true, false, false],
+                vec![true, false, true],
+                vec![true, false, false],
+                vec![true, true, false],
+                vec![true, true, false],
+            ]);
+            assert_eq!(map.find_mirror_point(0), Some(4));
+        }
+
+        #[test]
+        fn test_differences() {
+            let v1 = vec![true, true, true, true];
+            let v2 = vec![true, true, false, true];
+            assert_eq!(differences(&v1, &v2), 1);
+
+            let v1 = vec![true, true, true, true];
+            let v2 = vec![true, false, false, true];
+            assert_eq!(differences(&v1, &v2), 2);
+        }
+
+        #[test]
+        fn test_find_mirror_point_with_smudge() {
+            let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+            let map = parse_rock_and_ash_map(input).unwrap().1;
+            assert_eq!(map.find_mirror_point(1), Some(3));
+            assert_eq!(map.find_mirror_point(0), None);
+        }
+
+        #[test]
+        fn test_summary() {
+            let map = from_rows(vec![vec![true, true, true], vec![false, false, true]]);
+            assert_eq!(map.summary(0), 1);
+            assert_eq!(map.summary(1), 2);
+            assert_eq!(map.summary(2), 100);
+            assert_eq!(map.summary(3), 0);
+
+            let maps = parse_rock_and_ash_maps(get_test_input()).unwrap().1;
+            assert_eq!(summarise_grids(&maps, 0), 405);
+            assert_eq!(summarise_grids(&maps, 1), 400);
+        }
+
+        #[test]
+        fn test_masks() {
+            let map = from_rows(vec![vec![true, false, false], vec![false, true, true]]);
+            let expected = Masks {
+                rows: vec![0b100, 0b011],
+                columns: vec![0b10, 0b01, 0b01],
+            };
+            assert_eq!(Masks::new(&map), Some(expected));
+            assert_eq!(Masks::new(&map).unwrap().summary(0), 2);
+
+            // Only mirrored down the middle, where the rock at each end meets
+            let mirrored_ends = |width: usize| {
+                let ends = (0..width).map(|column| column == 0 || column == width - 1);
+                from_rows(vec![vec![true; width], ends.collect()])
+            };
+            let wide = mirrored_ends(64);
+            assert_eq!(Masks::new(&wide).unwrap().summary(0), 32);
+
+            let too_wide = mirrored_ends(66);
+            assert_eq!(Masks::new(&too_wide), None);
+            assert_eq!(summarise(&[too_wide], 0), 33);
+        }
+
+        #[test]
+        fn test_masks_agree_with_grids() {
+            let mut random = Random::new(13);
+            let maps = (0..500).map(|_| generated_map(&mut random)).collect_vec();
+            for smudges in 0..3 {
+                assert_eq!(summarise(&maps, smudges), summarise_grids(&maps, smudges));
+            }
+        }
+
+        #[test]
+        fn test_smudges_give_each_part() {
+            let maps = Day13::parse(get_test_input()).unwrap();
+            assert_eq!(summarise(&maps, 0), 405);
+            assert_eq!(
+                Answer::from(summarise(&maps, 0)),
+                Day13::part1(&maps).unwrap()
+            );
+            assert_eq!(summarise(&maps, 1), 400);
+            assert_eq!(
+                Answer::from(summarise(&maps, 1)),
+                Day13::part2(&maps).unwrap()
+            );
+        }
+    }
+}
@@ -1,30 +1,25 @@
-use itertools::Itertools;
-use nom::branch::alt;
-use nom::character::complete;
-use nom::character::complete::newline;
-use nom::combinator::{map, value};
-use nom::multi::{many1, separated_list1};
+use nom::combinator::map;
 use nom::IResult;
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq)]
-enum Rock {
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::memo::Memo;
+use crate::parse::{char_grid, finish};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+pub enum Rock {
     Round,
     Cube,
 }
 
-fn roll_rocks(rocks: &[Option<Rock>]) -> Vec<Option<Rock>> {
-    rocks
-        .iter()
-        .copied()
-        .group_by(|rock| rock == &Some(Rock::Cube))
-        .into_iter()
-        .flat_map(|(_, subset)| {
-            let mut v = subset.into_iter().collect_vec();
-            v.sort();
-            v.reverse();
-            v
-        })
-        .collect()
+/// Roll the round rocks to the start of the row, until they hit a cube
+fn roll_rocks(rocks: &mut [Option<Rock>]) {
+    for between_cubes in rocks.split_mut(|rock| rock == &Some(Rock::Cube)) {
+        between_cubes.sort_by(|a, b| b.cmp(a));
+    }
 }
 
 fn get_load(rocks: &[Option<Rock>]) -> usize {
@@ -38,45 +33,26 @@ fn get_load(rocks: &[Option<Rock>]) -> usize {
         .sum::<usize>()
 }
 
-#[derive(Debug, Clone, PartialEq)]
-struct RockMap(Vec<Vec<Option<Rock>>>);
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RockMap(Grid<Option<Rock>>);
 
 impl RockMap {
     fn roll_rocks(&self) -> Self {
-        RockMap(self.0.iter().map(|row| roll_rocks(row)).collect())
+        let mut rolled = self.0.clone();
+        rolled.rows_mut().for_each(roll_rocks);
+        RockMap(rolled)
     }
 
     fn get_load(&self) -> usize {
-        self.0.iter().map(|row| get_load(row)).sum()
+        self.0.rows().map(get_load).sum()
     }
 
     fn rotate_counter_clockwise(&self) -> Self {
-        let mut temp = self.0.clone(); // Temp store, we'll rewrite all data but its now the same size
-        let row_length = self.0.len();
-        let column_length = self.0[0].len();
-
-        for row in 0..row_length {
-            for col in 0..column_length {
-                temp[column_length - col - 1][row] = self.0[row][col];
-            }
-        }
-
-        RockMap(temp)
+        RockMap(self.0.rotate_counter_clockwise())
     }
 
-    #[allow(clippy::needless_range_loop)] // Want to keep this the same as the other loop
     fn rotate_clockwise(&self) -> Self {
-        let mut temp = self.0.clone(); // Temp store, we'll rewrite all data but its now the same size
-        let row_length = self.0.len();
-        let column_length = self.0[0].len();
-
-        for row in 0..row_length {
-            for col in 0..column_length {
-                temp[col][column_length - row - 1] = self.0[row][col];
-            }
-        }
-
-        RockMap(temp)
+        RockMap(self.0.rotate_clockwise())
     }
 
     fn spin(&self) -> Self {
@@ -91,44 +67,75 @@ impl RockMap {
     }
 }
 
-fn parse_rock(input: &str) -> IResult<&str, Option<Rock>> {
-    alt((
-        value(Some(Rock::Round), complete::char('O')),
-        value(Some(Rock::Cube), complete::char('#')),
-        value(None, complete::char('.')),
-    ))(input)
+/// What's in a cell of the map, which might be nothing but it has to be a known something
+fn rock_from_char(c: char) -> Option<Option<Rock>> {
+    match c {
+        'O' => Some(Some(Rock::Round)),
+        '#' => Some(Some(Rock::Cube)),
+        '.' => Some(None),
+        _ => None,
+    }
 }
 
-fn parse_rocks(input: &str) -> IResult<&str, Vec<Option<Rock>>> {
-    many1(parse_rock)(input)
+fn parse_rock_map(input: &str) -> IResult<&str, RockMap> {
+    map(char_grid(rock_from_char), RockMap)(input)
 }
 
-fn parse_rock_map(input: &str) -> IResult<&str, RockMap> {
-    map(separated_list1(newline, parse_rocks), RockMap)(input)
+fn get_prerotated_map(input: &str) -> Result<RockMap, AocError> {
+    Ok(finish(parse_rock_map, input)?.rotate_counter_clockwise())
 }
 
-fn get_prerotated_map(input: &str) -> RockMap {
-    parse_rock_map(input).unwrap().1.rotate_counter_clockwise()
+pub struct Day14;
+
+impl Day for Day14 {
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    /// The map turned so north is at the start of each row, which is where the rocks roll to
+    type Parsed<'a> = RockMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        get_prerotated_map(input)
+    }
+
+    fn part1(rock_map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(rock_map.roll_rocks().get_load().into())
+    }
+
+    fn part2(rock_map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut history = vec![rock_map.clone()];
+        let mut seen = Memo::new();
+        seen.insert(rock_map.clone(), 0);
+        let loop_start = loop {
+            let new_map = history.last().unwrap().spin();
+            if let Some(pos) = seen.get(&new_map) {
+                break *pos;
+            }
+            seen.insert(new_map.clone(), history.len());
+            history.push(new_map);
+        };
+        let loop_size = history.len() - loop_start;
+        let billionth_map_pos = ((1_000_000_000_usize - loop_start) % loop_size) + loop_start;
+        Ok(Answer::from(history[billionth_map_pos].get_load())
+            .with_stat("loop_start", loop_start)
+            .with_stat("loop_size", loop_size))
+    }
 }
 
-pub fn part1(input: &str) -> String {
-    let rock_map = get_prerotated_map(input).roll_rocks();
-    rock_map.get_load().to_string()
+solution! {
+    Day14 {
+        "d14.txt" => part1 "136", part2 "64";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
 }
 
-pub fn part2(input: &str) -> String {
-    let mut history = vec![get_prerotated_map(input)];
-    let loop_start = loop {
-        let new_map = history.last().unwrap().spin();
-        let found_pos = history.iter().position(|map| map == &new_map);
-        if let Some(pos) = found_pos {
-            break pos;
-        }
-        history.push(new_map);
-    };
-    let loop_size = history.len() - loop_start;
-    let billionth_map_pos = ((1_000_000_000_usize - loop_start) % loop_size) + loop_start;
-    history[billionth_map_pos].get_load().to_string()
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day14::part1(&Day14::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day14::part2(&Day14::parse(input)?)
 }
 
 #[cfg(test)]
@@ -136,16 +143,7 @@ mod test {
     use super::*;
 
     fn get_test_input() -> &'static str {
-        "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#...."
+        crate::examples::get_example(14, 1).unwrap().input
     }
 
     mod rocks {
@@ -153,14 +151,19 @@ O.#..O.#.#
 
         use super::*;
 
+        fn from_rows(rows: Vec<Vec<Option<Rock>>>) -> RockMap {
+            RockMap(Grid::try_from(rows).unwrap())
+        }
+
         #[test]
         fn test_get_prerotated_map() {
             let rock_map = get_prerotated_map(
                 "#.O
 ..O
 ..O",
-            );
-            let expected = RockMap(vec![
+            )
+            .unwrap();
+            let expected = from_rows(vec![
                 vec![Some(Round), Some(Round), Some(Round)],
                 vec![None, None, None],
                 vec![Some(Cube), None, None],
@@ -171,7 +174,7 @@ O.#..O.#.#
 
         #[test]
         fn test_roll_rocks() {
-            let rocks = vec![
+            let mut rocks = vec![
                 None,
                 Some(Round),
                 Some(Cube),
@@ -189,7 +192,8 @@ O.#..O.#.#
                 None,
                 None,
             ];
-            assert_eq!(roll_rocks(&rocks), expected);
+            roll_rocks(&mut rocks);
+            assert_eq!(rocks, expected);
         }
 
         #[test]
@@ -207,12 +211,12 @@ O.#..O.#.#
 
         #[test]
         fn test_rotate_counter_clockwise() {
-            let rocks = RockMap(vec![
+            let rocks = from_rows(vec![
                 vec![Some(Cube), None, Some(Round)],
                 vec![Some(Cube), None, None],
                 vec![Some(Cube), None, Some(Cube)],
             ]);
-            let expected = RockMap(vec![
+            let expected = from_rows(vec![
                 vec![Some(Round), None, Some(Cube)],
                 vec![None, None, None],
                 vec![Some(Cube), Some(Cube), Some(Cube)],
@@ -223,12 +227,12 @@ O.#..O.#.#
 
         #[test]
         fn test_rotate_clockwise() {
-            let rocks = RockMap(vec![
+            let rocks = from_rows(vec![
                 vec![Some(Cube), None, Some(Round)],
                 vec![Some(Cube), None, None],
                 vec![Some(Cube), None, Some(Cube)],
             ]);
-            let expected = RockMap(vec![
+            let expected = from_rows(vec![
                 vec![Some(Cube), Some(Cube), Some(Cube)],
                 vec![None, None, None],
                 vec![Some(Cube), None, Some(Round)],
@@ -239,12 +243,12 @@ O.#..O.#.#
 
         #[test]
         fn test_roll_map() {
-            let rocks = RockMap(vec![
+            let rocks = from_rows(vec![
                 vec![Some(Cube), None, Some(Round)],
                 vec![None, Some(Round), Some(Round)],
                 vec![None, Some(Cube), Some(Round)],
             ]);
-            let expected = RockMap(vec![
+            let expected = from_rows(vec![
                 vec![Some(Cube), Some(Round), None],
                 vec![Some(Round), Some(Round), None],
                 vec![None, Some(Cube), Some(Round)],
@@ -255,7 +259,7 @@ O.#..O.#.#
 
         #[test]
         fn test_spin() {
-            let initial = get_prerotated_map(get_test_input());
+            let initial = get_prerotated_map(get_test_input()).unwrap();
             let expected_input_1 = ".....#....
 ....#...O#
 ...OO##...
@@ -266,20 +270,8 @@ O.#..O.#.#
 ......OOOO
 #...O###..
 #..OO#....";
-            let expected = get_prerotated_map(expected_input_1);
+            let expected = get_prerotated_map(expected_input_1).unwrap();
             assert_eq!(initial.spin(), expected);
         }
     }
-
-    #[test]
-    fn test_part1() {
-        let input = get_test_input();
-        assert_eq!(part1(input), "136");
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = get_test_input();
-        assert_eq!(part2(input), "64")
-    }
 }
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use nom::bytes::complete::is_not;
+use nom::character::complete;
+use nom::multi::separated_list1;
+use nom::IResult;
+use tracing::trace;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution;
+use crate::solver::Day;
+
+fn hash(input: &str) -> usize {
+    input
+        .bytes()
+        .map(|byte| byte as usize)
+        .fold(0_usize, |acc, cur| ((acc + cur) * 17) % 256)
+}
+
+fn parse_steps(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(complete::char(','), is_not(",\n"))(input)
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq)]
+struct Lens {
+    label: String,
+    focal_length: usize,
+}
+
+impl Lens {
+    fn new(value: &str) -> Result<Self, AocError> {
+        let (label, focal_length) = value
+            .split_once('=')
+            .ok_or_else(|| AocError::parse(format!("{value} has no focal length")))?;
+        Ok(Self {
+            label: label.to_string(),
+            focal_length: focal_length
+                .parse()
+                .map_err(|_| AocError::parse(format!("{focal_length} isn't a focal length")))?,
+        })
+    }
+
+    fn get_hash(&self) -> usize {
+        hash(&self.label)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Instruction {
+    AddLens(Lens),
+    RemoveLens(String),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = AocError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.contains('=') {
+            Ok(Instruction::AddLens(Lens::new(value)?))
+        } else if value.ends_with('-') {
+            Ok(Instruction::RemoveLens(
+                value.trim_end_matches('-').to_string(),
+            ))
+        } else {
+            Err(AocError::parse(format!(
+                "{value} did not contain = or end with -"
+            )))
+        }
+    }
+}
+
+struct Box(Vec<Lens>);
+
+impl Box {
+    fn new() -> Self {
+        Self(vec![])
+    }
+
+    fn add_lens(&mut self, lens: Lens) {
+        if let Some((pos, _)) = self.0.iter().find_position(|l| l.label == lens.label) {
+            let _ = std::mem::replace(&mut self.0[pos], lens);
+        } else {
+            self.0.push(lens);
+        }
+    }
+
+    fn remove_lens(&mut self, label: &str) {
+        if let Some((pos, _)) = self.0.iter().find_position(|l| l.label == label) {
+            self.0.remove(pos);
+        }
+    }
+}
+
+/// Kept in box order, which is the order their focusing power is added up in
+struct Boxes(BTreeMap<usize, Box>);
+
+impl Boxes {
+    fn new() -> Self {
+        Boxes(BTreeMap::new())
+    }
+
+    fn apply(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::AddLens(lens) => {
+                let bx = self.0.entry(lens.get_hash()).or_insert(Box::new());
+                bx.add_lens(lens);
+            }
+            Instruction::RemoveLens(label) => {
+                let bx = self.0.entry(hash(&label)).or_insert(Box::new());
+                bx.remove_lens(&label);
+            }
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Day for Day15 {
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    /// The steps of the initialization sequence, which part 2 reads as instructions
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_steps, input)
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(steps.iter().copied().map(hash).sum::<usize>().into())
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let instructions = steps
+            .iter()
+            .copied()
+            .map(Instruction::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(instructions
+            .into_iter()
+            .fold(Boxes::new(), |mut boxes, instruction| {
+                boxes.apply(instruction);
+                boxes
+            })
+            .0
+            .into_iter()
+            .flat_map(|(h, bx)| {
+                bx.0.into_iter().enumerate().map(move |(slot, lens)| {
+                    let box_n = h + 1;
+                    let slot_n = slot + 1;
+                    let focal_length = lens.focal_length;
+                    let focusing_power = box_n * slot_n * focal_length;
+                    let label = &lens.label;
+                    trace!("{label}: {box_n} (box {h}) * {slot_n} (slot) * {focal_length} (focal length) = {focusing_power}");
+                    focusing_power
+                })
+            })
+            .sum::<usize>()
+            .into())
+    }
+}
+
+solution! {
+    Day15 {
+        "d15.txt" => part1 "1320", part2 "145";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day15::part1(&Day15::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day15::part2(&Day15::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("ot"), 3);
+    }
+
+    #[test]
+    fn test_instruction_from() {
+        let instruction = "rn=1";
+        assert_eq!(
+            Instruction::try_from(instruction),
+            Ok(Instruction::AddLens(Lens {
+                label: "rn".to_string(),
+                focal_length: 1,
+            }))
+        );
+        let instruction = "cm-";
+        assert_eq!(
+            Instruction::try_from(instruction),
+            Ok(Instruction::RemoveLens("cm".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_boxes() {
+        let steps = Day15::parse(include_str!("examples/d15.txt")).unwrap();
+        let mut boxes = Boxes::new();
+        for step in steps {
+            boxes.apply(Instruction::try_from(step).unwrap());
+        }
+        let labels: Vec<_> = boxes
+            .0
+            .iter()
+            .map(|(number, bx)| {
+                let labels: Vec<_> = bx.0.iter().map(|lens| lens.label.as_str()).collect();
+                (*number, labels)
+            })
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                (0, vec!["rn", "cm"]),
+                (1, vec![]),
+                (3, vec!["ot", "ab", "pc"])
+            ]
+        );
+    }
+}
@@ -2,27 +2,21 @@ use std::fmt::{Display, Formatter};
 
 use derive_more::{Deref, DerefMut, From as FromMore};
 use itertools::Itertools;
-use nom::branch::alt;
-use nom::character::complete;
-use nom::character::complete::newline;
-use nom::combinator::{map, value};
-use nom::multi::{many1, separated_list1};
-use nom::IResult;
-
-use crate::day16::Direction::*;
-use crate::day16::TileType::*;
+use tracing::trace;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use crate::answer::Answer;
+use crate::day16::TileType::*;
+use crate::error::AocError;
+use crate::grid::Direction::{self, *};
+use crate::grid::{ArrayCells, Cells, Grid, Pos};
+use crate::parse::{char_grid, finish};
+use crate::solution;
+use crate::solver::Day;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
 enum TileType {
     // .
+    #[default]
     Empty,
     // /
     MirrorForward,
@@ -36,38 +30,19 @@ enum TileType {
 
 impl TileType {
     fn process_light(&self, direction: Direction) -> (Direction, Option<Direction>) {
-        match self {
-            Empty => (direction, None),
-            MirrorForward => match direction {
-                Up => (Right, None),
-                Down => (Left, None),
-                Left => (Down, None),
-                Right => (Up, None),
-            },
-            MirrorBackward => match direction {
-                Up => (Left, None),
-                Down => (Right, None),
-                Left => (Up, None),
-                Right => (Down, None),
-            },
-            VerticalSplitter => match direction {
-                Up => (Up, None),
-                Down => (Down, None),
-                Left => (Up, Some(Down)),
-                Right => (Up, Some(Down)),
-            },
-            HorizontalSplitter => match direction {
-                Up => (Left, Some(Right)),
-                Down => (Left, Some(Right)),
-                Left => (Left, None),
-                Right => (Right, None),
-            },
+        match (self, direction.is_vertical()) {
+            (Empty, _) => (direction, None),
+            (MirrorForward, true) | (MirrorBackward, false) => (direction.turn_right(), None),
+            (MirrorForward, false) | (MirrorBackward, true) => (direction.turn_left(), None),
+            (VerticalSplitter, true) | (HorizontalSplitter, false) => (direction, None),
+            (VerticalSplitter, false) => (Up, Some(Down)),
+            (HorizontalSplitter, true) => (Left, Some(Right)),
         }
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-struct Tile {
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Tile {
     tile_type: TileType,
     seen_up: bool,
     seen_down: bool,
@@ -117,60 +92,77 @@ impl Tile {
     }
 }
 
+/// The real input is 110 by 110
+const MAX_TILES: usize = 110 * 110;
+
+/// Part 2 starts from a fresh copy of the map for every edge tile, so the tiles are kept inline
+/// where copying them is one copy, but any [Cells] will do
 #[derive(Debug, Clone, Deref, DerefMut, FromMore)]
-#[deref(forward)]
-struct TileMap(Vec<Vec<Tile>>);
+pub struct TileMap<C = ArrayCells<Tile, MAX_TILES>>(Grid<Tile, C>);
 
-impl TileMap {
+impl<C: Cells<Tile> + Clone> TileMap<C> {
     fn energy_level(&self) -> usize {
-        self.iter()
-            .flat_map(|row| row.iter())
-            .filter(|tile| tile.is_energized())
-            .count()
+        self.values().filter(|tile| tile.is_energized()).count()
     }
 
     fn process_light(&mut self, pos: Pos, direction: Direction) {
-        let Pos { row, column } = pos;
         // This will early return if the tile has already seen light go in that direction
-        let Some((next, maybe_also)) = self[row][column].process_light(direction) else {
+        let Some((next, maybe_also)) = self[pos].process_light(direction) else {
             return;
         };
         // Deal with the direction we just got back
-        if let Some(next_pos) = self.get_next_pos(pos, next) {
+        if let Some(next_pos) = self.step(pos, next) {
             self.process_light(next_pos, next);
         }
         // If the beam hit a spliter
         if let Some(maybe_direction) = maybe_also {
-            if let Some(next_pos) = self.get_next_pos(pos, maybe_direction) {
+            if let Some(next_pos) = self.step(pos, maybe_direction) {
                 self.process_light(next_pos, maybe_direction);
             }
         }
     }
 
-    fn get_next_pos(&self, pos: Pos, direction: Direction) -> Option<Pos> {
-        pos.apply_direction(direction)
-            .filter(|pos| self.pos_is_in_bounds(*pos))
+    /// How many tiles are energised with the beam starting from the top left going right
+    fn energised_from_top_left(&self) -> usize {
+        let mut tile_map = self.clone();
+        tile_map.process_light(Pos::default(), Right);
+        trace!("energised tiles:\n{tile_map}");
+        tile_map.energy_level()
     }
 
-    fn pos_is_in_bounds(&self, pos: Pos) -> bool {
-        pos.row < self.height() && pos.column < self.width()
-    }
+    /// The most tiles energised with the beam coming in from any edge tile
+    fn most_energised(&self) -> Result<usize, AocError> {
+        let mut energy_levels: Vec<usize> = Vec::with_capacity((self.width() + self.height()) * 2);
 
-    fn width(&self) -> usize {
-        self[0].len()
-    }
+        for row in 0..self.height() {
+            for (direction, column) in [(Right, 0), (Left, self.width() - 1)] {
+                let mut clone = self.clone();
+                clone.process_light(Pos { row, column }, direction);
+                energy_levels.push(clone.energy_level());
+            }
+        }
 
-    fn height(&self) -> usize {
-        self.len()
+        for column in 0..self.width() {
+            for (direction, row) in [(Down, 0), (Up, self.height() - 1)] {
+                let mut clone = self.clone();
+                clone.process_light(Pos { row, column }, direction);
+                energy_levels.push(clone.energy_level());
+            }
+        }
+
+        energy_levels
+            .into_iter()
+            .max()
+            .ok_or_else(|| AocError::no_answer("the map is empty"))
     }
 }
 
-impl Display for TileMap {
+impl<C: Cells<Tile>> Display for TileMap<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
-            self.iter()
+            self.rows()
                 .map(|row| row
                     .iter()
                     .map(|tile| if tile.is_energized() { "#" } else { "." })
@@ -180,82 +172,72 @@ impl Display for TileMap {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-struct Pos {
-    row: usize,
-    column: usize,
+fn tile_from_char(c: char) -> Option<Tile> {
+    let tile_type = match c {
+        '.' => Empty,
+        '/' => MirrorForward,
+        '\\' => MirrorBackward,
+        '|' => VerticalSplitter,
+        '-' => HorizontalSplitter,
+        _ => return None,
+    };
+    Some(Tile::new(tile_type))
 }
 
-impl Pos {
-    fn apply_direction(&self, direction: Direction) -> Option<Self> {
-        let Pos { row, column } = *self;
-        match direction {
-            Up => (row > 0).then_some(Pos {
-                row: row.saturating_sub(1),
-                column,
-            }),
-            Down => Some(Pos {
-                row: row + 1,
-                column,
-            }),
-            Left => (column > 0).then_some(Pos {
-                row,
-                column: column.saturating_sub(1),
-            }),
-            Right => Some(Pos {
-                row,
-                column: column + 1,
-            }),
-        }
-    }
+fn input_into_tile_map<C: Cells<Tile>>(input: &str) -> Result<TileMap<C>, AocError> {
+    Ok(finish(char_grid(tile_from_char), input)?
+        .into_cells()?
+        .into())
 }
 
-fn parse_tile(input: &str) -> IResult<&str, Tile> {
-    alt((
-        value(Tile::new(Empty), complete::char('.')),
-        value(Tile::new(MirrorForward), complete::char('/')),
-        value(Tile::new(MirrorBackward), complete::char('\\')),
-        value(Tile::new(VerticalSplitter), complete::char('|')),
-        value(Tile::new(HorizontalSplitter), complete::char('-')),
-    ))(input)
-}
+pub struct Day16;
 
-fn parse_tile_map(input: &str) -> IResult<&str, TileMap> {
-    map(separated_list1(newline, many1(parse_tile)), TileMap::from)(input)
-}
+impl Day for Day16 {
+    const DAY: usize = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
 
-fn input_into_tile_map(input: &str) -> TileMap {
-    parse_tile_map(input).expect("failed to parse tile map").1
-}
+    type Parsed<'a> = TileMap;
 
-pub fn part1(input: &str) -> String {
-    let mut tile_map = input_into_tile_map(input);
-    tile_map.process_light(Pos::default(), Right);
-    // eprintln!("{tile_map}");
-    tile_map.energy_level().to_string()
-}
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        input_into_tile_map(input)
+    }
 
-pub fn part2(input: &str) -> String {
-    let map = input_into_tile_map(input);
-    let mut energy_levels: Vec<usize> = Vec::with_capacity((map.width() + map.height()) * 2);
+    fn part1(map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(map.energised_from_top_left().into())
+    }
 
-    for row in 0..map.height() {
-        for (direction, column) in [(Right, 0), (Left, map.width() - 1)] {
-            let mut clone = map.clone();
-            clone.process_light(Pos { row, column }, direction);
-            energy_levels.push(clone.energy_level());
-        }
+    fn part2(map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(map.most_energised()?.into())
     }
+}
 
-    for column in 0..map.width() {
-        for (direction, row) in [(Down, 0), (Up, map.height() - 1)] {
-            let mut clone = map.clone();
-            clone.process_light(Pos { row, column }, direction);
-            energy_levels.push(clone.energy_level());
-        }
+solution! {
+    Day16 {
+        "d16.txt" => part1 "46", part2 "51";
     }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_vec_grid(1, Solved).named("vec_grid"),
+    part2_vec_grid(2, Solved).named("vec_grid"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day16::part1(&Day16::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day16::part2(&Day16::parse(input)?)
+}
+
+/// Part 1 with the tiles kept on the heap, to compare with the inline map
+pub fn part1_vec_grid(input: &str) -> Result<Answer, AocError> {
+    let map = input_into_tile_map::<Vec<Tile>>(input)?;
+    Ok(map.energised_from_top_left().into())
+}
 
-    energy_levels.into_iter().max().unwrap().to_string()
+pub fn part2_vec_grid(input: &str) -> Result<Answer, AocError> {
+    let map = input_into_tile_map::<Vec<Tile>>(input)?;
+    Ok(map.most_energised()?.into())
 }
 
 #[cfg(test)]
@@ -337,34 +319,4 @@ mod test {
             assert_eq!(tile.process_light(Right), None);
         }
     }
-
-    #[test]
-    fn test_part1() {
-        let input = r#".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."#;
-        assert_eq!(part1(input), "46");
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = r#".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."#;
-        assert_eq!(part2(input), "51");
-    }
 }
@@ -0,0 +1,168 @@
+use std::ops::RangeInclusive;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::grid::{ArrayCells, Cells, Direction, Grid, Pos};
+use crate::parse::{char_grid, finish};
+use crate::solution;
+use crate::solver::Day;
+
+/// How far a crucible can go before it has to turn
+const CRUCIBLE: RangeInclusive<usize> = 1..=3;
+/// How far an ultra crucible has to go before it can turn, and can go before it has to
+const ULTRA_CRUCIBLE: RangeInclusive<usize> = 4..=10;
+/// The real input is 141 by 141
+const MAX_BLOCKS: usize = 141 * 141;
+
+/// How much heat is lost entering each block. Every block is looked at for every way through it,
+/// so they're kept inline, but any [Cells] will do
+type HeatLosses<C = ArrayCells<u8, MAX_BLOCKS>> = Grid<u8, C>;
+
+fn parse_heat_losses<C: Cells<u8>>(input: &str) -> Result<HeatLosses<C>, AocError> {
+    let heat_loss = |c: char| c.to_digit(10).map(|heat_loss| heat_loss as u8);
+    finish(char_grid(heat_loss), input)?.into_cells()
+}
+
+/// Each block twice over, for whether the crucible got there going up or down or going across,
+/// as it has to turn the other way next
+fn node<C: Cells<u8>>(heat_losses: &HeatLosses<C>, pos: Pos, vertical: bool) -> usize {
+    heat_losses.cell_index(pos) * 2 + usize::from(vertical)
+}
+
+/// Edges for every straight run the crucible can make before turning, weighted by the heat lost
+/// entering each block on the way
+fn crucible_graph<C: Cells<u8>>(
+    heat_losses: &HeatLosses<C>,
+    runs: &RangeInclusive<usize>,
+) -> Graph {
+    let mut graph = Graph::new(heat_losses.width() * heat_losses.height() * 2);
+    for from in heat_losses.positions() {
+        for direction in Direction::ALL {
+            let vertical = direction.is_vertical();
+            let mut to = from;
+            let mut heat_loss = 0;
+            for distance in 1..=*runs.end() {
+                let Some(next) = heat_losses.step(to, direction) else {
+                    break;
+                };
+                to = next;
+                heat_loss += usize::from(heat_losses[to]);
+                if runs.contains(&distance) {
+                    graph.add_weighted_edge(
+                        node(heat_losses, from, !vertical),
+                        node(heat_losses, to, vertical),
+                        heat_loss,
+                    );
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// The least heat lost getting from the top left to the bottom right, starting off either way
+fn least_heat_loss<C: Cells<u8>>(
+    heat_losses: &HeatLosses<C>,
+    runs: &RangeInclusive<usize>,
+) -> Result<usize, AocError> {
+    if heat_losses.width() == 0 || heat_losses.height() == 0 {
+        return Err(AocError::no_answer("there are no blocks"));
+    }
+    let start = Pos::default();
+    let goal = Pos::new(heat_losses.height() - 1, heat_losses.width() - 1);
+    let costs = crucible_graph(heat_losses, runs)
+        .dijkstra([true, false].map(|vertical| node(heat_losses, start, vertical)));
+    [true, false]
+        .into_iter()
+        .filter_map(|vertical| costs[node(heat_losses, goal, vertical)])
+        .min()
+        .ok_or_else(|| AocError::no_answer("the crucible can't get to the factory"))
+}
+
+pub struct Day17;
+
+impl Day for Day17 {
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    type Parsed<'a> = HeatLosses;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        parse_heat_losses(input)
+    }
+
+    fn part1(heat_losses: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(least_heat_loss(heat_losses, &CRUCIBLE)?.into())
+    }
+
+    fn part2(heat_losses: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(least_heat_loss(heat_losses, &ULTRA_CRUCIBLE)?.into())
+    }
+}
+
+solution! {
+    Day17 {
+        "d17.txt" => part1 "102", part2 "94";
+        "d17_part2.txt" => part2 "71";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+    part1_vec_grid(1, Solved).named("vec_grid"),
+    part2_vec_grid(2, Solved).named("vec_grid"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day17::part1(&Day17::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day17::part2(&Day17::parse(input)?)
+}
+
+/// Part 1 with the blocks kept on the heap, to compare with the inline grid
+pub fn part1_vec_grid(input: &str) -> Result<Answer, AocError> {
+    let heat_losses = parse_heat_losses::<Vec<u8>>(input)?;
+    Ok(least_heat_loss(&heat_losses, &CRUCIBLE)?.into())
+}
+
+pub fn part2_vec_grid(input: &str) -> Result<Answer, AocError> {
+    let heat_losses = parse_heat_losses::<Vec<u8>>(input)?;
+    Ok(least_heat_loss(&heat_losses, &ULTRA_CRUCIBLE)?.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod grid {
+        use super::*;
+
+        #[test]
+        fn test_from_str() {
+            let input = "123
+456
+789";
+            assert_eq!(
+                parse_heat_losses::<Vec<u8>>(input),
+                Grid::try_from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])
+            );
+            assert_eq!(
+                Day17::parse(input),
+                parse_heat_losses::<Vec<u8>>(input).and_then(Grid::into_cells)
+            )
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("examples/d17.txt");
+        assert_eq!(part1(input).unwrap(), "102");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("examples/d17_part2.txt");
+        assert_eq!(part2(input).unwrap(), "71");
+    }
+}
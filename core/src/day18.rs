@@ -1,5 +1,5 @@
 use std::cmp::{max, min};
-use std::ops::Add;
+use std::ops::Range;
 
 use derive_more::{Deref, DerefMut, From};
 use itertools::Itertools;
@@ -13,15 +13,13 @@ use nom::sequence::{delimited, preceded, tuple};
 use nom::IResult;
 use num::abs;
 
-use Direction::*;
-
-#[derive(Debug, Copy, Clone, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::grid::Direction::{self, *};
+use crate::grid::{self, Pos};
+use crate::parse::finish;
+use crate::solution;
+use crate::solver::Day;
 
 fn parse_direction(input: &str) -> IResult<&str, Direction> {
     alt((
@@ -73,7 +71,7 @@ fn parse_alt_instruction(input: &str) -> IResult<&str, AltInstruction> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-struct Instruction {
+pub struct Instruction {
     direction: Direction,
     distance: u8,
     alt: AltInstruction,
@@ -120,18 +118,13 @@ impl Bounds {
 }
 
 #[derive(Debug, Clone, PartialEq, From, Deref)]
-struct Instructions(Vec<Instruction>);
+pub struct Instructions(Vec<Instruction>);
 
 impl Instructions {
     fn get_width_bounds(&self) -> Bounds {
         let mut width = 0_isize;
         self.iter()
-            .filter_map(|instruction| match instruction.direction {
-                Up => None,
-                Down => None,
-                Left => Some(0 - (instruction.distance as isize)),
-                Right => Some(instruction.distance as isize),
-            })
+            .map(|instruction| instruction.direction.delta().1 * instruction.distance as isize)
             .fold(Bounds::default(), |bounds: Bounds, num| {
                 width += num;
                 bounds.apply(width)
@@ -141,12 +134,7 @@ impl Instructions {
     fn get_height_bounds(&self) -> Bounds {
         let mut height = 0_isize;
         self.iter()
-            .filter_map(|instruction| match instruction.direction {
-                Up => Some(0 - (instruction.distance as isize)),
-                Down => Some(instruction.distance as isize),
-                Left => None,
-                Right => None,
-            })
+            .map(|instruction| instruction.direction.delta().0 * instruction.distance as isize)
             .fold(Bounds::default(), |bounds: Bounds, num| {
                 height += num;
                 bounds.apply(height)
@@ -156,11 +144,8 @@ impl Instructions {
     fn get_width_bounds_alt(&self) -> Bounds {
         let mut width = 0_isize;
         self.iter()
-            .filter_map(|instruction| match instruction.alt.direction {
-                Up => None,
-                Down => None,
-                Left => Some(0 - (instruction.alt.distance as isize)),
-                Right => Some(instruction.alt.distance as isize),
+            .map(|instruction| {
+                instruction.alt.direction.delta().1 * instruction.alt.distance as isize
             })
             .fold(Bounds::default(), |bounds: Bounds, num| {
                 width += num;
@@ -171,11 +156,8 @@ impl Instructions {
     fn get_height_bounds_alt(&self) -> Bounds {
         let mut height = 0_isize;
         self.iter()
-            .filter_map(|instruction| match instruction.alt.direction {
-                Up => Some(0 - (instruction.alt.distance as isize)),
-                Down => Some(instruction.alt.distance as isize),
-                Left => None,
-                Right => None,
+            .map(|instruction| {
+                instruction.alt.direction.delta().0 * instruction.alt.distance as isize
             })
             .fold(Bounds::default(), |bounds: Bounds, num| {
                 height += num;
@@ -200,7 +182,7 @@ struct Tile {
 struct Grid {
     #[deref]
     #[deref_mut]
-    grid: Vec<Vec<Tile>>,
+    tiles: grid::Grid<Tile>,
     initial_start: Pos,
 }
 
@@ -222,28 +204,17 @@ impl Grid {
     fn with_bounds(height: Bounds, width: Bounds) -> Self {
         let initial_start = Pos {
             row: abs(height.min) as usize,
-            col: abs(width.min) as usize,
+            column: abs(width.min) as usize,
         };
 
-        let row = vec![Tile::default(); width.len() + 1];
-        let grid = vec![row.clone(); height.len() + 1];
-
         Grid {
-            grid,
+            tiles: grid::Grid::filled(width.len() + 1, height.len() + 1, Tile::default()),
             initial_start,
         }
     }
 
-    fn height(&self) -> usize {
-        self.len()
-    }
-
-    fn width(&self) -> usize {
-        self[0].len()
-    }
-
     fn dig_at(&mut self, pos: Pos) {
-        self[pos.row][pos.col].is_dug = true;
+        self[pos].is_dug = true;
     }
 
     fn dig_trench(&mut self, instructions: &[Instruction]) {
@@ -251,7 +222,7 @@ impl Grid {
         self.dig_at(pos);
         instructions.iter().for_each(|instruction| {
             for _ in 0..instruction.distance {
-                pos = pos + instruction.direction;
+                pos = self.next_pos(pos, instruction.direction);
                 self.dig_at(pos)
             }
         })
@@ -262,17 +233,31 @@ impl Grid {
         self.dig_at(pos);
         instructions.iter().for_each(|instruction| {
             for _ in 0..instruction.alt.distance {
-                pos = pos + instruction.alt.direction;
+                pos = self.next_pos(pos, instruction.alt.direction);
                 self.dig_at(pos)
             }
         })
     }
 
+    fn next_pos(&self, pos: Pos, direction: Direction) -> Pos {
+        self.step(pos, direction)
+            .expect("the grid is sized to fit the whole trench")
+    }
+
     fn point_is_definitely_inside_trench(&self, pos: Pos) -> bool {
-        let up: Vec<_> = self[..pos.row].iter().map(|row| &row[pos.col]).collect();
-        let down: Vec<_> = self[pos.row..].iter().map(|row| &row[pos.col]).collect();
-        let left: Vec<_> = self[pos.row][..pos.col].iter().collect();
-        let right: Vec<_> = self[pos.row][pos.col..].iter().collect();
+        let column = |rows: Range<usize>| {
+            rows.map(|row| &self[Pos::new(row, pos.column)])
+                .collect_vec()
+        };
+        let row = |columns: Range<usize>| {
+            columns
+                .map(|column| &self[Pos::new(pos.row, column)])
+                .collect_vec()
+        };
+        let up = column(0..pos.row);
+        let down = column(pos.row..self.height());
+        let left = row(0..pos.column);
+        let right = row(pos.column..self.width());
 
         for ray in [up, down, left, right] {
             let groups = ray
@@ -290,59 +275,33 @@ impl Grid {
         false
     }
 
-    fn get_tile(&mut self, pos: Pos) -> &mut Tile {
-        &mut self[pos.row][pos.col]
-    }
-
     fn flood_fill(&mut self, pos: Pos) {
-        if self.get_tile(pos).is_dug {
+        if self[pos].is_dug {
             return;
         }
-        let width = self.width();
-        let height = self.height();
+        self[pos].is_dug = true;
 
-        self.get_tile(pos).is_dug = true;
         // straight
-        pos.up().into_iter().for_each(|up| self.flood_fill(up));
-        pos.down(height)
-            .into_iter()
-            .for_each(|down| self.flood_fill(down));
-        pos.left()
-            .into_iter()
-            .for_each(|left| self.flood_fill(left));
-        pos.right(width)
-            .into_iter()
-            .for_each(|right| self.flood_fill(right));
+        for next in self.neighbours(pos).collect_vec() {
+            self.flood_fill(next);
+        }
 
         // diagonal, just in case
-        pos.up()
-            .into_iter()
-            .filter_map(|up| up.left())
-            .for_each(|up| self.flood_fill(up));
-        pos.up()
-            .into_iter()
-            .filter_map(|up| up.right(width))
-            .for_each(|up| self.flood_fill(up));
-        pos.down(height)
-            .into_iter()
-            .filter_map(|down| down.left())
-            .for_each(|down| self.flood_fill(down));
-        pos.down(height)
-            .into_iter()
-            .filter_map(|down| down.right(width))
-            .for_each(|down| self.flood_fill(down));
+        for vertical in [Up, Down] {
+            for horizontal in [Left, Right] {
+                let diagonal = self
+                    .step(pos, vertical)
+                    .and_then(|next| self.step(next, horizontal));
+                if let Some(diagonal) = diagonal {
+                    self.flood_fill(diagonal);
+                }
+            }
+        }
     }
 
     fn fill_trench(&mut self) {
         let to_dig = self
             .iter()
-            .enumerate()
-            .flat_map(|(row, tiles)| {
-                tiles
-                    .iter()
-                    .enumerate()
-                    .map(move |(col, tile)| (Pos { row, col }, tile))
-            })
             .filter_map(|(pos, tile)| (!tile.is_dug).then_some(pos))
             .filter(|pos| self.point_is_definitely_inside_trench(*pos))
             .collect_vec();
@@ -352,88 +311,51 @@ impl Grid {
     }
 
     fn count_holes(&self) -> usize {
-        self.iter()
-            .flat_map(|row| row.iter().map(|tile| tile.is_dug))
-            .filter(|is_dug| *is_dug)
-            .count()
+        self.values().filter(|tile| tile.is_dug).count()
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-struct Pos {
-    row: usize,
-    col: usize,
-}
+pub struct Day18;
 
-impl Pos {
-    fn up(&self) -> Option<Pos> {
-        (self.row > 0).then_some(Pos {
-            row: self.row.saturating_sub(1),
-            col: self.col,
-        })
-    }
+impl Day for Day18 {
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
 
-    fn down(&self, max: usize) -> Option<Pos> {
-        (self.row + 1 < max).then_some(Pos {
-            row: self.row + 1,
-            col: self.col,
-        })
+    type Parsed<'a> = Instructions;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_instructions, input)
     }
 
-    fn left(&self) -> Option<Pos> {
-        (self.col > 0).then_some(Pos {
-            row: self.row,
-            col: self.col.saturating_sub(1),
-        })
+    fn part1(instructions: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut grid = Grid::from(instructions);
+        grid.dig_trench(instructions);
+        grid.fill_trench();
+        Ok(grid.count_holes().into())
     }
 
-    fn right(&self, max: usize) -> Option<Pos> {
-        (self.col + 1 < max).then_some(Pos {
-            row: self.row,
-            col: self.col + 1,
-        })
+    fn part2(instructions: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut grid = Grid::from_alt(instructions);
+        grid.dig_trench_alt(instructions);
+        grid.fill_trench();
+        Ok(grid.count_holes().into())
     }
 }
 
-impl Add<Direction> for Pos {
-    type Output = Pos;
-
-    fn add(self, direction: Direction) -> Self::Output {
-        match direction {
-            Up => Pos {
-                row: self.row - 1,
-                col: self.col,
-            },
-            Down => Pos {
-                row: self.row + 1,
-                col: self.col,
-            },
-            Left => Pos {
-                row: self.row,
-                col: self.col - 1,
-            },
-            Right => Pos {
-                row: self.row,
-                col: self.col + 1,
-            },
-        }
+solution! {
+    Day18 {
+        "d18.txt" => part1 "62", part2 "952408144115";
     }
+    part1(1, Solved),
+    part2(2, Incomplete),
 }
 
-pub fn part1(input: &str) -> String {
-    let instructions = parse_instructions(input).unwrap().1;
-    let mut grid = Grid::from(&instructions);
-    grid.dig_trench(&instructions);
-    grid.fill_trench();
-    grid.count_holes().to_string()
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day18::part1(&Day18::parse(input)?)
 }
 
-pub fn part2(input: &str) -> String {
-    let instructions = parse_instructions(input).unwrap().1;
-    let mut grid = Grid::from_alt(&instructions);
-    grid.dig_trench_alt(&instructions);
-    grid.fill_trench();
-    grid.count_holes().to_string()
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day18::part2(&Day18::parse(input)?)
 }
 
 #[cfg(test)]
@@ -573,43 +495,9 @@ U 2 (#7a21e3)
         }
     }
 
-    #[test]
-    fn test_part1() {
-        let input = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)
-";
-        assert_eq!(part1(input), "62");
-    }
-
     #[test]
     fn test_part2() {
-        let input = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)
-";
-        assert_eq!(part2(input), "952408144115");
+        let input = include_str!("examples/d18.txt");
+        assert_eq!(part2(input).unwrap(), "952408144115");
     }
 }
@@ -0,0 +1,697 @@
+use std::collections::BTreeMap;
+
+use derive_more::{Deref, DerefMut};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{alpha1, newline};
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::intern::{Id, Interner};
+use crate::interval::Interval;
+use crate::parse::{blank_line, finish};
+use crate::solution;
+use crate::solver::Day;
+use Category::*;
+use MetaOutcome::*;
+use Outcome::*;
+use RuleType::*;
+
+/// What happens to a part, the workflow it continues to named by `L`, a label when parsed and an
+/// [Id] once the workflows are put together
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Outcome<L> {
+    Accepted,
+    Rejected,
+    ContinueTo(L),
+}
+
+impl<L> Outcome<L> {
+    fn try_map<M>(
+        self,
+        f: &mut impl FnMut(L) -> Result<M, AocError>,
+    ) -> Result<Outcome<M>, AocError> {
+        Ok(match self {
+            Accepted => Accepted,
+            Rejected => Rejected,
+            ContinueTo(label) => ContinueTo(f(label)?),
+        })
+    }
+}
+
+fn parse_outcome(input: &str) -> IResult<&str, Outcome<&str>> {
+    alt((
+        value(Accepted, complete::char('A')),
+        value(Rejected, complete::char('R')),
+        map(alpha1, ContinueTo),
+    ))(input)
+}
+
+enum MetaOutcome {
+    MetaAccepted {
+        accepted_part: MetaPart,
+        remainder: Option<MetaPart>,
+    },
+    MetaRejected {
+        rejected_part: MetaPart,
+        remainder: Option<MetaPart>,
+    },
+    MetaContinueTo {
+        continue_to: Id,
+        continue_part: MetaPart,
+        remainder: Option<MetaPart>,
+    },
+    NoOutcome {
+        remainder: MetaPart,
+    },
+}
+
+/// What a part is rated on, written `x`, `m`, `a` and `s`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    Cool,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+fn parse_category(input: &str) -> IResult<&str, Category> {
+    alt((
+        value(Cool, complete::char('x')),
+        value(Musical, complete::char('m')),
+        value(Aerodynamic, complete::char('a')),
+        value(Shiny, complete::char('s')),
+    ))(input)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RuleType {
+    GreaterThan,
+    LessThan,
+}
+
+fn parse_rule_type(input: &str) -> IResult<&str, RuleType> {
+    alt((
+        value(GreaterThan, complete::char('>')),
+        value(LessThan, complete::char('<')),
+    ))(input)
+}
+
+/// A part whose rating in `category` is greater or less than `value` goes to `outcome`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule<L> {
+    category: Category,
+    rule_type: RuleType,
+    value: u64,
+    outcome: Outcome<L>,
+}
+
+impl<L> Rule<L> {
+    /// eg `a<2006:qkq` is `Rule::new(Aerodynamic, LessThan, 2006, ContinueTo("qkq"))`
+    pub fn new(category: Category, rule_type: RuleType, value: u64, outcome: Outcome<L>) -> Self {
+        Self {
+            category,
+            rule_type,
+            value,
+            outcome,
+        }
+    }
+}
+
+impl<L: Copy> Rule<L> {
+    /// Where `part` goes, if it matches
+    pub fn process_part(&self, part: Part) -> Option<Outcome<L>> {
+        let value = part.value_for_category(self.category);
+        match self.rule_type {
+            GreaterThan => (value > self.value).then_some(self.outcome),
+            LessThan => (value < self.value).then_some(self.outcome),
+        }
+    }
+}
+
+fn parse_rule(input: &str) -> IResult<&str, Rule<&str>> {
+    map(
+        tuple((
+            parse_category,
+            parse_rule_type,
+            complete::u64,
+            complete::char(':'),
+            parse_outcome,
+        )),
+        |(category, rule_type, value, _, outcome)| Rule::new(category, rule_type, value, outcome),
+    )(input)
+}
+
+/// A step of a workflow, either a rule to check or where everything that gets this far goes
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleOrOutcome<L> {
+    Rule(Rule<L>),
+    Outcome(Outcome<L>),
+}
+
+impl<L> RuleOrOutcome<L> {
+    fn try_map<M>(
+        self,
+        f: &mut impl FnMut(L) -> Result<M, AocError>,
+    ) -> Result<RuleOrOutcome<M>, AocError> {
+        Ok(match self {
+            RuleOrOutcome::Rule(rule) => RuleOrOutcome::Rule(Rule::new(
+                rule.category,
+                rule.rule_type,
+                rule.value,
+                rule.outcome.try_map(f)?,
+            )),
+            RuleOrOutcome::Outcome(outcome) => RuleOrOutcome::Outcome(outcome.try_map(f)?),
+        })
+    }
+}
+
+fn parse_rule_or_outcome(input: &str) -> IResult<&str, RuleOrOutcome<&str>> {
+    alt((
+        map(parse_rule, RuleOrOutcome::Rule),
+        map(parse_outcome, RuleOrOutcome::Outcome),
+    ))(input)
+}
+
+/// A named list of steps, the first that matches a part saying where it goes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Workflow<L> {
+    label: L,
+    rules: Vec<RuleOrOutcome<L>>,
+}
+
+impl<L> Workflow<L> {
+    pub fn new(label: L, rules: Vec<RuleOrOutcome<L>>) -> Self {
+        Self { label, rules }
+    }
+}
+
+impl<L: Copy> Workflow<L> {
+    /// Where `part` goes, or `None` if no step matches it
+    pub fn process_part(&self, part: Part) -> Option<Outcome<L>> {
+        self.rules
+            .iter()
+            .find_map(|rule_or_outcome| match rule_or_outcome {
+                RuleOrOutcome::Rule(rule) => rule.process_part(part),
+                RuleOrOutcome::Outcome(outcome) => Some(*outcome),
+            })
+    }
+}
+
+impl Workflow<Id> {
+    fn process_meta_part(&self, part: MetaPart) -> Vec<MetaWorkflowInstruction> {
+        let mut next_to_process = Some(part);
+        let mut processed = vec![];
+
+        for rule_or_outcome in &self.rules {
+            if let Some(next) = next_to_process.take() {
+                match rule_or_outcome {
+                    RuleOrOutcome::Rule(rule) => match next.apply_rule(rule) {
+                        MetaAccepted {
+                            accepted_part,
+                            remainder,
+                        } => {
+                            processed.push(MetaWorkflowInstruction {
+                                part: accepted_part,
+                                outcome: Accepted,
+                            });
+                            next_to_process = remainder
+                        }
+                        MetaRejected {
+                            rejected_part,
+                            remainder,
+                        } => {
+                            processed.push(MetaWorkflowInstruction {
+                                part: rejected_part,
+                                outcome: Rejected,
+                            });
+                            next_to_process = remainder
+                        }
+                        MetaContinueTo {
+                            continue_to,
+                            continue_part,
+                            remainder,
+                        } => {
+                            processed.push(MetaWorkflowInstruction {
+                                part: continue_part,
+                                outcome: ContinueTo(continue_to),
+                            });
+                            next_to_process = remainder
+                        }
+                        MetaOutcome::NoOutcome { remainder } => next_to_process = Some(remainder),
+                    },
+                    RuleOrOutcome::Outcome(outcome) => processed.push(MetaWorkflowInstruction {
+                        part: next.clone(),
+                        outcome: *outcome,
+                    }),
+                }
+            }
+        }
+
+        processed
+    }
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, Workflow<&str>> {
+    map(
+        tuple((
+            alpha1,
+            delimited(
+                complete::char('{'),
+                separated_list1(complete::char(','), parse_rule_or_outcome),
+                complete::char('}'),
+            ),
+        )),
+        |(label, rules)| Workflow::new(label, rules),
+    )(input)
+}
+
+/// The workflows, each found by its [Id]
+pub struct Workflows<'a> {
+    labels: Interner<'a>,
+    workflows: Vec<Workflow<Id>>,
+}
+
+impl<'a> Workflows<'a> {
+    /// Workflows that go to each other by label. It's an error for one to continue to a workflow
+    /// that isn't there
+    pub fn new(workflows: Vec<Workflow<&'a str>>) -> Result<Self, AocError> {
+        // Workflows are interned first so workflow `n` has the id `n`, anything continued to
+        // after that isn't a workflow
+        let mut labels = Interner::new();
+        for workflow in &workflows {
+            labels.intern(workflow.label);
+        }
+        let count = workflows.len();
+        let mut continue_to = |label| {
+            let id = labels.intern(label);
+            (id.index() < count)
+                .then_some(id)
+                .ok_or_else(|| AocError::parse(format!("there's no workflow called {label}")))
+        };
+        let workflows = workflows
+            .into_iter()
+            .map(|workflow| {
+                Ok(Workflow::new(
+                    continue_to(workflow.label)?,
+                    workflow
+                        .rules
+                        .into_iter()
+                        .map(|rule| rule.try_map(&mut continue_to))
+                        .collect::<Result<_, AocError>>()?,
+                ))
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(Self { labels, workflows })
+    }
+
+    /// The workflow every part starts at
+    pub fn start(&self) -> Result<Id, AocError> {
+        self.labels
+            .get("in")
+            .ok_or_else(|| AocError::parse("there's no workflow called in"))
+    }
+
+    pub fn label(&self, id: Id) -> &'a str {
+        self.labels.label(id)
+    }
+
+    /// Where workflow `id` sends `part`
+    pub fn process_part(&self, part: Part, id: Id) -> Result<Outcome<Id>, AocError> {
+        self.workflows[id.index()]
+            .process_part(part)
+            .ok_or_else(|| {
+                let label = self.labels.label(id);
+                AocError::no_answer(format!("workflow {label} did not match {part:?}"))
+            })
+    }
+
+    /// Whether `part` ends up accepted, going through the workflows from the start
+    pub fn accepts(&self, part: Part) -> Result<bool, AocError> {
+        let mut workflow = self.start()?;
+        loop {
+            match self.process_part(part, workflow)? {
+                Accepted => return Ok(true),
+                Rejected => return Ok(false),
+                ContinueTo(id) => workflow = id,
+            }
+        }
+    }
+
+    fn process_meta_part(&self, part: MetaPart, id: Id) -> Vec<MetaWorkflowInstruction> {
+        self.workflows[id.index()].process_meta_part(part)
+    }
+}
+
+struct MetaWorkflowInstruction {
+    part: MetaPart,
+    outcome: Outcome<Id>,
+}
+
+/// A part's rating in each [Category]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Part {
+    pub x: u64,
+    pub m: u64,
+    pub a: u64,
+    pub s: u64,
+}
+
+impl Part {
+    pub fn new(x: u64, m: u64, a: u64, s: u64) -> Self {
+        Self { x, m, a, s }
+    }
+
+    pub fn value_for_category(&self, category: Category) -> u64 {
+        match category {
+            Cool => self.x,
+            Musical => self.m,
+            Aerodynamic => self.a,
+            Shiny => self.s,
+        }
+    }
+
+    pub fn total_value(&self) -> u64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+/// Every rating a part could have in each category
+const RATINGS: Interval = Interval::inclusive(1, 4000);
+
+/// The ratings in `range` that match the rule and whatever's left over, if any of them match
+fn split_on(
+    range: &Interval,
+    rule_type: RuleType,
+    value: u64,
+) -> Option<(Interval, Option<Interval>)> {
+    match rule_type {
+        LessThan => {
+            let (below, above) = range.split_at(value);
+            Some((below?, above))
+        }
+        GreaterThan => {
+            let (below, above) = range.split_at(value + 1);
+            Some((above?, below))
+        }
+    }
+}
+
+/// The range of ratings in each category, kept in `xmas` order so it always prints the same
+#[derive(Debug, Clone, PartialEq, Deref, DerefMut)]
+struct MetaPart(BTreeMap<Category, Interval>);
+
+impl MetaPart {
+    fn new() -> Self {
+        Self(BTreeMap::from([
+            (Cool, RATINGS),
+            (Musical, RATINGS),
+            (Aerodynamic, RATINGS),
+            (Shiny, RATINGS),
+        ]))
+    }
+
+    fn replace_quantity(mut self, category: &Category, range: Interval) -> Self {
+        *self.get_mut(category).unwrap() = range;
+        self
+    }
+
+    fn apply_rule(&self, rule: &Rule<Id>) -> MetaOutcome {
+        let range = self.get(&rule.category).unwrap();
+
+        if let Some((inclusive, exclusive)) = split_on(range, rule.rule_type, rule.value) {
+            match &rule.outcome {
+                Accepted => MetaAccepted {
+                    accepted_part: self.clone().replace_quantity(&rule.category, inclusive),
+                    remainder: exclusive
+                        .map(|exclusive| self.clone().replace_quantity(&rule.category, exclusive)),
+                },
+                Rejected => MetaRejected {
+                    rejected_part: self.clone().replace_quantity(&rule.category, inclusive),
+                    remainder: exclusive
+                        .map(|exclusive| self.clone().replace_quantity(&rule.category, exclusive)),
+                },
+                ContinueTo(id) => MetaContinueTo {
+                    continue_to: *id,
+                    continue_part: self.clone().replace_quantity(&rule.category, inclusive),
+                    remainder: exclusive
+                        .map(|exclusive| self.clone().replace_quantity(&rule.category, exclusive)),
+                },
+            }
+        } else {
+            NoOutcome {
+                remainder: self.clone(),
+            }
+        }
+    }
+
+    fn possible_ranges(&self) -> u64 {
+        self.get(&Cool).unwrap().len()
+            * self.get(&Musical).unwrap().len()
+            * self.get(&Aerodynamic).unwrap().len()
+            * self.get(&Shiny).unwrap().len()
+    }
+}
+
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    map(
+        delimited(
+            complete::char('{'),
+            tuple((
+                delimited(tag("x="), complete::u64, tag(",")),
+                delimited(tag("m="), complete::u64, tag(",")),
+                delimited(tag("a="), complete::u64, tag(",")),
+                preceded(tag("s="), complete::u64),
+            )),
+            complete::char('}'),
+        ),
+        |(x, m, a, s)| Part::new(x, m, a, s),
+    )(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<Workflow<&str>>, Vec<Part>)> {
+    separated_pair(
+        separated_list1(newline, parse_workflow),
+        blank_line,
+        separated_list1(newline, parse_part),
+    )(input)
+}
+
+pub struct Day19;
+
+/// The workflows and the parts waiting to be sorted by them
+pub struct System<'a> {
+    workflows: Workflows<'a>,
+    parts: Vec<Part>,
+}
+
+impl<'a> System<'a> {
+    pub fn new(workflows: Workflows<'a>, parts: Vec<Part>) -> Self {
+        Self { workflows, parts }
+    }
+
+    pub fn workflows(&self) -> &Workflows<'a> {
+        &self.workflows
+    }
+
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+}
+
+impl Day for Day19 {
+    const DAY: usize = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    type Parsed<'a> = System<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        let (workflows, parts) = finish(parse_input, input)?;
+        Ok(System::new(Workflows::new(workflows)?, parts))
+    }
+
+    fn part1(System { workflows, parts }: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut total = 0;
+        for part in parts.iter().copied() {
+            if workflows.accepts(part)? {
+                total += part.total_value();
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(System { workflows, .. }: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        // Could make a parser for workflows but meh
+        let mut queue = vec![MetaWorkflowInstruction {
+            part: MetaPart::new(),
+            outcome: ContinueTo(workflows.start()?),
+        }];
+        let mut accepted: Vec<MetaPart> = vec![];
+
+        while let Some(instruction) = queue.pop() {
+            match instruction.outcome {
+                Accepted => accepted.push(instruction.part),
+                Rejected => {}
+                ContinueTo(id) => queue.extend(workflows.process_meta_part(instruction.part, id)),
+            }
+        }
+
+        Ok(accepted
+            .into_iter()
+            .map(|part| part.possible_ranges())
+            .sum::<u64>()
+            .into())
+    }
+}
+
+solution! {
+    Day19 {
+        "d19.txt" => part1 "19114", part2 "167409079868000";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day19::part1(&Day19::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day19::part2(&Day19::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod workflow {
+        use super::*;
+
+        #[test]
+        fn test_parse_workflow() {
+            let input = "ex{x>10:one,m<20:two,a>30:R,A}";
+            let workflow = parse_workflow(input).unwrap().1;
+            assert_eq!(
+                workflow,
+                Workflow {
+                    label: "ex",
+                    rules: vec![
+                        RuleOrOutcome::Rule(Rule {
+                            category: Cool,
+                            rule_type: GreaterThan,
+                            value: 10,
+                            outcome: ContinueTo("one"),
+                        }),
+                        RuleOrOutcome::Rule(Rule {
+                            category: Musical,
+                            rule_type: LessThan,
+                            value: 20,
+                            outcome: ContinueTo("two"),
+                        }),
+                        RuleOrOutcome::Rule(Rule {
+                            category: Aerodynamic,
+                            rule_type: GreaterThan,
+                            value: 30,
+                            outcome: Rejected,
+                        }),
+                        RuleOrOutcome::Outcome(Accepted),
+                    ],
+                }
+            )
+        }
+
+        #[test]
+        fn test_accepts() {
+            let workflows = Workflows::new(vec![
+                Workflow::new(
+                    "in",
+                    vec![
+                        RuleOrOutcome::Rule(Rule::new(Shiny, LessThan, 1351, ContinueTo("px"))),
+                        RuleOrOutcome::Outcome(Rejected),
+                    ],
+                ),
+                Workflow::new(
+                    "px",
+                    vec![
+                        RuleOrOutcome::Rule(Rule::new(Aerodynamic, GreaterThan, 2000, Accepted)),
+                        RuleOrOutcome::Outcome(Rejected),
+                    ],
+                ),
+            ])
+            .unwrap();
+            assert_eq!(workflows.accepts(Part::new(1, 1, 2001, 1)), Ok(true));
+            assert_eq!(workflows.accepts(Part::new(1, 1, 2000, 1)), Ok(false));
+            assert_eq!(workflows.accepts(Part::new(1, 1, 2001, 1351)), Ok(false));
+            let nowhere = Workflow::new("in", vec![RuleOrOutcome::Outcome(ContinueTo("nowhere"))]);
+            assert!(Workflows::new(vec![nowhere]).is_err());
+        }
+    }
+
+    mod meta_part {
+        use super::*;
+
+        #[test]
+        fn test_total_value() {
+            let part = MetaPart(BTreeMap::from([
+                (Cool, Interval::inclusive(2, 3)),        // 2 + 3 = 2
+                (Musical, Interval::inclusive(4, 6)),     // 4 + 5 + 6 = 3
+                (Aerodynamic, Interval::inclusive(1, 1)), // 1 = 1
+                (Shiny, Interval::inclusive(10, 13)),     // 10 + 11 + 12 + 13 = 4
+            ]));
+            assert_eq!(part.possible_ranges(), [2, 3, 1, 4].iter().product::<u64>())
+        }
+
+        #[test]
+        fn test_debug_order() {
+            let part = MetaPart::new().replace_quantity(&Shiny, Interval::inclusive(1, 10));
+            let categories: Vec<_> = part.keys().copied().collect();
+            assert_eq!(categories, vec![Cool, Musical, Aerodynamic, Shiny]);
+            assert!(format!("{part:?}").ends_with("Shiny: Interval { start: 1, end: 11 }})"));
+        }
+
+        #[test]
+        fn test_split_on() {
+            let range = Interval::inclusive(10, 20);
+            assert_eq!(
+                split_on(&range, LessThan, 15),
+                Some((
+                    Interval::inclusive(10, 14),
+                    Some(Interval::inclusive(15, 20))
+                ))
+            );
+            assert_eq!(
+                split_on(&range, GreaterThan, 15),
+                Some((
+                    Interval::inclusive(16, 20),
+                    Some(Interval::inclusive(10, 15))
+                ))
+            );
+            assert_eq!(split_on(&range, LessThan, 30), Some((range, None)));
+            assert_eq!(split_on(&range, GreaterThan, 20), None);
+        }
+    }
+
+    mod part {
+        use super::*;
+
+        #[test]
+        fn test_parse_part() {
+            let input = "{x=787,m=2655,a=1222,s=2876}";
+            let part = parse_part(input).unwrap().1;
+            assert_eq!(
+                part,
+                Part {
+                    x: 787,
+                    m: 2655,
+                    a: 1222,
+                    s: 2876,
+                }
+            )
+        }
+    }
+}
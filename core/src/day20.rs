@@ -0,0 +1,637 @@
+use std::collections::VecDeque;
+
+use derive_more::From;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, newline};
+use nom::combinator::{into, map};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+use tracing::debug;
+
+use Pulse::*;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::intern::{Id, Interner};
+use crate::maths::lcm_all;
+use crate::parse::finish;
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+impl Pulse {
+    fn flip(&self) -> Self {
+        match self {
+            High => Low,
+            Low => High,
+        }
+    }
+}
+
+/// Labelled by name when parsed, and by [Id] once on the network
+#[derive(Debug, Clone, PartialEq)]
+pub struct Broadcaster<L> {
+    label: L,
+    outputs: Vec<L>,
+}
+
+impl<L> Broadcaster<L> {
+    /// Sends each pulse it gets on to all of `outputs`
+    pub fn new(label: L, outputs: Vec<L>) -> Self {
+        Self { label, outputs }
+    }
+}
+
+impl Broadcaster<Id> {
+    fn process_message(&mut self, message: Message) -> Vec<Message> {
+        assert_eq!(self.label, message.to);
+        self.outputs
+            .iter()
+            .map(|&to| Message {
+                to,
+                from: self.label,
+                pulse: message.pulse,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlipFlop<L> {
+    label: L,
+    is_on: bool,
+    outputs: Vec<L>,
+}
+
+impl<L> FlipFlop<L> {
+    /// Starts off, and turns on or off with each low pulse, telling `outputs` which it's become
+    pub fn new(label: L, outputs: Vec<L>) -> Self {
+        Self {
+            label,
+            is_on: false,
+            outputs,
+        }
+    }
+}
+
+impl FlipFlop<Id> {
+    fn process_message(&mut self, message: Message) -> Vec<Message> {
+        assert_eq!(self.label, message.to);
+
+        if message.pulse == High {
+            return vec![];
+        }
+
+        self.is_on = !self.is_on;
+
+        let pulse = if self.is_on { High } else { Low };
+
+        self.outputs
+            .iter()
+            .map(|&to| Message {
+                to,
+                from: self.label,
+                pulse,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conjunction<L> {
+    label: L,
+    /// The last pulse from each input, there are only ever a few
+    inputs: Vec<(L, Pulse)>,
+    outputs: Vec<L>,
+}
+
+impl<L> Conjunction<L> {
+    /// Sends `outputs` a low pulse once the last pulse from every input was high. Its inputs are
+    /// found when it's put on a network of [Modules]
+    pub fn new(label: L, outputs: Vec<L>) -> Self {
+        Self {
+            label,
+            inputs: vec![],
+            outputs,
+        }
+    }
+}
+
+impl Conjunction<Id> {
+    fn connect_input(&mut self, input: Id) {
+        if !self.inputs.iter().any(|(from, _)| *from == input) {
+            self.inputs.push((input, Low));
+        }
+    }
+
+    fn process_message(&mut self, message: Message) -> Vec<Message> {
+        assert_eq!(self.label, message.to);
+
+        match self
+            .inputs
+            .iter_mut()
+            .find(|(from, _)| *from == message.from)
+        {
+            Some((_, pulse)) => *pulse = message.pulse,
+            None => self.inputs.push((message.from, message.pulse)),
+        }
+
+        let pulse = self
+            .inputs
+            .iter()
+            .map(|(_, pulse)| pulse)
+            .find(|pulse| *pulse == &Low)
+            .unwrap_or(&High)
+            .flip();
+
+        self.outputs
+            .iter()
+            .map(|&to| Message {
+                to,
+                from: self.label,
+                pulse,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, From)]
+pub enum Module<L> {
+    Broadcaster(Broadcaster<L>),
+    FlipFlop(FlipFlop<L>),
+    Conjunction(Conjunction<L>),
+}
+
+impl<L: Copy> Module<L> {
+    #[cfg(test)]
+    fn broadcaster(&mut self) -> Option<&mut Broadcaster<L>> {
+        match self {
+            Module::Broadcaster(module) => Some(module),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    fn flip_flop(&mut self) -> Option<&mut FlipFlop<L>> {
+        match self {
+            Module::FlipFlop(module) => Some(module),
+            _ => None,
+        }
+    }
+
+    fn conjunction(&mut self) -> Option<&mut Conjunction<L>> {
+        match self {
+            Module::Conjunction(module) => Some(module),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> L {
+        match self {
+            Module::Broadcaster(broadcaster) => broadcaster.label,
+            Module::FlipFlop(flip_flop) => flip_flop.label,
+            Module::Conjunction(conjunction) => conjunction.label,
+        }
+    }
+
+    /// Who it sends pulses to
+    pub fn outputs(&self) -> &[L] {
+        match self {
+            Module::Broadcaster(b) => &b.outputs,
+            Module::FlipFlop(f) => &f.outputs,
+            Module::Conjunction(c) => &c.outputs,
+        }
+    }
+
+    fn get_connections(&self) -> impl Iterator<Item = (L, L)> + '_ {
+        self.outputs().iter().map(|&output| (self.label(), output))
+    }
+}
+
+impl<'a> Module<&'a str> {
+    fn intern(self, labels: &mut Interner<'a>) -> Module<Id> {
+        let mut intern_all =
+            |outputs: Vec<&'a str>| outputs.into_iter().map(|o| labels.intern(o)).collect();
+        match self {
+            Module::Broadcaster(b) => Module::Broadcaster(Broadcaster {
+                outputs: intern_all(b.outputs),
+                label: labels.intern(b.label),
+            }),
+            Module::FlipFlop(f) => Module::FlipFlop(FlipFlop {
+                outputs: intern_all(f.outputs),
+                label: labels.intern(f.label),
+                is_on: f.is_on,
+            }),
+            Module::Conjunction(c) => Module::Conjunction(Conjunction {
+                outputs: intern_all(c.outputs),
+                label: labels.intern(c.label),
+                inputs: vec![],
+            }),
+        }
+    }
+}
+
+impl Module<Id> {
+    fn process_message(&mut self, message: Message) -> Vec<Message> {
+        match self {
+            Module::Broadcaster(b) => b.process_message(message),
+            Module::FlipFlop(f) => f.process_message(message),
+            Module::Conjunction(c) => c.process_message(message),
+        }
+    }
+}
+
+/// The network of modules, module `n` having the [Id] `n`. Labels that aren't modules, like the
+/// button or an output, have ids after those
+#[derive(Debug, Clone, PartialEq)]
+pub struct Modules<'a> {
+    labels: Interner<'a>,
+    modules: Vec<Module<Id>>,
+    /// An edge from each module to each of its outputs, with nodes numbered by [Id]
+    wiring: Graph,
+    button: Id,
+    broadcaster: Id,
+    rx: Id,
+}
+
+impl<'a> Modules<'a> {
+    /// Modules sending pulses to each other by label. The button sends its pulse to the module
+    /// labelled `broadcaster`, and anything sent to a label that isn't a module goes nowhere
+    pub fn new(modules: Vec<Module<&'a str>>) -> Self {
+        let mut labels = Interner::new();
+        for module in &modules {
+            labels.intern(module.label());
+        }
+        let mut modules: Vec<_> = modules
+            .into_iter()
+            .map(|module| module.intern(&mut labels))
+            .collect();
+        let button = labels.intern("button");
+        let broadcaster = labels.intern("broadcaster");
+        let rx = labels.intern("rx");
+        let mut wiring = Graph::new(labels.len());
+        for (from, to) in modules.iter().flat_map(|module| module.get_connections()) {
+            wiring.add_edge(from.index(), to.index());
+        }
+        connect_conjunctions(&mut modules, &wiring.reversed());
+        Self {
+            labels,
+            modules,
+            wiring,
+            button,
+            broadcaster,
+            rx,
+        }
+    }
+
+    /// The modules in the order they were given, with module `n` having the [Id] `n`
+    pub fn modules(&self) -> &[Module<Id>] {
+        &self.modules
+    }
+
+    pub fn id(&self, label: &str) -> Option<Id> {
+        self.labels.get(label)
+    }
+
+    pub fn label(&self, id: Id) -> &'a str {
+        self.labels.label(id)
+    }
+
+    /// An edge from each module to each of its outputs, with nodes numbered by [Id]
+    pub fn wiring(&self) -> &Graph {
+        &self.wiring
+    }
+}
+
+/// Conjunctions remember a pulse from each of their inputs, which are the modules with edges to
+/// them in `wiring`'s reverse
+fn connect_conjunctions(modules: &mut [Module<Id>], inputs: &Graph) {
+    modules
+        .iter_mut()
+        .filter_map(|module| module.conjunction())
+        .for_each(|conjunction| {
+            for from in inputs.neighbours(conjunction.label.index()) {
+                conjunction.connect_input(Id::from_index(from));
+            }
+        })
+}
+
+struct Communications<'a> {
+    network: &'a Modules<'a>,
+    modules: Vec<Module<Id>>,
+    message_queue: VecDeque<Message>,
+    low_counter: usize,
+    high_counter: usize,
+}
+
+impl<'a> Communications<'a> {
+    fn new(network: &'a Modules<'a>) -> Self {
+        Self {
+            network,
+            modules: network.modules.clone(),
+            message_queue: VecDeque::new(),
+            low_counter: 0,
+            high_counter: 0,
+        }
+    }
+
+    fn process_message(&mut self, message: Message) -> Vec<Message> {
+        match self.modules.get_mut(message.to.index()) {
+            Some(module) => module.process_message(message),
+            None => {
+                debug!(
+                    "unable to find module {}",
+                    self.network.labels.label(message.to)
+                );
+                vec![]
+            }
+        }
+    }
+
+    fn push_button(&mut self) {
+        self.push_button_watching(|_| {})
+    }
+
+    /// Push the button, showing `watch` every message sent before it's delivered
+    fn push_button_watching(&mut self, mut watch: impl FnMut(&Message)) {
+        self.message_queue.push_back(Message {
+            to: self.network.broadcaster,
+            from: self.network.button,
+            pulse: Low,
+        });
+
+        while let Some(message) = self.message_queue.pop_front() {
+            match message.pulse {
+                High => self.high_counter += 1,
+                Low => self.low_counter += 1,
+            }
+
+            watch(&message);
+            let messages = self.process_message(message);
+            self.message_queue.extend(messages);
+        }
+    }
+
+    fn value(&self) -> usize {
+        self.high_counter * self.low_counter
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Message {
+    to: Id,
+    from: Id,
+    pulse: Pulse,
+}
+
+fn parse_broadcaster(input: &str) -> IResult<&str, Broadcaster<&str>> {
+    map(
+        separated_pair(
+            tag("broadcaster"),
+            tag(" -> "),
+            separated_list1(tag(", "), alpha1),
+        ),
+        |(label, outputs)| Broadcaster::new(label, outputs),
+    )(input)
+}
+
+fn parse_flip_flop(input: &str) -> IResult<&str, FlipFlop<&str>> {
+    map(
+        separated_pair(
+            preceded(tag("%"), alpha1),
+            tag(" -> "),
+            separated_list1(tag(", "), alpha1),
+        ),
+        |(label, outputs)| FlipFlop::new(label, outputs),
+    )(input)
+}
+
+fn parse_conjunction(input: &str) -> IResult<&str, Conjunction<&str>> {
+    map(
+        separated_pair(
+            preceded(tag("&"), alpha1),
+            tag(" -> "),
+            separated_list1(tag(", "), alpha1),
+        ),
+        |(label, outputs)| Conjunction::new(label, outputs),
+    )(input)
+}
+
+fn parse_module(input: &str) -> IResult<&str, Module<&str>> {
+    alt((
+        into(parse_broadcaster),
+        into(parse_flip_flop),
+        into(parse_conjunction),
+    ))(input)
+}
+
+fn parse_modules(input: &str) -> IResult<&str, Vec<Module<&str>>> {
+    separated_list1(newline, parse_module)(input)
+}
+
+pub struct Day20;
+
+impl Day for Day20 {
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+
+    type Parsed<'a> = Modules<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok(Modules::new(finish(parse_modules, input)?))
+    }
+
+    fn part1(modules: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut communications = Communications::new(modules);
+        for _ in 0..1000 {
+            communications.push_button();
+        }
+        Ok(communications.value().into())
+    }
+
+    /// rx is fed by a single conjunction, which sends it a low pulse once all of its inputs have
+    /// sent it a high one in the same push. Each input does that on its own cycle, so rx gets a low
+    /// pulse when all the cycles line up
+    fn part2(modules: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let inputs = modules.wiring.reversed();
+        let feeder = inputs
+            .neighbours(modules.rx.index())
+            .exactly_one()
+            .map_err(|_| AocError::no_answer("rx isn't fed by a single module"))?;
+        let cycles: Vec<_> = inputs.neighbours(feeder).collect();
+        let mut first_high = vec![None; cycles.len()];
+        let mut communications = Communications::new(modules);
+        for presses in 1usize.. {
+            communications.push_button_watching(|message| {
+                if message.to.index() == feeder && message.pulse == High {
+                    if let Some(cycle) = cycles.iter().position(|c| *c == message.from.index()) {
+                        first_high[cycle].get_or_insert(presses);
+                    }
+                }
+            });
+            if first_high.iter().all(Option::is_some) {
+                break;
+            }
+        }
+        let presses = lcm_all(first_high.iter().flatten().copied())
+            .ok_or_else(|| AocError::no_answer("nothing feeds the module that feeds rx"))?;
+        // How often each input to rx's feeder sends it a high pulse
+        let answer = cycles.iter().zip(first_high.into_iter().flatten()).fold(
+            Answer::from(presses),
+            |answer, (&input, cycle)| {
+                let label = modules.labels.label(Id::from_index(input));
+                answer.with_stat(label, cycle)
+            },
+        );
+        Ok(answer)
+    }
+}
+
+solution! {
+    Day20 {
+        "d20_part1.txt" => part1 "32000000";
+        "d20_part1_2.txt" => part1 "11687500";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day20::part1(&Day20::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day20::part2(&Day20::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parsers {
+        use super::*;
+
+        #[test]
+        fn test_parse_broadcaster() {
+            let input = "broadcaster -> a, b, c\n";
+            let result = parse_broadcaster(input);
+            assert_eq!(
+                result,
+                Ok((
+                    "\n",
+                    Broadcaster {
+                        label: "broadcaster",
+                        outputs: vec!["a", "b", "c"],
+                    }
+                ))
+            )
+        }
+
+        #[test]
+        fn test_parse_flip_flop() {
+            let input = "%a -> b\n";
+            let result = parse_flip_flop(input);
+            assert_eq!(
+                result,
+                Ok((
+                    "\n",
+                    FlipFlop {
+                        label: "a",
+                        is_on: false,
+                        outputs: vec!["b"],
+                    }
+                ))
+            )
+        }
+
+        #[test]
+        fn test_parse_conjunction() {
+            let input = "&inv -> a\n";
+            let result = parse_conjunction(input);
+            assert_eq!(
+                result,
+                Ok((
+                    "\n",
+                    Conjunction {
+                        label: "inv",
+                        inputs: vec![],
+                        outputs: vec!["a"],
+                    }
+                ))
+            )
+        }
+
+        #[test]
+        fn test_parse_modules() {
+            let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+            let mut mods = parse_modules(input).unwrap().1;
+            assert_eq!(mods.len(), 5);
+            assert_eq!(mods.iter_mut().filter_map(|m| m.broadcaster()).count(), 1);
+            assert_eq!(mods.iter_mut().filter_map(|m| m.flip_flop()).count(), 3);
+            assert_eq!(mods.iter_mut().filter_map(|m| m.conjunction()).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_modules_new() {
+        let modules = Modules::new(vec![
+            Broadcaster::new("broadcaster", vec!["a"]).into(),
+            FlipFlop::new("a", vec!["inv", "con"]).into(),
+            Conjunction::new("inv", vec!["b"]).into(),
+            FlipFlop::new("b", vec!["con"]).into(),
+            Conjunction::new("con", vec!["output"]).into(),
+        ]);
+        assert_eq!(
+            Ok(&modules),
+            Day20::parse(include_str!("examples/d20_part1_2.txt")).as_ref()
+        );
+        let con = modules.id("con").unwrap();
+        assert_eq!(modules.label(con), "con");
+        assert_eq!(
+            modules.wiring().reversed().neighbours(con.index()).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_conjunction_inputs() {
+        let modules = Day20::parse(include_str!("examples/d20_part1_2.txt")).unwrap();
+        let con = modules.id("con").unwrap();
+        let Module::Conjunction(conjunction) = &modules.modules()[con.index()] else {
+            panic!("con should be a conjunction");
+        };
+        // Kept in the order the inputs are wired up, so it's the same from run to run
+        let inputs: Vec<_> = conjunction
+            .inputs
+            .iter()
+            .map(|(input, pulse)| (modules.label(*input), *pulse))
+            .collect();
+        assert_eq!(inputs, vec![("a", Low), ("b", Low)]);
+    }
+
+    #[test]
+    fn test_part2() {
+        // p sends hf a high pulse every 2nd push and q every 4th
+        let input = "broadcaster -> a, b
+%a -> p
+&p -> hf
+%b -> c
+%c -> q
+&q -> hf
+&hf -> rx";
+        assert_eq!(part2(input).unwrap(), "4");
+    }
+}
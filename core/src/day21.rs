@@ -0,0 +1,288 @@
+use derive_more::{Deref, From};
+use itertools::Itertools;
+use nom::combinator::into;
+use nom::IResult;
+use sorted_vec::SortedSet;
+
+use GardenFeature::*;
+
+use crate::answer::Answer;
+use crate::config::day_param;
+use crate::error::AocError;
+use crate::grid::{ArrayCells, Cells, Direction, Grid, Pos};
+use crate::parse::{char_grid, finish};
+use crate::progress::{NoProgress, Progress};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct BigPos {
+    row: isize,
+    col: isize,
+}
+
+impl BigPos {
+    fn adjacent(&self) -> Vec<BigPos> {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| {
+                let (rows, cols) = direction.delta();
+                BigPos {
+                    row: self.row + rows,
+                    col: self.col + cols,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum GardenFeature {
+    Start,
+    #[default]
+    Plot,
+    Rock,
+}
+
+/// The real input is 131 by 131
+const MAX_PLOTS: usize = 131 * 131;
+
+/// Every step looks at the plots around every plot reached so far, so they're kept inline, but
+/// any [Cells] will do
+#[derive(Debug, Clone, PartialEq, From, Deref)]
+pub struct Map<C = ArrayCells<GardenFeature, MAX_PLOTS>>(Grid<GardenFeature, C>);
+
+impl<C: Cells<GardenFeature>> Map<C> {
+    fn get_start_pos(&self) -> Result<Pos, AocError> {
+        self.position(|feature| feature == &Start)
+            .ok_or_else(|| AocError::parse("there's no S to start from"))
+    }
+
+    fn is_not_rock(&self, pos: Pos) -> bool {
+        self[pos] != Rock
+    }
+
+    fn is_not_rock_infinite(&self, pos: BigPos) -> bool {
+        let rows = self.height() as isize;
+        let cols = self.width() as isize;
+        let row = ((pos.row % rows) + rows) % rows;
+        let col = ((pos.col % cols) + cols) % cols;
+        self[Pos::new(row as usize, col as usize)] != Rock
+    }
+
+    fn reachable_in_n_steps(&self, steps: usize) -> Result<usize, AocError> {
+        let start = self.get_start_pos()?;
+        let mut queue: Vec<Pos> = vec![start];
+
+        for _ in 0..steps {
+            let mut temp = vec![];
+            while let Some(pos) = queue.pop() {
+                temp.extend(self.neighbours(pos))
+            }
+            queue.extend(
+                temp.into_iter()
+                    .filter(|pos| self.is_not_rock(*pos))
+                    .unique(),
+            )
+        }
+
+        Ok(queue.len())
+    }
+
+    fn reachable_in_n_steps_infinite(
+        &self,
+        steps: usize,
+        progress: &dyn Progress,
+    ) -> Result<usize, AocError> {
+        let start = self.get_start_pos()?;
+        let start = BigPos {
+            row: start.row as isize,
+            col: start.column as isize,
+        };
+        let mut queue: Vec<BigPos> = vec![start];
+        let mut could_end_here: SortedSet<BigPos> = SortedSet::new();
+        let mut could_not_end_here: SortedSet<BigPos> = SortedSet::new();
+        let steps_mod_2 = steps % 2;
+
+        progress.set_length(steps as u64);
+        for step in 1..=steps {
+            progress.check_cancelled()?;
+            progress.inc(1);
+            let could_end_this_tile = step % 2 == steps_mod_2;
+
+            let mut temp = vec![];
+            while let Some(pos) = queue.pop() {
+                temp.append(&mut pos.adjacent())
+            }
+
+            queue.extend(
+                temp.into_iter()
+                    .filter(|pos| self.is_not_rock_infinite(*pos))
+                    .filter(|pos| {
+                        if could_end_this_tile {
+                            could_end_here.find_or_insert(*pos).is_inserted()
+                        } else {
+                            could_not_end_here.find_or_insert(*pos).is_inserted()
+                        }
+                    })
+                    .unique(),
+            )
+        }
+
+        Ok(could_end_here.len())
+    }
+}
+
+fn garden_feature_from_char(c: char) -> Option<GardenFeature> {
+    match c {
+        'S' => Some(Start),
+        '.' => Some(Plot),
+        '#' => Some(Rock),
+        _ => None,
+    }
+}
+
+fn parse_garden_map(input: &str) -> IResult<&str, Map<Vec<GardenFeature>>> {
+    into(char_grid(garden_feature_from_char))(input)
+}
+
+fn garden_map<C: Cells<GardenFeature>>(input: &str) -> Result<Map<C>, AocError> {
+    Ok(finish(parse_garden_map, input)?.0.into_cells()?.into())
+}
+
+pub fn part2_with_progress(input: &str, progress: &dyn Progress) -> Result<Answer, AocError> {
+    Day21::part2_with_progress(&Day21::parse(input)?, progress)
+}
+
+fn reachable_in_part2_steps<C: Cells<GardenFeature>>(
+    map: &Map<C>,
+    progress: &dyn Progress,
+) -> Result<Answer, AocError> {
+    let steps = day_param(21, "part2_steps")?.unwrap_or(26501365);
+    Ok(map.reachable_in_n_steps_infinite(steps, progress)?.into())
+}
+
+fn reachable_in_part1_steps<C: Cells<GardenFeature>>(map: &Map<C>) -> Result<Answer, AocError> {
+    let steps = day_param(21, "part1_steps")?.unwrap_or(64);
+    Ok(map.reachable_in_n_steps(steps)?.into())
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+    const DAY: usize = 21;
+    const TITLE: &'static str = "Step Counter";
+
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        garden_map(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        reachable_in_part1_steps(map)
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Self::part2_with_progress(map, &NoProgress)
+    }
+
+    fn part2_with_progress(
+        map: &Self::Parsed<'_>,
+        progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        reachable_in_part2_steps(map, progress)
+    }
+}
+
+solution! {
+    Day21;
+    part1(1, Solved),
+    part2(2, Incomplete).with_progress(part2_with_progress),
+    part1_vec_grid(1, Solved).named("vec_grid"),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day21::part1(&Day21::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day21::part2(&Day21::parse(input)?)
+}
+
+/// Part 1 with the plots kept on the heap, to compare with the inline map
+pub fn part1_vec_grid(input: &str) -> Result<Answer, AocError> {
+    reachable_in_part1_steps(&garden_map::<Vec<GardenFeature>>(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod parsers {
+        use super::*;
+
+        #[test]
+        fn test_garden_feature_from_char() {
+            assert_eq!(garden_feature_from_char('S'), Some(Start));
+            assert_eq!(garden_feature_from_char('.'), Some(Plot));
+            assert_eq!(garden_feature_from_char('#'), Some(Rock));
+            assert_eq!(garden_feature_from_char('x'), None);
+        }
+
+        #[test]
+        fn test_parse_garden_map() {
+            let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+            let map = parse_garden_map(input).unwrap().1;
+            assert_eq!(map.height(), 11);
+            assert_eq!(map.width(), 11);
+            assert_eq!(map.get_start_pos(), Ok(Pos::new(5, 5)));
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        // assert_eq!(part1(input), "");
+        let map = parse_garden_map(input).unwrap().1;
+        assert_eq!(map.reachable_in_n_steps(6), Ok(16))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        // assert_eq!(part2(input), "");
+        let map = parse_garden_map(input).unwrap().1;
+        assert_eq!(map.reachable_in_n_steps_infinite(50, &NoProgress), Ok(1594))
+    }
+}
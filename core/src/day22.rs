@@ -9,58 +9,71 @@ use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair, tuple};
 use nom::IResult;
 
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::interval::Interval;
+use crate::parse::finish;
+use crate::solution;
+use crate::solver::Day;
+
+/// Where a cube is, `z` counting up from the ground at 0
 #[derive(Debug, Copy, Clone, PartialEq, From)]
-struct Coordinate {
-    x: u64,
-    y: u64,
-    z: u64,
+pub struct Coordinate {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
 }
 
+impl Coordinate {
+    pub fn new(x: u64, y: u64, z: u64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// The cells a brick covers, looking down from above
 #[derive(Debug, Copy, Clone, PartialEq)]
 struct Area {
-    top: u64,
-    bottom: u64,
-    left: u64,
-    right: u64,
+    x: Interval,
+    y: Interval,
 }
 
 impl Area {
-    fn point_inside(&self, x: u64, y: u64) -> bool {
-        self.left <= x && self.right >= x && self.top <= y && self.bottom >= y
-    }
-
+    /// Whether the areas share a cell. Checking corners isn't enough, a long thin brick can cross
+    /// another without either having a corner inside the other
     fn overlaps(&self, other: &Area) -> bool {
-        self.point_inside(other.left, other.top)
-            || self.point_inside(other.right, other.top)
-            || self.point_inside(other.left, other.bottom)
-            || self.point_inside(other.right, other.bottom)
-            || other.point_inside(self.left, self.top)
-            || other.point_inside(self.right, self.top)
-            || other.point_inside(self.left, self.bottom)
-            || other.point_inside(self.right, self.bottom)
+        self.x.overlaps(&other.x) && self.y.overlaps(&other.y)
     }
 }
 
 impl From<Brick> for Area {
     fn from(brick: Brick) -> Self {
         Self {
-            top: min(brick.0.y, brick.1.y),
-            bottom: max(brick.0.y, brick.1.y),
-            left: min(brick.0.x, brick.1.x),
-            right: max(brick.0.x, brick.1.x),
+            x: Interval::inclusive(min(brick.0.x, brick.1.x), max(brick.0.x, brick.1.x)),
+            y: Interval::inclusive(min(brick.0.y, brick.1.y), max(brick.0.y, brick.1.y)),
         }
     }
 }
 
+/// A line of cubes from one end to the other, including both
 #[derive(Debug, Copy, Clone, PartialEq, From)]
-struct Brick(Coordinate, Coordinate);
+pub struct Brick(Coordinate, Coordinate);
 
 impl Brick {
-    fn lowest_point(&self) -> u64 {
+    /// The ends can be either way round
+    pub fn new(one_end: Coordinate, other_end: Coordinate) -> Self {
+        Self(one_end, other_end)
+    }
+
+    pub fn ends(&self) -> (Coordinate, Coordinate) {
+        (self.0, self.1)
+    }
+
+    pub fn lowest_point(&self) -> u64 {
         min(self.0.z, self.1.z)
     }
 
-    fn highest_point(&self) -> u64 {
+    pub fn highest_point(&self) -> u64 {
         max(self.0.z, self.1.z)
     }
 
@@ -74,37 +87,27 @@ impl Brick {
         Area::from(*self).overlaps(&Area::from(*other))
     }
 
-    fn is_resting_on(&self, other: &Brick) -> bool {
+    /// Whether this is directly on top of `other`, with part of it over part of `other`
+    pub fn is_resting_on(&self, other: &Brick) -> bool {
         self.lowest_point() == other.highest_point() + 1 && self.footprint_overlaps(other)
     }
-
-    fn held_by(&self, bricks: &[Brick]) -> Vec<Brick> {
-        bricks
-            .iter()
-            .filter(|other| self.is_resting_on(other))
-            .copied()
-            .collect()
-    }
-
-    fn held_by_only(&self, bricks: &[Brick], other: &Brick) -> bool {
-        let held_by = self.held_by(bricks);
-        held_by.contains(other) && held_by.len() == 1
-    }
 }
 
+/// All of the bricks, made `From` a `Vec` of them
 #[derive(Debug, Clone, PartialEq, From, Deref, DerefMut)]
-struct Bricks(Vec<Brick>);
+pub struct Bricks(Vec<Brick>);
 
 impl Bricks {
     fn sort(&mut self) {
         self.sort_by_key(|brick| brick.lowest_point())
     }
 
-    fn collapse(&mut self) {
+    /// Let every brick fall as far as it can, leaving them sorted lowest first
+    pub fn collapse(&mut self) {
         self.sort();
         for i in 0..self.len() {
-            let mut current_brick = *self.get(i).unwrap();
-            let mut bricks_below = self[0..i].iter().rev();
+            let current_brick = self[i];
+            let bricks_below = self[0..i].iter().rev();
             let new_z = bricks_below
                 .filter_map(|other| {
                     current_brick
@@ -113,22 +116,65 @@ impl Bricks {
                 })
                 .max()
                 .unwrap_or(1);
-            self.get_mut(i).map(|brick| brick.move_down_to(new_z));
+            self[i].move_down_to(new_z);
         }
     }
 
-    fn find_potentially_removable(&self) -> Vec<Brick> {
-        let mut removable = vec![];
-        for i in 0..self.len() {
-            let current_brick = self.get(i).unwrap();
-            let is_holding_brick = self[(i + 1)..]
-                .iter()
-                .any(|other| other.held_by_only(&self, current_brick));
-            if !is_holding_brick {
-                removable.push(*current_brick)
+    /// An edge from each brick to every brick resting on it, numbered by where they are in the
+    /// list. Only makes sense once the bricks have [collapsed](Self::collapse)
+    pub fn support_graph(&self) -> Graph {
+        let mut graph = Graph::new(self.len());
+        for (below, brick) in self.iter().enumerate() {
+            for (above, other) in self.iter().enumerate() {
+                if other.is_resting_on(brick) {
+                    graph.add_edge(below, above);
+                }
             }
         }
-        removable
+        graph
+    }
+
+    /// Bricks that aren't the only thing holding up any brick above them
+    fn find_potentially_removable(&self) -> Vec<Brick> {
+        let supports = self.support_graph();
+        let supported_by = supports.reversed();
+        self.iter()
+            .enumerate()
+            .filter(|(brick, _)| {
+                supports
+                    .neighbours(*brick)
+                    .all(|above| supported_by.edges(above).len() > 1)
+            })
+            .map(|(_, brick)| *brick)
+            .collect()
+    }
+
+    /// For each brick, how many others would fall if it was taken away, all added up. Going
+    /// through the bricks in support order means everything under a brick has been decided by
+    /// the time it is
+    fn count_falling(&self) -> Result<usize, AocError> {
+        let supports = self.support_graph();
+        let supported_by = supports.reversed();
+        let order = supports
+            .toposort()
+            .ok_or_else(|| AocError::no_answer("the bricks are holding each other up"))?;
+        let count = order
+            .iter()
+            .enumerate()
+            .map(|(position, &removed)| {
+                let mut falling = vec![false; self.len()];
+                falling[removed] = true;
+                order[position + 1..]
+                    .iter()
+                    .filter(|&&brick| {
+                        falling[brick] = !supported_by.edges(brick).is_empty()
+                            && supported_by.neighbours(brick).all(|below| falling[below]);
+                        falling[brick]
+                    })
+                    .count()
+            })
+            .sum();
+        Ok(count)
     }
 }
 
@@ -139,7 +185,7 @@ fn parse_coordinate(input: &str) -> IResult<&str, Coordinate> {
             preceded(tag(","), complete::u64),
             preceded(tag(","), complete::u64),
         )),
-        |(x, y, z)| Coordinate { x, y, z },
+        |(x, y, z)| Coordinate::new(x, y, z),
     )(input)
 }
 
@@ -151,14 +197,46 @@ fn parse_bricks(input: &str) -> IResult<&str, Bricks> {
     into(separated_list1(newline, parse_brick))(input)
 }
 
-pub fn part1(input: &str) -> String {
-    let mut bricks = parse_bricks(input).unwrap().1;
-    bricks.collapse();
-    bricks.find_potentially_removable().len().to_string()
+pub struct Day22;
+
+impl Day for Day22 {
+    const DAY: usize = 22;
+    const TITLE: &'static str = "Sand Slabs";
+
+    /// The bricks as they were in the snapshot, before they've fallen
+    type Parsed<'a> = Bricks;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        finish(parse_bricks, input)
+    }
+
+    fn part1(bricks: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut bricks = bricks.clone();
+        bricks.collapse();
+        Ok(bricks.find_potentially_removable().len().into())
+    }
+
+    fn part2(bricks: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        let mut bricks = bricks.clone();
+        bricks.collapse();
+        Ok(bricks.count_falling()?.into())
+    }
+}
+
+solution! {
+    Day22 {
+        "d22.txt" => part1 "5", part2 "7";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day22::part1(&Day22::parse(input)?)
 }
 
-pub fn part2(_input: &str) -> String {
-    todo!()
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day22::part2(&Day22::parse(input)?)
 }
 
 #[cfg(test)]
@@ -168,78 +246,56 @@ mod test {
     mod area {
         use super::*;
 
-        #[test]
-        fn test_point_inside() {
-            let area = Area {
-                top: 1,
-                bottom: 2,
-                left: 1,
-                right: 2,
-            };
-
-            assert!(area.point_inside(1, 1));
-            assert!(area.point_inside(1, 2));
-            assert!(area.point_inside(2, 1));
-            assert!(area.point_inside(2, 2));
-            assert!(!area.point_inside(0, 2));
-        }
-
         #[test]
         fn test_area_overlaps() {
             let area1 = Area {
-                top: 1,
-                bottom: 3,
-                left: 1,
-                right: 3,
+                x: Interval::inclusive(1, 3),
+                y: Interval::inclusive(1, 3),
             };
             // Perfect overlap
             assert!(area1.overlaps(&Area {
-                top: 1,
-                bottom: 3,
-                left: 1,
-                right: 3,
+                x: Interval::inclusive(1, 3),
+                y: Interval::inclusive(1, 3),
             }));
             // Inside
             assert!(area1.overlaps(&Area {
-                top: 2,
-                bottom: 2,
-                left: 2,
-                right: 2,
+                x: Interval::inclusive(2, 2),
+                y: Interval::inclusive(2, 2),
             }));
             // Outside
             assert!(area1.overlaps(&Area {
-                top: 0,
-                bottom: 4,
-                left: 0,
-                right: 4,
+                x: Interval::inclusive(0, 4),
+                y: Interval::inclusive(0, 4),
             }));
             // TL
             assert!(area1.overlaps(&Area {
-                top: 2,
-                bottom: 4,
-                left: 2,
-                right: 4,
+                x: Interval::inclusive(2, 4),
+                y: Interval::inclusive(2, 4),
             }));
             // TR
             assert!(area1.overlaps(&Area {
-                top: 2,
-                bottom: 4,
-                left: 0,
-                right: 2,
+                x: Interval::inclusive(0, 2),
+                y: Interval::inclusive(2, 4),
             }));
             // BL
             assert!(area1.overlaps(&Area {
-                top: 0,
-                bottom: 2,
-                left: 2,
-                right: 4,
+                x: Interval::inclusive(2, 4),
+                y: Interval::inclusive(0, 2),
             }));
             // BR
             assert!(area1.overlaps(&Area {
-                top: 0,
-                bottom: 2,
-                left: 0,
-                right: 2,
+                x: Interval::inclusive(0, 2),
+                y: Interval::inclusive(0, 2),
+            }));
+            // Crossing, no corners inside each other
+            assert!(area1.overlaps(&Area {
+                x: Interval::inclusive(0, 4),
+                y: Interval::inclusive(2, 2),
+            }));
+            // Beside
+            assert!(!area1.overlaps(&Area {
+                x: Interval::inclusive(4, 5),
+                y: Interval::inclusive(1, 3),
             }));
         }
 
@@ -252,10 +308,8 @@ mod test {
             assert_eq!(
                 Area::from(brick),
                 Area {
-                    top: 2,
-                    bottom: 3,
-                    left: 1,
-                    right: 2,
+                    x: Interval::inclusive(1, 2),
+                    y: Interval::inclusive(2, 3),
                 }
             )
         }
@@ -309,65 +363,6 @@ mod test {
             assert!(!test_brick.is_resting_on(&below));
             assert!(!test_brick.is_resting_on(&aside));
         }
-
-        #[test]
-        fn test_held_by() {
-            let test_brick = Brick(
-                Coordinate { x: 1, y: 1, z: 3 },
-                Coordinate { x: 2, y: 2, z: 3 },
-            );
-            let resting_on_1 = Brick(
-                Coordinate { x: 0, y: 0, z: 2 },
-                Coordinate { x: 1, y: 1, z: 2 },
-            );
-            let resting_on_2 = Brick(
-                Coordinate { x: 2, y: 2, z: 2 },
-                Coordinate { x: 3, y: 3, z: 2 },
-            );
-            let below = Brick(
-                Coordinate { x: 0, y: 0, z: 1 },
-                Coordinate { x: 1, y: 1, z: 1 },
-            );
-            let aside = Brick(
-                Coordinate { x: 4, y: 4, z: 2 },
-                Coordinate { x: 4, y: 4, z: 2 },
-            );
-            let bricks = [test_brick, resting_on_1, resting_on_2, below, aside];
-
-            assert_eq!(
-                test_brick.held_by(&bricks),
-                vec![resting_on_1, resting_on_2]
-            );
-        }
-
-        #[test]
-        fn test_held_by_only() {
-            let test_brick = Brick(
-                Coordinate { x: 1, y: 1, z: 3 },
-                Coordinate { x: 2, y: 2, z: 3 },
-            );
-            let resting_on_1 = Brick(
-                Coordinate { x: 0, y: 0, z: 2 },
-                Coordinate { x: 1, y: 1, z: 2 },
-            );
-            let resting_on_2 = Brick(
-                Coordinate { x: 2, y: 2, z: 2 },
-                Coordinate { x: 3, y: 3, z: 2 },
-            );
-            let below = Brick(
-                Coordinate { x: 0, y: 0, z: 1 },
-                Coordinate { x: 1, y: 1, z: 1 },
-            );
-            let aside = Brick(
-                Coordinate { x: 4, y: 4, z: 2 },
-                Coordinate { x: 4, y: 4, z: 2 },
-            );
-            let two_resting = [test_brick, resting_on_1, resting_on_2, below, aside];
-            let one_resting = [test_brick, resting_on_1, below, aside];
-
-            assert!(!test_brick.held_by_only(&two_resting, &resting_on_1));
-            assert!(test_brick.held_by_only(&one_resting, &resting_on_1));
-        }
     }
 
     mod bricks {
@@ -502,24 +497,48 @@ mod test {
             // || ===
             assert_eq!(bricks.find_potentially_removable().len(), 2);
         }
+
+        #[test]
+        fn test_support_graph() {
+            let bricks = Bricks(vec![
+                // Low and flat
+                Brick(
+                    Coordinate { x: 1, y: 1, z: 1 },
+                    Coordinate { x: 1, y: 4, z: 1 },
+                ),
+                // Sits on low and flat
+                Brick(
+                    Coordinate { x: 1, y: 2, z: 2 },
+                    Coordinate { x: 1, y: 2, z: 2 },
+                ),
+                // Is really tall but rests on ground
+                Brick(
+                    Coordinate { x: 0, y: 0, z: 1 },
+                    Coordinate { x: 0, y: 0, z: 6 },
+                ),
+                // Crosses the other two
+                Brick(
+                    Coordinate { x: 0, y: 0, z: 7 },
+                    Coordinate { x: 4, y: 0, z: 7 },
+                ),
+            ]);
+            let supports = bricks.support_graph();
+            assert_eq!(supports.neighbours(0).collect::<Vec<_>>(), vec![1]);
+            assert_eq!(supports.neighbours(2).collect::<Vec<_>>(), vec![3]);
+            assert_eq!(supports.neighbours(3).count(), 0);
+            assert_eq!(bricks.count_falling(), Ok(2));
+        }
     }
 
     #[test]
     fn test_part1() {
-        let input = "1,0,1~1,2,1
-0,0,2~2,0,2
-0,2,3~2,2,3
-0,0,4~0,2,4
-2,0,5~2,2,5
-0,1,6~2,1,6
-1,1,8~1,1,9";
-        assert_eq!(part1(input), "5");
+        let input = include_str!("examples/d22.txt");
+        assert_eq!(part1(input).unwrap(), "5");
     }
 
-    #[ignore]
     #[test]
     fn test_part2() {
-        let input = "";
-        assert_eq!(part2(input), "");
+        let input = include_str!("examples/d22.txt");
+        assert_eq!(part2(input).unwrap(), "7");
     }
 }
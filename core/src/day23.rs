@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::grid::{Direction, Grid, Pos};
+use crate::parse::{char_grid, finish};
+use crate::solution;
+use crate::solver::Day;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    /// Can only be left going downhill, which is the way it points
+    Slope(Direction),
+}
+
+fn tile_from_char(c: char) -> Option<Tile> {
+    match c {
+        '.' => Some(Tile::Path),
+        '#' => Some(Tile::Forest),
+        '^' => Some(Tile::Slope(Direction::Up)),
+        'v' => Some(Tile::Slope(Direction::Down)),
+        '<' => Some(Tile::Slope(Direction::Left)),
+        '>' => Some(Tile::Slope(Direction::Right)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trails {
+    map: Grid<Tile>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Trails {
+    fn new(map: Grid<Tile>) -> Result<Self, AocError> {
+        let path_in_row = |row: usize| {
+            (0..map.width())
+                .map(|column| Pos::new(row, column))
+                .find(|pos| map[*pos] == Tile::Path)
+        };
+        let start =
+            path_in_row(0).ok_or_else(|| AocError::parse("there's no path in the top row"))?;
+        let end = path_in_row(map.height() - 1)
+            .ok_or_else(|| AocError::parse("there's no path in the bottom row"))?;
+        Ok(Self { map, start, end })
+    }
+
+    /// Where one step in `direction` goes, if it isn't into the forest or up a slippery slope
+    fn step(&self, from: Pos, direction: Direction, slippery: bool) -> Option<Pos> {
+        if let Tile::Slope(downhill) = self.map[from] {
+            if slippery && downhill != direction {
+                return None;
+            }
+        }
+        self.map
+            .step(from, direction)
+            .filter(|to| self.map[*to] != Tile::Forest)
+    }
+
+    /// The start, the end and everywhere the path splits
+    fn junctions(&self) -> Vec<Pos> {
+        let splits = self.map.positions().filter(|pos| {
+            self.map[*pos] != Tile::Forest
+                && self
+                    .map
+                    .neighbours(*pos)
+                    .filter(|next| self.map[*next] != Tile::Forest)
+                    .count()
+                    > 2
+        });
+        [self.start, self.end].into_iter().chain(splits).collect()
+    }
+
+    /// The junctions, with the start as node 0 and the end as node 1, and an edge for each trail
+    /// between two of them weighted by its length
+    fn junction_graph(&self, slippery: bool) -> Graph {
+        let junctions = self.junctions();
+        let node: HashMap<Pos, usize> = junctions
+            .iter()
+            .enumerate()
+            .map(|(node, pos)| (*pos, node))
+            .collect();
+        let mut graph = Graph::new(junctions.len());
+        for (from, &junction) in junctions.iter().enumerate() {
+            for direction in Direction::ALL {
+                let Some(mut pos) = self.step(junction, direction, slippery) else {
+                    continue;
+                };
+                let mut previous = junction;
+                let mut length = 1;
+                while !node.contains_key(&pos) {
+                    let next = Direction::ALL
+                        .into_iter()
+                        .filter_map(|direction| self.step(pos, direction, slippery))
+                        .find(|next| *next != previous);
+                    let Some(next) = next else {
+                        break;
+                    };
+                    previous = pos;
+                    pos = next;
+                    length += 1;
+                }
+                if let Some(&to) = node.get(&pos) {
+                    graph.add_weighted_edge(from, to, length);
+                }
+            }
+        }
+        graph
+    }
+
+    /// The most steps it takes to get from the start to the end without stepping anywhere twice
+    fn longest_hike(&self, slippery: bool) -> Result<usize, AocError> {
+        self.junction_graph(slippery)
+            .longest_path(0, 1)
+            .ok_or_else(|| AocError::no_answer("there's no way from the start to the end"))
+    }
+}
+
+pub struct Day23;
+
+impl Day for Day23 {
+    const DAY: usize = 23;
+    const TITLE: &'static str = "A Long Walk";
+
+    type Parsed<'a> = Trails;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Trails::new(finish(char_grid(tile_from_char), input)?)
+    }
+
+    fn part1(trails: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(trails.longest_hike(true)?.into())
+    }
+
+    fn part2(trails: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Ok(trails.longest_hike(false)?.into())
+    }
+}
+
+solution! {
+    Day23 {
+        "d23.txt" => part1 "94", part2 "154";
+    }
+    part1(1, Solved),
+    part2(2, Solved),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day23::part1(&Day23::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day23::part2(&Day23::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_junction_graph() {
+        let trails = Day23::parse(include_str!("examples/d23.txt")).unwrap();
+        assert_eq!(trails.start, Pos::new(0, 1));
+        assert_eq!(trails.end, Pos::new(22, 21));
+        let slippery = trails.junction_graph(true);
+        assert_eq!(slippery.len(), 9);
+        assert_eq!(
+            slippery.edges(0),
+            [crate::graph::Edge { to: 3, weight: 15 }]
+        );
+        assert!(slippery.toposort().is_some());
+        assert!(trails.junction_graph(false).toposort().is_none());
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("examples/d23.txt");
+        assert_eq!(part1(input).unwrap(), "94");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("examples/d23.txt");
+        assert_eq!(part2(input).unwrap(), "154");
+    }
+}
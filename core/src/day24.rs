@@ -0,0 +1,58 @@
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::solution;
+use crate::solver::Day;
+
+pub fn part1(_input: &str) -> Result<Answer, AocError> {
+    todo!()
+}
+
+pub fn part2(_input: &str) -> Result<Answer, AocError> {
+    todo!()
+}
+
+pub struct Day24;
+
+impl Day for Day24 {
+    const DAY: usize = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        part2(input)
+    }
+}
+
+solution! {
+    Day24;
+    part1(1, Todo),
+    part2(2, Todo),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[ignore]
+    #[test]
+    fn test_part1() {
+        let input = "";
+        assert_eq!(part1(input).unwrap(), "");
+    }
+
+    #[ignore]
+    #[test]
+    fn test_part2() {
+        let input = "";
+        assert_eq!(part2(input).unwrap(), "");
+    }
+}
@@ -0,0 +1,159 @@
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, space1};
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::graph::Graph;
+use crate::intern::Interner;
+use crate::parse::{finish, lines};
+use crate::progress::{NoProgress, Progress};
+use crate::solution;
+use crate::solver::Day;
+
+/// How many wires need cutting to split the components in two
+const WIRES_TO_CUT: usize = 3;
+
+fn parse_wiring(input: &str) -> IResult<&str, Vec<(&str, Vec<&str>)>> {
+    lines(separated_pair(
+        alpha1,
+        tag(": "),
+        separated_list1(space1, alpha1),
+    ))(input)
+}
+
+/// Every component, joined both ways to each component it's wired to
+fn wiring_graph(wiring: &[(&str, Vec<&str>)]) -> Graph {
+    let mut labels = Interner::new();
+    let mut wires = vec![];
+    for (from, to) in wiring {
+        let from = labels.intern(from);
+        wires.extend(to.iter().map(|to| (from, labels.intern(to))));
+    }
+    let mut graph = Graph::new(labels.len());
+    for (from, to) in wires {
+        graph.add_edge(from.index(), to.index());
+        graph.add_edge(to.index(), from.index());
+    }
+    graph
+}
+
+/// The fewest wires that split `source` from `sink`, if that's no more than `most` of them. Each
+/// wire can carry one path, so after sending as many paths from `source` to `sink` as will go, the
+/// wires from where `source` can still reach to where it can't are the ones to cut
+fn min_cut(graph: &Graph, source: usize, sink: usize, most: usize) -> Option<Vec<(usize, usize)>> {
+    let mut residual = graph.clone();
+    for _ in 0..=most {
+        let Some(path) = residual.shortest_path(source, sink) else {
+            let reachable = residual.bfs(source);
+            let cut = (0..graph.len())
+                .filter(|from| reachable[*from].is_some())
+                .flat_map(|from| graph.neighbours(from).map(move |to| (from, to)))
+                .filter(|(_, to)| reachable[*to].is_none())
+                .collect();
+            return Some(cut);
+        };
+        for (&from, &to) in path.iter().tuple_windows() {
+            residual.remove_edge(from, to);
+            residual.add_edge(to, from);
+        }
+    }
+    None
+}
+
+/// Cut the wires that split the components into two groups, and multiply the groups' sizes. Any
+/// component is in one group or the other, so it's tried against each other in turn until they're
+/// in different ones
+fn split_groups(graph: &Graph, wires: usize, progress: &dyn Progress) -> Result<usize, AocError> {
+    progress.set_length(graph.len().saturating_sub(1) as u64);
+    let mut cut = None;
+    for sink in 1..graph.len() {
+        progress.check_cancelled()?;
+        cut = min_cut(graph, 0, sink, wires);
+        progress.inc(1);
+        if cut.is_some() {
+            break;
+        }
+    }
+    let cut =
+        cut.ok_or_else(|| AocError::no_answer(format!("cutting {wires} wires can't split them")))?;
+    let mut graph = graph.clone();
+    for (from, to) in cut {
+        graph.remove_edge(from, to);
+        graph.remove_edge(to, from);
+    }
+    Ok(graph.connected_components().iter().map(Vec::len).product())
+}
+
+pub struct Day25;
+
+impl Day for Day25 {
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Snowverload";
+
+    type Parsed<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+        Ok(wiring_graph(&finish(parse_wiring, input)?))
+    }
+
+    fn part1(graph: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        Self::part1_with_progress(graph, &NoProgress)
+    }
+
+    fn part2(_graph: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+        todo!()
+    }
+
+    fn part1_with_progress(
+        graph: &Self::Parsed<'_>,
+        progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        Ok(split_groups(graph, WIRES_TO_CUT, progress)?.into())
+    }
+}
+
+solution! {
+    Day25 {
+        "d25.txt" => part1 "54";
+    }
+    part1(1, Solved).with_progress(part1_with_progress),
+    part2(2, Todo),
+}
+
+pub fn part1(input: &str) -> Result<Answer, AocError> {
+    Day25::part1(&Day25::parse(input)?)
+}
+
+pub fn part1_with_progress(input: &str, progress: &dyn Progress) -> Result<Answer, AocError> {
+    Day25::part1_with_progress(&Day25::parse(input)?, progress)
+}
+
+pub fn part2(input: &str) -> Result<Answer, AocError> {
+    Day25::part2(&Day25::parse(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_cut() {
+        let graph = Day25::parse(include_str!("examples/d25.txt")).unwrap();
+        assert_eq!(graph.len(), 15);
+        let cut = (1..graph.len())
+            .find_map(|sink| min_cut(&graph, 0, sink, WIRES_TO_CUT))
+            .unwrap();
+        assert_eq!(cut.len(), WIRES_TO_CUT);
+        assert_eq!(min_cut(&graph, 0, 0, WIRES_TO_CUT), None);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("examples/d25.txt");
+        assert_eq!(part1(input).unwrap(), "54");
+    }
+}
@@ -0,0 +1,127 @@
+use thiserror::Error;
+
+/// Why a part couldn't give an answer for its input
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AocError {
+    /// The input isn't in the shape the puzzle describes
+    #[error("couldn't parse the input: {0}")]
+    Parse(String),
+    /// The input parsed, but there's no answer in it, eg a route that never reaches the end
+    #[error("no answer: {0}")]
+    NoAnswer(String),
+    /// A solver loaded from a plugin failed, all that comes back is its message
+    #[error("the plugin failed: {0}")]
+    Plugin(String),
+    /// A day parameter, from `aoc.toml` or `--param`, isn't the type the day expects
+    #[error("bad parameter: {0}")]
+    Param(String),
+    /// Whoever was running the solver asked it to stop, eg because it took too long
+    #[error("cancelled")]
+    Cancelled,
+}
+
+impl AocError {
+    pub fn parse(message: impl ToString) -> Self {
+        AocError::Parse(message.to_string())
+    }
+
+    pub fn no_answer(message: impl ToString) -> Self {
+        AocError::NoAnswer(message.to_string())
+    }
+
+    /// Where in `input` nom gave up, as a line and column along with the start of what it couldn't
+    /// parse. `error` has to have come from parsing `input`, otherwise there's no telling where it
+    /// is and it's just the line nom gave up on
+    pub fn parse_at(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        let (nom::Err::Error(failed) | nom::Err::Failure(failed)) = &error else {
+            return error.into();
+        };
+        let Some(offset) = (failed.input.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + failed.input.len() == input.len())
+        else {
+            return error.into();
+        };
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+        AocError::Parse(format!(
+            "{:?} failed at line {line} column {column}, {:?}",
+            failed.code,
+            snippet(failed.input)
+        ))
+    }
+}
+
+/// How much of what's left to parse to show, enough to spot the problem without a whole map
+const SNIPPET_LENGTH: usize = 40;
+
+/// The start of the rest of the line
+fn snippet(remainder: &str) -> String {
+    let line = remainder.lines().next().unwrap_or_default();
+    match line.char_indices().nth(SNIPPET_LENGTH) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+/// Just the line nom gave up on, the rest of the input after it doesn't help find the problem
+impl From<nom::Err<nom::error::Error<&str>>> for AocError {
+    fn from(error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => AocError::parse("the input ended early"),
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                let line = error.input.lines().next().unwrap_or_default();
+                AocError::Parse(format!("{:?} failed at {line:?}", error.code))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete::{digit1, newline};
+    use nom::combinator::eof;
+    use nom::multi::separated_list1;
+    use nom::sequence::terminated;
+    use nom::IResult;
+
+    #[test]
+    fn test_from_nom() {
+        let result: IResult<&str, &str> = digit1("abc\ndef");
+        assert_eq!(
+            AocError::from(result.unwrap_err()).to_string(),
+            r#"couldn't parse the input: Digit failed at "abc""#
+        );
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "12\n34\n5x6\n";
+        let result: IResult<&str, Vec<&str>> =
+            terminated(separated_list1(newline, digit1), eof)(input);
+        assert_eq!(
+            AocError::parse_at(input, result.unwrap_err()).to_string(),
+            r#"couldn't parse the input: Eof failed at line 3 column 2, "x6""#
+        );
+        let long = "1".repeat(50) + "x";
+        let result: IResult<&str, &str> = eof(long.as_str());
+        assert_eq!(
+            AocError::parse_at(&long, result.unwrap_err()).to_string(),
+            format!(
+                r#"couldn't parse the input: Eof failed at line 1 column 1, "{}...""#,
+                "1".repeat(40)
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_at_other_input() {
+        let result: IResult<&str, &str> = digit1("abc");
+        assert_eq!(
+            AocError::parse_at("something else", result.unwrap_err()).to_string(),
+            r#"couldn't parse the input: Digit failed at "abc""#
+        );
+    }
+}
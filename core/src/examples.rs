@@ -0,0 +1,93 @@
+use crate::registry::get_info;
+
+/// An example input along with the answer the puzzle description gives for it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Example {
+    pub path: &'static str,
+    pub input: &'static str,
+    pub answer: &'static str,
+}
+
+/// An example from a day's puzzle description, with the answer it gives for each part it's an
+/// example for. Some days have a different example for each part, some share one
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DayExample {
+    pub path: &'static str,
+    pub input: &'static str,
+    pub answers: [Option<&'static str>; 2],
+}
+
+impl DayExample {
+    pub const fn new(path: &'static str, input: &'static str) -> Self {
+        Self {
+            path,
+            input,
+            answers: [None, None],
+        }
+    }
+
+    pub const fn part1(mut self, answer: &'static str) -> Self {
+        self.answers[0] = Some(answer);
+        self
+    }
+
+    pub const fn part2(mut self, answer: &'static str) -> Self {
+        self.answers[1] = Some(answer);
+        self
+    }
+
+    pub fn answer(&self, part: usize) -> Option<&'static str> {
+        *self.answers.get(part.checked_sub(1)?)?
+    }
+
+    /// Just this example's input and its answer for `part`, if it's an example for that part
+    pub fn for_part(&self, part: usize) -> Option<Example> {
+        Some(Example {
+            path: self.path,
+            input: self.input,
+            answer: self.answer(part)?,
+        })
+    }
+}
+
+/// Every example there is for a day and part
+pub fn examples(day: usize, part: usize) -> impl Iterator<Item = Example> {
+    get_info(day)
+        .into_iter()
+        .flat_map(|info| info.examples)
+        .filter_map(move |example| example.for_part(part))
+}
+
+/// The (first) example from a day's puzzle description for a part, if it has one
+pub fn get_example(day: usize, part: usize) -> Option<Example> {
+    examples(day, part).next()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_for_part() {
+        let example = DayExample::new("example.txt", "1\n2").part2("3");
+        assert_eq!(example.for_part(1), None);
+        assert_eq!(example.for_part(2).map(|example| example.answer), Some("3"));
+        assert_eq!(example.for_part(3), None);
+        assert_eq!(example.for_part(0), None);
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(10, 1)
+                .map(|example| example.answer)
+                .collect::<Vec<_>>(),
+            vec!["4", "8"]
+        );
+        assert_eq!(
+            get_example(1, 2).unwrap().path,
+            "core/src/examples/d01_part2.txt"
+        );
+        assert_eq!(get_example(24, 1), None);
+    }
+}
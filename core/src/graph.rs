@@ -0,0 +1,289 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// A way from one node of a [Graph] to another, and what it costs to take
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: usize,
+}
+
+/// A directed graph of nodes numbered from 0, each with a list of the edges leaving it. Undirected
+/// graphs add each edge both ways
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Graph {
+    edges: Vec<Vec<Edge>>,
+}
+
+impl Graph {
+    /// A graph of `len` nodes with no edges between them
+    pub fn new(len: usize) -> Self {
+        Self {
+            edges: vec![Vec::new(); len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, from: usize, to: usize, weight: usize) {
+        self.edges[from].push(Edge { to, weight });
+    }
+
+    /// Take out one of the edges from `from` to `to`, returning it if there was one
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> Option<Edge> {
+        let position = self.edges[from].iter().position(|edge| edge.to == to)?;
+        Some(self.edges[from].swap_remove(position))
+    }
+
+    pub fn edges(&self, node: usize) -> &[Edge] {
+        &self.edges[node]
+    }
+
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[node].iter().map(|edge| edge.to)
+    }
+
+    /// The same graph with every edge turned around, so a node's neighbours are the nodes that
+    /// had edges to it
+    pub fn reversed(&self) -> Self {
+        let mut reversed = Self::new(self.len());
+        for (from, edges) in self.edges.iter().enumerate() {
+            for edge in edges {
+                reversed.add_weighted_edge(edge.to, from, edge.weight);
+            }
+        }
+        reversed
+    }
+
+    /// How many edges it takes to get to each node from `start`, ignoring weights, or `None` for
+    /// nodes that can't be reached
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> {
+        let mut steps = vec![None; self.len()];
+        steps[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let next_steps = steps[node].map(|steps| steps + 1);
+            for next in self.neighbours(node) {
+                if steps[next].is_none() {
+                    steps[next] = next_steps;
+                    queue.push_back(next);
+                }
+            }
+        }
+        steps
+    }
+
+    /// One of the paths from `start` to `goal` with the fewest edges, including both ends
+    pub fn shortest_path(&self, start: usize, goal: usize) -> Option<Vec<usize>> {
+        let mut came_from = vec![None; self.len()];
+        came_from[start] = Some(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(&previous) = path.last().filter(|node| **node != start) {
+                    path.push(came_from[previous]?);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in self.neighbours(node) {
+                if came_from[next].is_none() {
+                    came_from[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Every node that can be reached from `start`, in the order a depth first search finds them
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![start];
+        let mut order = vec![];
+        while let Some(node) = stack.pop() {
+            if seen[node] {
+                continue;
+            }
+            seen[node] = true;
+            order.push(node);
+            stack.extend(self.neighbours(node).filter(|next| !seen[*next]));
+        }
+        order
+    }
+
+    /// The cheapest total weight to get to each node from any of the `starts`, or `None` for nodes
+    /// that can't be reached
+    pub fn dijkstra(&self, starts: impl IntoIterator<Item = usize>) -> Vec<Option<usize>> {
+        let mut costs = vec![None; self.len()];
+        let mut queue: BinaryHeap<_> = starts
+            .into_iter()
+            .map(|start| Reverse((0, start)))
+            .collect();
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if costs[node].is_some() {
+                continue;
+            }
+            costs[node] = Some(cost);
+            for edge in self.edges(node) {
+                if costs[edge.to].is_none() {
+                    queue.push(Reverse((cost + edge.weight, edge.to)));
+                }
+            }
+        }
+        costs
+    }
+
+    /// The nodes ordered so every edge goes from an earlier node to a later one, or `None` if
+    /// there's a cycle and no such order
+    pub fn toposort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for to in self.edges.iter().flatten().map(|edge| edge.to) {
+            incoming[to] += 1;
+        }
+        let mut ready: VecDeque<_> = (0..self.len())
+            .filter(|node| incoming[*node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for next in self.neighbours(node) {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Groups of nodes joined to each other by edges going either way, with no edges between groups
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let reversed = self.reversed();
+        let mut component = vec![None; self.len()];
+        let mut components = vec![];
+        for start in 0..self.len() {
+            if component[start].is_some() {
+                continue;
+            }
+            let mut nodes = vec![];
+            let mut stack = vec![start];
+            component[start] = Some(components.len());
+            while let Some(node) = stack.pop() {
+                nodes.push(node);
+                for next in self.neighbours(node).chain(reversed.neighbours(node)) {
+                    if component[next].is_none() {
+                        component[next] = Some(components.len());
+                        stack.push(next);
+                    }
+                }
+            }
+            nodes.sort();
+            components.push(nodes);
+        }
+        components
+    }
+
+    /// The heaviest path from `start` to `goal` that doesn't visit a node twice. This tries every
+    /// path, so is only fit for small graphs, eg the junctions of a maze rather than every cell
+    pub fn longest_path(&self, start: usize, goal: usize) -> Option<usize> {
+        let mut visited = vec![false; self.len()];
+        self.longest_path_from(start, goal, &mut visited)
+    }
+
+    fn longest_path_from(&self, node: usize, goal: usize, visited: &mut [bool]) -> Option<usize> {
+        if node == goal {
+            return Some(0);
+        }
+        visited[node] = true;
+        let longest = self
+            .edges(node)
+            .iter()
+            .filter_map(|edge| {
+                if visited[edge.to] {
+                    return None;
+                }
+                let rest = self.longest_path_from(edge.to, goal, visited)?;
+                Some(edge.weight + rest)
+            })
+            .max();
+        visited[node] = false;
+        longest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 with a shortcut from 0 to 2 and 4 off on its own
+    fn example() -> Graph {
+        let mut graph = Graph::new(5);
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(1, 2, 1);
+        graph.add_weighted_edge(2, 3, 1);
+        graph.add_weighted_edge(0, 2, 5);
+        graph
+    }
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(
+            example().bfs(0),
+            vec![Some(0), Some(1), Some(1), Some(2), None]
+        );
+        assert_eq!(example().shortest_path(0, 3), Some(vec![0, 2, 3]));
+        assert_eq!(example().shortest_path(0, 0), Some(vec![0]));
+        assert_eq!(example().shortest_path(3, 0), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        assert_eq!(example().dfs(0), vec![0, 2, 3, 1]);
+        assert_eq!(example().dfs(4), vec![4]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let costs = example().dijkstra([0]);
+        assert_eq!(costs, vec![Some(0), Some(1), Some(2), Some(3), None]);
+        assert_eq!(example().dijkstra([1, 4])[3], Some(2));
+    }
+
+    #[test]
+    fn test_toposort() {
+        assert_eq!(example().toposort(), Some(vec![0, 4, 1, 2, 3]));
+        let mut cycle = example();
+        cycle.add_edge(3, 0);
+        assert_eq!(cycle.toposort(), None);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = example();
+        assert_eq!(graph.remove_edge(1, 2), Some(Edge { to: 2, weight: 1 }));
+        assert_eq!(graph.remove_edge(1, 2), None);
+        graph.remove_edge(0, 2);
+        let components = graph.connected_components();
+        assert_eq!(components, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn test_longest_path() {
+        assert_eq!(example().longest_path(0, 3), Some(6));
+        assert_eq!(example().longest_path(1, 3), Some(2));
+        assert_eq!(example().longest_path(3, 0), None);
+        assert_eq!(example().reversed().longest_path(3, 0), Some(6));
+    }
+}
@@ -0,0 +1,456 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use crate::error::AocError;
+
+/// One of the four ways to move around a [Grid]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// Which way you'd face after turning left while facing this way
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// How far one step this way moves the row and column
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+/// A cell in a [Grid], counting rows down from the top and columns right from the left
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// One step in `direction`, unless that goes off the top or left. Only the grid knows where
+    /// its bottom and right are, so use [Grid::step] to stay inside it
+    pub fn step(self, direction: Direction) -> Option<Pos> {
+        let (rows, columns) = direction.delta();
+        Some(Pos::new(
+            self.row.checked_add_signed(rows)?,
+            self.column.checked_add_signed(columns)?,
+        ))
+    }
+
+    /// How many steps it takes to get to `other` when nothing is in the way
+    pub fn distance(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+/// Where a [Grid] keeps its cells, a row after another
+pub trait Cells<T>: AsRef<[T]> + AsMut<[T]> + Sized {
+    /// Somewhere holding all of `cells`, or `None` if there are more than fit
+    fn from_cells(cells: impl IntoIterator<Item = T>) -> Option<Self>;
+}
+
+impl<T> Cells<T> for Vec<T> {
+    fn from_cells(cells: impl IntoIterator<Item = T>) -> Option<Self> {
+        Some(cells.into_iter().collect())
+    }
+}
+
+/// Up to `N` cells kept inline rather than on the heap, so a grid of them is one block of memory
+/// that's copied whole when cloned. Fits a day's input when `N` is at least its width times height
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayCells<T, const N: usize> {
+    cells: [T; N],
+    len: usize,
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayCells<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.cells[..self.len]
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayCells<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.cells[..self.len]
+    }
+}
+
+/// The unused cells are left as `T::default()`
+impl<T: Copy + Default, const N: usize> Cells<T> for ArrayCells<T, N> {
+    fn from_cells(cells: impl IntoIterator<Item = T>) -> Option<Self> {
+        let mut array = Self {
+            cells: [T::default(); N],
+            len: 0,
+        };
+        for cell in cells {
+            *array.cells.get_mut(array.len)? = cell;
+            array.len += 1;
+        }
+        Some(array)
+    }
+}
+
+/// A [Grid] with its cells kept inline, for days where the grid is cloned or walked over a lot
+pub type FixedGrid<T, const N: usize> = Grid<T, ArrayCells<T, N>>;
+
+/// A rectangle of cells, most often a map parsed a line per row. The cells are in a `Vec` unless
+/// `C` says otherwise
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, C = Vec<T>> {
+    cells: C,
+    width: usize,
+    height: usize,
+    cell: PhantomData<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, cell: T) -> Self {
+        Self {
+            cells: vec![cell; width * height],
+            width,
+            height,
+            cell: PhantomData,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// The same grid keeping its cells in `C` instead, eg a [FixedGrid]
+    pub fn into_cells<C: Cells<T>>(self) -> Result<Grid<T, C>, AocError> {
+        let (width, height) = (self.width, self.height);
+        let cells = C::from_cells(self.cells).ok_or_else(|| {
+            AocError::parse(format!("a {width}x{height} grid has too many cells to fit"))
+        })?;
+        Ok(Grid {
+            cells,
+            width,
+            height,
+            cell: PhantomData,
+        })
+    }
+}
+
+impl<T, C: Cells<T>> Grid<T, C> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells.as_ref()[pos.row * self.width + pos.column])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells.as_mut()[pos.row * self.width + pos.column])
+    }
+
+    /// Where `pos` is counting cells a row at a time, as in [Grid::positions], eg to number the
+    /// nodes of a [Graph](crate::graph::Graph) built from the grid
+    pub fn cell_index(&self, pos: Pos) -> usize {
+        pos.row * self.width + pos.column
+    }
+
+    /// One step from `pos` in `direction`, if that's still in the grid
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        pos.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// The cells above, below, left and right of `pos` that are in the grid
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks can't be empty, an empty grid has no cells to chunk anyway
+        self.cells.as_ref().chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.as_mut().chunks_mut(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Pos::new(row, column)))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.as_ref().iter()
+    }
+
+    /// Every cell along with where it is, a row at a time
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.as_ref().iter())
+    }
+
+    /// Where the first cell (reading a row at a time) that matches `predicate` is
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// A grid the same size with `f` of each cell, kept in a `Vec`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.values().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            cell: PhantomData,
+        }
+    }
+
+    /// Swap the rows for the columns, so the left column becomes the top row
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|column| {
+            (0..self.height).map(move |row| self[Pos::new(row, column)].clone())
+        });
+        self.rearranged(cells)
+    }
+
+    /// Turn the grid a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|column| {
+            (0..self.height)
+                .rev()
+                .map(move |row| self[Pos::new(row, column)].clone())
+        });
+        self.rearranged(cells)
+    }
+
+    /// Turn the grid a quarter turn counter clockwise, so the right column becomes the top row
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).rev().flat_map(|column| {
+            (0..self.height).map(move |row| self[Pos::new(row, column)].clone())
+        });
+        self.rearranged(cells)
+    }
+
+    /// The same cells in a different order, with the width and height swapped
+    fn rearranged(&self, cells: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            cells: C::from_cells(cells).expect("the same number of cells still fit"),
+            width: self.height,
+            height: self.width,
+            cell: PhantomData,
+        }
+    }
+}
+
+impl<T, C: Cells<T>> Default for Grid<T, C> {
+    fn default() -> Self {
+        Self {
+            cells: C::from_cells([]).expect("no cells always fit"),
+            width: 0,
+            height: 0,
+            cell: PhantomData,
+        }
+    }
+}
+
+impl<T, C: Cells<T>> Index<Pos> for Grid<T, C> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T, C: Cells<T>> IndexMut<Pos> for Grid<T, C> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {width}x{height} grid"))
+    }
+}
+
+/// Rows have to all be the same length
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = AocError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = rows.len();
+        let width = rows.first().map(Vec::len).unwrap_or_default();
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(AocError::parse(format!(
+                "row {} is {} long but the first row is {width}",
+                row + 1,
+                rows[row].len()
+            )));
+        }
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+            cell: PhantomData,
+        })
+    }
+}
+
+/// Each cell's own `Display`, a line per row
+impl<T: Display, C: Cells<T>> Display for Grid<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::{char_grid, finish};
+
+    fn digits(input: &str) -> Grid<u32> {
+        finish(char_grid(|c| c.to_digit(10)), input).unwrap()
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_ne!(
+                direction.is_vertical(),
+                direction.turn_right().is_vertical()
+            );
+            let (rows, columns) = direction.delta();
+            assert_eq!(direction.opposite().delta(), (-rows, -columns));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+    }
+
+    #[test]
+    fn test_step() {
+        let pos = Pos::new(1, 1);
+        assert_eq!(pos.step(Direction::Up), Some(Pos::new(0, 1)));
+        assert_eq!(pos.step(Direction::Right), Some(Pos::new(1, 2)));
+        assert_eq!(Pos::new(0, 1).step(Direction::Up), None);
+        assert_eq!(Pos::new(1, 0).step(Direction::Left), None);
+        assert_eq!(pos.distance(Pos::new(3, 0)), 3);
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(1, 0)], 4);
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.position(|n| *n == 6), Some(Pos::new(1, 2)));
+        assert_eq!(grid.to_string(), "123\n456");
+        assert!(Grid::try_from(vec![vec![1, 2, 3], vec![4, 5]]).is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(
+            grid.neighbours(Pos::new(1, 1)).collect::<Vec<_>>(),
+            vec![
+                Pos::new(0, 1),
+                Pos::new(2, 1),
+                Pos::new(1, 0),
+                Pos::new(1, 2)
+            ]
+        );
+        assert_eq!(
+            grid.neighbours(Pos::new(2, 2)).collect::<Vec<_>>(),
+            vec![Pos::new(1, 2), Pos::new(2, 1)]
+        );
+        assert_eq!(grid.step(Pos::new(0, 0), Direction::Up), None);
+        assert_eq!(grid.step(Pos::new(0, 2), Direction::Right), None);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+    }
+
+    #[test]
+    fn test_fixed_grid() {
+        let grid: FixedGrid<u32, 8> = digits("123\n456").into_cells().unwrap();
+        assert_eq!(grid[Pos::new(1, 2)], 6);
+        assert_eq!(grid.values().count(), 6);
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid, digits("123\n456").into_cells().unwrap());
+        assert!(digits("123\n456\n789")
+            .into_cells::<ArrayCells<_, 8>>()
+            .is_err());
+    }
+}
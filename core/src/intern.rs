@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+/// A label that's been through an [Interner], cheap to copy, compare and hash. Ids count up from 0
+/// in the order labels were first seen, so they can index a `Vec` too
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u32);
+
+impl Id {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// The id with the given [index](Id::index), eg for a node of a [Graph](crate::graph::Graph)
+    /// numbered by id
+    pub fn from_index(index: usize) -> Self {
+        Id(index
+            .try_into()
+            .expect("there shouldn't be more than u32::MAX labels"))
+    }
+}
+
+/// Hands out an [Id] for each different label, so days that send things between nodes by name can
+/// look them up by number rather than by string
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, Id>,
+    labels: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The label's id, giving it the next one if it hasn't been seen before
+    pub fn intern(&mut self, label: &'a str) -> Id {
+        if let Some(id) = self.ids.get(label) {
+            return *id;
+        }
+        let id = Id(self
+            .labels
+            .len()
+            .try_into()
+            .expect("there shouldn't be more than u32::MAX labels"));
+        self.ids.insert(label, id);
+        self.labels.push(label);
+        id
+    }
+
+    /// The label's id, if it's been interned
+    pub fn get(&self, label: &str) -> Option<Id> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: Id) -> &'a str {
+        self.labels[id.index()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Every id handed out, in order
+    pub fn ids(&self) -> impl Iterator<Item = Id> {
+        (0..self.labels.len() as u32).map(Id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut labels = Interner::new();
+        let aaa = labels.intern("AAA");
+        let bbb = labels.intern("BBB");
+        assert_ne!(aaa, bbb);
+        assert_eq!(labels.intern("AAA"), aaa);
+        assert_eq!((aaa.index(), bbb.index()), (0, 1));
+        assert_eq!(Id::from_index(1), bbb);
+        assert_eq!(labels.get("BBB"), Some(bbb));
+        assert_eq!(labels.get("CCC"), None);
+        assert_eq!(labels.label(bbb), "BBB");
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels.ids().collect::<Vec<_>>(), vec![aaa, bbb]);
+    }
+}
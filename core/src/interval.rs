@@ -0,0 +1,141 @@
+use std::cmp::{max, min};
+
+/// The numbers from `start` up to but not including `end`. Like a `Range`, but `Copy` and with
+/// the splitting and joining that days working on whole ranges of numbers at once need
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Interval {
+    pub const fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+
+    /// The numbers from `first` to `last`, including `last`, which is how puzzles tend to put it
+    pub const fn inclusive(first: u64, last: u64) -> Self {
+        Self::new(first, last + 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn contains(&self, number: u64) -> bool {
+        self.start <= number && number < self.end
+    }
+
+    /// The numbers below `at` and the numbers from `at` on, either of which might be nothing
+    pub fn split_at(&self, at: u64) -> (Option<Interval>, Option<Interval>) {
+        let below = Self::new(self.start, min(at, self.end));
+        let above = Self::new(max(at, self.start), self.end);
+        (below.non_empty(), above.non_empty())
+    }
+
+    /// The numbers in both, if there are any
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        Self::new(max(self.start, other.start), min(self.end, other.end)).non_empty()
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// The numbers in either, if that's a single interval, ie they overlap or one starts where the
+    /// other ends
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        (self.start <= other.end && other.start <= self.end)
+            .then(|| Self::new(min(self.start, other.start), max(self.end, other.end)))
+    }
+
+    /// The same numbers as all of `intervals`, in as few intervals as they'll go in, in order
+    pub fn merge(intervals: impl IntoIterator<Item = Interval>) -> Vec<Interval> {
+        let mut intervals: Vec<_> = intervals.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort();
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            if let Some(last) = merged.last_mut() {
+                if let Some(union) = last.union(&interval) {
+                    *last = union;
+                    continue;
+                }
+            }
+            merged.push(interval);
+        }
+        merged
+    }
+
+    fn non_empty(self) -> Option<Interval> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::inclusive(2, 5);
+        assert_eq!(interval, Interval::new(2, 6));
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(5));
+        assert!(!interval.contains(6));
+        assert!(Interval::new(3, 3).is_empty());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let interval = Interval::new(2, 6);
+        assert_eq!(
+            interval.split_at(4),
+            (Some(Interval::new(2, 4)), Some(Interval::new(4, 6)))
+        );
+        assert_eq!(interval.split_at(2), (None, Some(interval)));
+        assert_eq!(interval.split_at(0), (None, Some(interval)));
+        assert_eq!(interval.split_at(6), (Some(interval), None));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let interval = Interval::new(2, 6);
+        assert_eq!(
+            interval.intersect(&Interval::new(4, 10)),
+            Some(Interval::new(4, 6))
+        );
+        assert_eq!(
+            interval.intersect(&Interval::new(3, 4)),
+            Some(Interval::new(3, 4))
+        );
+        assert_eq!(interval.intersect(&Interval::new(6, 10)), None);
+        assert!(!interval.overlaps(&Interval::new(0, 2)));
+    }
+
+    #[test]
+    fn test_union() {
+        let interval = Interval::new(2, 6);
+        assert_eq!(
+            interval.union(&Interval::new(6, 8)),
+            Some(Interval::new(2, 8))
+        );
+        assert_eq!(
+            interval.union(&Interval::new(0, 3)),
+            Some(Interval::new(0, 6))
+        );
+        assert_eq!(interval.union(&Interval::new(7, 8)), None);
+        assert_eq!(
+            Interval::merge([
+                Interval::new(7, 8),
+                Interval::new(0, 3),
+                Interval::new(5, 5),
+                Interval::new(2, 6)
+            ]),
+            vec![Interval::new(0, 6), Interval::new(7, 8)]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Solutions to [Advent of Code 2023](https://adventofcode.com/2023), each day in its own module with
+//! a `part1` and `part2` taking the puzzle input and returning the answer. [solve] picks the right
+//! one for a day and part.
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::answer::Answer;
+use crate::registry::{get_solver, Status};
+
+pub mod answer;
+pub mod config;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod error;
+pub mod examples;
+pub mod graph;
+pub mod grid;
+pub mod intern;
+pub mod interval;
+pub mod maths;
+pub mod memo;
+pub mod parse;
+pub mod phases;
+pub mod plugin;
+pub mod progress;
+pub mod registry;
+pub mod report;
+pub mod solver;
+#[cfg(test)]
+mod testing;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PuzzleError {
+    #[error("there's no day {0} part {1}")]
+    NotFound(u8, u8),
+    #[error("day {0} part {1} isn't implemented yet")]
+    Unimplemented(u8, u8),
+}
+
+/// Solve a day and part of the puzzle for the given input
+pub fn solve(day: u8, part: u8, input: &str) -> Result<Answer> {
+    let solver = get_solver(day.into(), part.into()).ok_or(PuzzleError::NotFound(day, part))?;
+    if solver.status == Status::Todo {
+        return Err(PuzzleError::Unimplemented(day, part).into());
+    }
+    Ok(solver.solve.call(input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(1, 1, examples::get_example(1, 1).unwrap().input).unwrap(),
+            "142"
+        );
+        assert_eq!(
+            solve(26, 1, "")
+                .unwrap_err()
+                .downcast::<PuzzleError>()
+                .unwrap(),
+            PuzzleError::NotFound(26, 1)
+        );
+        assert_eq!(
+            solve(25, 2, "")
+                .unwrap_err()
+                .downcast::<PuzzleError>()
+                .unwrap(),
+            PuzzleError::Unimplemented(25, 2)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use num::integer::{gcd, lcm};
+use num::Integer;
+
+/// The largest number that divides all of `numbers`, or `None` if there aren't any
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().reduce(gcd)
+}
+
+/// The smallest number all of `numbers` divide, eg when things on different cycles next line up,
+/// or `None` if there aren't any
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().reduce(lcm)
+}
+
+/// The greatest common divisor of `a` and `b`, along with `x` and `y` where `a * x + b * y` is it
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - (a / b) * y)
+    }
+}
+
+/// The smallest number that leaves each `(remainder, modulus)` pair's remainder when divided by its
+/// modulus, along with the modulus it repeats on. Things on cycles that don't all start at zero
+/// line up at that number, then every modulus after. The moduli have to be positive, but needn't
+/// be coprime, and if the remainders can't all be had at once there's `None`
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences.into_iter().try_fold(
+        (0, 1),
+        |(remainder, modulus), (next_remainder, next_modulus)| {
+            let (gcd, x, _) = extended_gcd(modulus, next_modulus);
+            let difference = next_remainder.rem_euclid(next_modulus) - remainder;
+            if difference % gcd != 0 {
+                return None;
+            }
+            let combined = modulus / gcd * next_modulus;
+            // How many `modulus` to add to `remainder` to get the next remainder too
+            let steps =
+                i128::from(difference / gcd) * i128::from(x) % i128::from(next_modulus / gcd);
+            let remainder =
+                (i128::from(remainder) + i128::from(modulus) * steps).rem_euclid(combined.into());
+            Some((remainder as i64, combined))
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm_all() {
+        assert_eq!(gcd_all([12, 18, 24]), Some(6));
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all::<u64>([]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(7, 0), (7, 1, 0));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+}
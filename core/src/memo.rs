@@ -0,0 +1,114 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Remembers values worked out for a key so they don't need working out again. A bounded memo
+/// forgets the oldest key once it's full, for when there are too many keys to keep them all
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A memo that holds at most `capacity` values, which has to be at least one
+    pub fn bounded(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a bounded memo has to hold at least one value"
+        );
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Remember the value for the key, forgetting the oldest one if the memo is full
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.values.insert(key.clone(), value).is_some() {
+            return;
+        }
+        self.order.push_back(key);
+        if self
+            .capacity
+            .is_some_and(|capacity| self.order.len() > capacity)
+        {
+            if let Some(oldest) = self.order.pop_front() {
+                self.values.remove(&oldest);
+            }
+        }
+    }
+
+    /// The remembered value for the key, or work it out and remember it. Recursive functions can't
+    /// hold the memo while `work_out` runs, so they [get](Self::get) and [insert](Self::insert)
+    /// instead
+    pub fn get_or_insert_with(&mut self, key: K, work_out: impl FnOnce() -> V) -> &V {
+        if !self.values.contains_key(&key) {
+            let value = work_out();
+            self.insert(key.clone(), value);
+        }
+        &self.values[&key]
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        for _ in 0..3 {
+            let value = memo.get_or_insert_with(2, || {
+                calls += 1;
+                4
+            });
+            assert_eq!(value, &4);
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(memo.get(&2), Some(&4));
+        assert_eq!(memo.get(&3), None);
+        assert_eq!(memo.len(), 1);
+    }
+
+    #[test]
+    fn test_bounded() {
+        let mut memo = Memo::bounded(2);
+        memo.insert('a', 1);
+        memo.insert('b', 2);
+        memo.insert('a', 3);
+        assert_eq!(memo.get(&'a'), Some(&3));
+        memo.insert('c', 4);
+        assert_eq!(memo.get(&'a'), None);
+        assert_eq!(memo.get(&'b'), Some(&2));
+        assert_eq!(memo.get(&'c'), Some(&4));
+        assert_eq!(memo.len(), 2);
+    }
+}
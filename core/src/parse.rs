@@ -0,0 +1,118 @@
+//! nom parsers for the shapes of input that turn up on more than one day
+
+use nom::character::complete::{anychar, multispace0, newline, space0, space1};
+use nom::combinator::{all_consuming, map_opt, map_res, value};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+
+use crate::error::AocError;
+use crate::grid::Grid;
+
+/// The empty line between two sections of the input, spaces on it are fine
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    value((), tuple((newline, space0, newline)))(input)
+}
+
+/// Parse each of the sections of the input, which are separated by blank lines
+pub fn sections<'a, O>(
+    section: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, section)
+}
+
+/// Numbers separated by spaces, however many, eg `numbers(complete::u32)` for `41 48  6 17`
+pub fn numbers<'a, O>(
+    number: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(space1, number)
+}
+
+/// A line of `cell`s for each row of the grid
+pub fn grid<'a, T>(
+    cell: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    map_res(separated_list1(newline, many1(cell)), Grid::try_from)
+}
+
+/// A grid with a character per cell, `cell` turning each one into what's there or `None` if it
+/// can't be in the grid
+pub fn char_grid<'a, T>(
+    cell: impl Fn(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    grid(map_opt(anychar, cell))
+}
+
+/// A `line` on each line of the input
+pub fn lines<'a, O>(
+    line: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(newline, line)
+}
+
+/// Parse the whole input with `parser`. Trailing newlines are fine, anything else left over is an
+/// error rather than being quietly ignored. Errors say which line and column parsing failed at
+pub fn finish<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    input: &'a str,
+) -> Result<O, AocError> {
+    let (_, output) = all_consuming(terminated(parser, multispace0))(input)
+        .map_err(|error| AocError::parse_at(input, error))?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete;
+
+    #[test]
+    fn test_sections() {
+        let input = "1 2\n3\n\n4\n \n5 6";
+        let lines = separated_list1(newline, numbers(complete::u32));
+        assert_eq!(
+            finish(sections(lines), input),
+            Ok(vec![
+                vec![vec![1, 2], vec![3]],
+                vec![vec![4]],
+                vec![vec![5, 6]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers(complete::i64)("41 -48  6 | 17"),
+            Ok((" | 17", vec![41, -48, 6]))
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let result = finish(lines(numbers(complete::u32)), "1 2\n3\n4 x");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            r#"couldn't parse the input: Eof failed at line 3 column 3, "x""#
+        );
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let digits = |input| finish(char_grid(|c| c.to_digit(10)), input);
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert!(digits("123\n45").is_err());
+        assert!(digits("123\n4a6").is_err());
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish(complete::u32, "42\n\n"), Ok(42));
+        assert_eq!(
+            finish(complete::u32, "42\n43").unwrap_err().to_string(),
+            r#"couldn't parse the input: Eof failed at line 2 column 1, "43""#
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+/// How long a run spent parsing its input, and how long solving from that
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl PhaseTimes {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
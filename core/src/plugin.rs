@@ -0,0 +1,246 @@
+use std::ffi::{c_char, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::slice;
+
+use thiserror::Error;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::registry::{Implementation, Solution};
+
+/// Bumped whenever anything below changes, so a plugin built against another version is turned
+/// away rather than called wrongly
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// The name of the [PluginEntryPoint] a plugin exports
+pub const PLUGIN_ENTRY_POINT: &str = "aoc_plugin_solvers";
+
+/// How many bytes a plugin's solver has to write its answer or error into, anything longer is cut
+/// short
+pub const PLUGIN_OUTPUT_CAPACITY: usize = 4096;
+
+/// A plugin's solver. It's given the input as UTF-8, writes the answer (or what went wrong) as
+/// UTF-8 into `output`, up to `output_capacity` bytes, sets `output_len` to how many it wrote, and
+/// returns whether it's an answer rather than an error
+pub type PluginSolve = unsafe extern "C" fn(
+    input: *const u8,
+    input_len: usize,
+    output: *mut u8,
+    output_capacity: usize,
+    output_len: *mut usize,
+) -> bool;
+
+/// One of a plugin's solvers, which day and part it's for and what to call it with `--impl`
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PluginSolver {
+    pub day: u32,
+    pub part: u32,
+    /// A nul terminated UTF-8 name
+    pub name: *const c_char,
+    pub solve: PluginSolve,
+}
+
+/// The names plugins give are static strings, so the table of solvers can be too
+unsafe impl Sync for PluginSolver {}
+
+/// What a plugin exports as [PLUGIN_ENTRY_POINT]. Given the [PLUGIN_ABI_VERSION] the runner was
+/// built with, it sets `len` and returns its solvers, or returns null if it was built for another
+pub type PluginEntryPoint =
+    unsafe extern "C" fn(abi_version: u32, len: *mut usize) -> *const PluginSolver;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PluginError {
+    #[error("the plugin was built for a different version of the runner")]
+    AbiVersion,
+    #[error("a solver's name isn't UTF-8")]
+    Name,
+    #[error("there's no day {0} part {1}")]
+    NotFound(usize, usize),
+    #[error("day {0} part {1} already has an implementation called {2}")]
+    Duplicate(usize, usize, String),
+    #[error("plugins have already been added")]
+    AlreadyAdded,
+}
+
+/// Every solver a plugin has, as implementations to run alongside the built in ones
+///
+/// # Safety
+///
+/// `entry_point` has to follow [PluginEntryPoint], and the library it's from has to stay loaded
+/// for as long as the implementations are used. Their names are copied, and leaked to live as long
+pub unsafe fn plugin_implementations(
+    entry_point: PluginEntryPoint,
+) -> Result<Vec<Implementation>, PluginError> {
+    let mut len = 0;
+    let solvers = entry_point(PLUGIN_ABI_VERSION, &mut len);
+    if solvers.is_null() {
+        return Err(PluginError::AbiVersion);
+    }
+    slice::from_raw_parts(solvers, len)
+        .iter()
+        .map(|solver| {
+            let name = CStr::from_ptr(solver.name)
+                .to_str()
+                .map_err(|_| PluginError::Name)?;
+            let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+            Ok(
+                Implementation::from_plugin(
+                    solver.day as usize,
+                    solver.part as usize,
+                    solver.solve,
+                )
+                .named(name),
+            )
+        })
+        .collect()
+}
+
+/// Run a plugin's solver, reading back what it wrote as the answer
+pub fn call_plugin(solve: PluginSolve, input: &str) -> Result<Answer, AocError> {
+    let mut output = vec![0; PLUGIN_OUTPUT_CAPACITY];
+    let mut len = 0;
+    // The plugin promises to stay inside the buffers it's given
+    let solved = unsafe {
+        solve(
+            input.as_ptr(),
+            input.len(),
+            output.as_mut_ptr(),
+            output.len(),
+            &mut len,
+        )
+    };
+    output.truncate(len);
+    let output = String::from_utf8_lossy(&output);
+    if solved {
+        // Numbers are read back as numbers, the same as answers read from a file
+        let Ok(answer) = output.parse();
+        Ok(answer)
+    } else {
+        Err(AocError::Plugin(output.into_owned()))
+    }
+}
+
+/// The plugin's side of [PluginSolve], running `solution` and writing what it gives to `output`.
+/// A panic is written as an error, it can't unwind into the runner
+///
+/// # Safety
+///
+/// The pointers have to be what [PluginSolve] says they are
+pub unsafe fn plugin_solve(
+    solution: Solution,
+    input: *const u8,
+    input_len: usize,
+    output: *mut u8,
+    output_capacity: usize,
+    output_len: *mut usize,
+) -> bool {
+    let input = String::from_utf8_lossy(slice::from_raw_parts(input, input_len));
+    let (solved, text) = match catch_unwind(AssertUnwindSafe(|| solution(&input))) {
+        Ok(Ok(answer)) => (true, answer.to_string()),
+        Ok(Err(error)) => (false, error.to_string()),
+        Err(_) => (false, "the solver panicked".to_string()),
+    };
+    let written = text.len().min(output_capacity);
+    slice::from_raw_parts_mut(output, written).copy_from_slice(&text.as_bytes()[..written]);
+    *output_len = written;
+    solved
+}
+
+/// Export solvers from a `cdylib` crate for the runner to load with `--plugin`, each given as the
+/// day and part it's for, the name to pick it with `--impl`, and a function solving it like any
+/// built in one, eg:
+///
+/// ```ignore
+/// advent_of_code_2024::plugin! {
+///     (5, 2, "faster") => part2_faster,
+/// }
+/// ```
+#[macro_export]
+macro_rules! plugin {
+    ($(($day:literal, $part:literal, $name:literal) => $solve:path),+ $(,)?) => {
+        /// # Safety
+        ///
+        /// Only to be called by the runner, as a [PluginEntryPoint]($crate::plugin::PluginEntryPoint)
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_solvers(
+            abi_version: u32,
+            len: *mut usize,
+        ) -> *const $crate::plugin::PluginSolver {
+            static SOLVERS: &[$crate::plugin::PluginSolver] = &[$(
+                $crate::plugin::PluginSolver {
+                    day: $day,
+                    part: $part,
+                    name: concat!($name, "\0").as_ptr().cast(),
+                    solve: {
+                        unsafe extern "C" fn solve(
+                            input: *const u8,
+                            input_len: usize,
+                            output: *mut u8,
+                            output_capacity: usize,
+                            output_len: *mut usize,
+                        ) -> bool {
+                            $crate::plugin::plugin_solve(
+                                $solve,
+                                input,
+                                input_len,
+                                output,
+                                output_capacity,
+                                output_len,
+                            )
+                        }
+                        solve
+                    },
+                }
+            ),+];
+            if abi_version != $crate::plugin::PLUGIN_ABI_VERSION {
+                return ::std::ptr::null();
+            }
+            *len = SOLVERS.len();
+            SOLVERS.as_ptr()
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn shout(input: &str) -> Result<Answer, AocError> {
+        match input {
+            "" => Err(AocError::no_answer("nothing to shout")),
+            input => Ok(input.to_uppercase().into()),
+        }
+    }
+
+    fn count(input: &str) -> Result<Answer, AocError> {
+        Ok(input.len().into())
+    }
+
+    crate::plugin! {
+        (1, 1, "shout") => shout,
+        (1, 2, "count") => count,
+    }
+
+    #[test]
+    fn test_plugin_implementations() {
+        let implementations = unsafe { plugin_implementations(aoc_plugin_solvers) }.unwrap();
+        let names: Vec<_> = implementations
+            .iter()
+            .map(|implementation| (implementation.day, implementation.part, implementation.name))
+            .collect();
+        assert_eq!(names, vec![(1, 1, "shout"), (1, 2, "count")]);
+        assert_eq!(implementations[0].solve.call("abc").unwrap(), "ABC");
+        assert_eq!(implementations[1].solve.call("abc").unwrap(), "3");
+        assert_eq!(
+            implementations[0].solve.call(""),
+            Err(AocError::Plugin("no answer: nothing to shout".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_abi_version() {
+        let mut len = 0;
+        assert!(unsafe { aoc_plugin_solvers(PLUGIN_ABI_VERSION + 1, &mut len) }.is_null());
+    }
+}
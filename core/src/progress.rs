@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::AocError;
+
+/// Lets a long running solver report how far through it is without knowing how it's displayed, and
+/// find out whether whoever's running it has stopped waiting
+pub trait Progress: Sync {
+    /// Set how many steps the solver expects to take in total
+    fn set_length(&self, length: u64);
+
+    /// Mark another `delta` steps as done
+    fn inc(&self, delta: u64);
+
+    /// Whether the solver should give up rather than carry on
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+
+    /// For checking in a solver's long loops, stopping it with [AocError::Cancelled]
+    fn check_cancelled(&self) -> Result<(), AocError> {
+        if self.is_cancelled() {
+            Err(AocError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// For when nobody is watching, eg in tests
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn set_length(&self, _length: u64) {}
+
+    fn inc(&self, _delta: u64) {}
+}
+
+/// Shared between a solver and whoever's running it, so they can ask it to stop. A thread can't be
+/// stopped from outside, so this is the only way to get one back after a timeout
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every solver given a clone of this to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Some other [Progress] that can also be cancelled with a [CancelToken]
+pub struct Cancellable<'a> {
+    progress: &'a dyn Progress,
+    token: CancelToken,
+}
+
+impl<'a> Cancellable<'a> {
+    pub fn new(progress: &'a dyn Progress, token: CancelToken) -> Self {
+        Self { progress, token }
+    }
+}
+
+impl Progress for Cancellable<'_> {
+    fn set_length(&self, length: u64) {
+        self.progress.set_length(length);
+    }
+
+    fn inc(&self, delta: u64) {
+        self.progress.inc(delta);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled() || self.progress.is_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cancellable() {
+        let token = CancelToken::new();
+        let progress = Cancellable::new(&NoProgress, token.clone());
+        assert_eq!(progress.check_cancelled(), Ok(()));
+        token.cancel();
+        assert!(progress.is_cancelled());
+        assert_eq!(progress.check_cancelled(), Err(AocError::Cancelled));
+    }
+}
@@ -0,0 +1,386 @@
+use std::fmt::{Display, Formatter};
+
+use std::sync::OnceLock;
+
+use linkme::distributed_slice;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::examples::DayExample;
+use crate::plugin::{call_plugin, PluginError, PluginSolve};
+use crate::progress::Progress;
+use crate::solver::Solver;
+use Status::*;
+
+pub type Solution = fn(&str) -> Result<Answer, AocError>;
+pub type ProgressSolution = fn(&str, &dyn Progress) -> Result<Answer, AocError>;
+
+/// What runs an implementation, a function in this crate or one loaded from a plugin
+#[derive(Debug, Copy, Clone)]
+pub enum Solve {
+    BuiltIn(Solution),
+    Plugin(PluginSolve),
+}
+
+impl Solve {
+    pub fn call(self, input: &str) -> Result<Answer, AocError> {
+        match self {
+            Solve::BuiltIn(solution) => solution(input),
+            Solve::Plugin(solve) => call_plugin(solve, input),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Status {
+    /// Gives the right answer
+    Solved,
+    /// Has an attempt, but it gives the wrong answer or won't finish on a real input
+    Incomplete,
+    /// Still `todo!()`
+    Todo,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solved => f.pad("solved"),
+            Incomplete => f.pad("incomplete"),
+            Todo => f.pad("todo"),
+        }
+    }
+}
+
+/// What the main solver for each day and part is called when picking one with `--impl`
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+/// One way of solving a day and part
+#[derive(Debug, Copy, Clone)]
+pub struct Implementation {
+    pub day: usize,
+    pub part: usize,
+    /// Which implementation this is, to tell alternatives apart
+    pub name: &'static str,
+    pub solve: Solve,
+    /// The same solution, but reporting how far it's got, for those slow enough to need it
+    pub solve_with_progress: Option<ProgressSolution>,
+    pub status: Status,
+}
+
+impl Implementation {
+    pub const fn new(day: usize, part: usize, solve: Solution, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            name: DEFAULT_IMPLEMENTATION,
+            solve: Solve::BuiltIn(solve),
+            solve_with_progress: None,
+            status,
+        }
+    }
+
+    /// One of a plugin's solvers, which can't be trusted to be right until it's been compared
+    /// with a built in one
+    pub const fn from_plugin(day: usize, part: usize, solve: PluginSolve) -> Self {
+        Self {
+            day,
+            part,
+            name: DEFAULT_IMPLEMENTATION,
+            solve: Solve::Plugin(solve),
+            solve_with_progress: None,
+            status: Incomplete,
+        }
+    }
+
+    pub const fn with_progress(mut self, solve_with_progress: ProgressSolution) -> Self {
+        self.solve_with_progress = Some(solve_with_progress);
+        self
+    }
+
+    pub const fn named(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+}
+
+/// What there is to know about a day other than how to solve it
+#[derive(Debug, Copy, Clone)]
+pub struct DayInfo {
+    /// The puzzle's title on adventofcode.com
+    pub title: &'static str,
+    /// The examples from the puzzle description
+    pub examples: &'static [DayExample],
+}
+
+/// A day's solver, what's known about it, and every implementation of its parts, the main ones
+/// first. Each day module adds its own to [DAYS], so a new day only needs its `pub mod` line in
+/// `lib.rs`
+pub struct Registration {
+    pub solver: &'static dyn Solver,
+    pub info: DayInfo,
+    pub implementations: &'static [Implementation],
+}
+
+/// Register a day's solver, the examples from its puzzle description along with their answers, and
+/// the functions that solve its parts, and test every solved one against those examples, eg:
+///
+/// ```ignore
+/// solution! {
+///     Day04 {
+///         "d04.txt" => part1 "13", part2 "30";
+///     }
+///     part1(1, Solved),
+///     part2(2, Solved),
+///     part2_queue(2, Solved).named("queue"),
+/// }
+/// ```
+///
+/// Examples are files in `src/examples`, given the answer for whichever parts they're an example
+/// for. Each function is given its part and [Status], then any of the [Implementation] builders,
+/// the main implementation of each part coming first
+#[macro_export]
+macro_rules! solution {
+    (
+        $solver:ident $({
+            $($file:literal => $($example_part:ident $answer:literal),+;)*
+        })? $(;)?
+        $(
+            $solve:ident($part:literal, $status:ident)
+            $(.$builder:ident($($argument:expr),*))*
+        ),+ $(,)?
+    ) => {
+        #[::linkme::distributed_slice($crate::registry::DAYS)]
+        static REGISTRATION: $crate::registry::Registration = $crate::registry::Registration {
+            solver: &$solver,
+            info: $crate::registry::DayInfo {
+                title: <$solver as $crate::solver::Day>::TITLE,
+                examples: &[$($(
+                    $crate::examples::DayExample::new(
+                        concat!("core/src/examples/", $file),
+                        include_str!(concat!("examples/", $file)),
+                    )
+                    $(.$example_part($answer))+
+                ),*)?],
+            },
+            implementations: &[$(
+                $crate::registry::Implementation::new(
+                    <$solver as $crate::solver::Day>::DAY,
+                    $part,
+                    $solve,
+                    $crate::registry::Status::$status,
+                )
+                $(.$builder($($argument),*))*
+            ),+],
+        };
+
+        #[cfg(test)]
+        mod example_test {
+            /// Every solved implementation gets the answer to every example for its part
+            #[test]
+            fn examples() {
+                let implementations = super::REGISTRATION.implementations.iter();
+                for implementation in implementations.filter(|implementation| {
+                    implementation.status == $crate::registry::Status::Solved
+                }) {
+                    for example in super::REGISTRATION.info.examples {
+                        let Some(answer) = example.answer(implementation.part) else {
+                            continue;
+                        };
+                        let solved = implementation.solve.call(example.input).unwrap();
+                        if let Err(mismatch) = solved.compare(answer) {
+                            panic!(
+                                "part {} {} with {}: {mismatch}",
+                                implementation.part,
+                                implementation.name,
+                                example.path
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Every day's registration, in whatever order the linker put them in, see [registered] for them
+/// in order
+#[distributed_slice]
+pub static DAYS: [Registration];
+
+/// Every registered day, in order
+pub fn registered() -> &'static [&'static Registration] {
+    static REGISTERED: OnceLock<Vec<&'static Registration>> = OnceLock::new();
+    REGISTERED.get_or_init(|| {
+        let mut days: Vec<_> = DAYS.iter().collect();
+        days.sort_by_key(|registration| registration.solver.day());
+        days
+    })
+}
+
+static PLUGINS: OnceLock<Vec<Implementation>> = OnceLock::new();
+
+/// Add implementations loaded from plugins, found after the built in ones. They can only be
+/// added once, and each has to be for a day and part that's registered, under a new name
+pub fn add_plugins(plugins: Vec<Implementation>) -> Result<(), PluginError> {
+    for (index, plugin) in plugins.iter().enumerate() {
+        let (day, part) = (plugin.day, plugin.part);
+        if get_solver(day, part).is_none() {
+            return Err(PluginError::NotFound(day, part));
+        }
+        let taken = |existing: &Implementation| {
+            existing.day == day && existing.part == part && existing.name == plugin.name
+        };
+        if implementations(day, part).any(taken) || plugins[..index].iter().any(taken) {
+            return Err(PluginError::Duplicate(day, part, plugin.name.to_string()));
+        }
+    }
+    PLUGINS.set(plugins).map_err(|_| PluginError::AlreadyAdded)
+}
+
+fn all_implementations() -> impl Iterator<Item = &'static Implementation> {
+    registered()
+        .iter()
+        .flat_map(|registration| registration.implementations)
+        .chain(PLUGINS.get().into_iter().flatten())
+}
+
+/// The main solver for every day and part, in order
+pub fn solvers() -> impl Iterator<Item = &'static Implementation> {
+    all_implementations().filter(|solver| solver.name == DEFAULT_IMPLEMENTATION)
+}
+
+/// Other ways of solving some days and parts, kept to compare against the main ones
+pub fn alternatives() -> impl Iterator<Item = &'static Implementation> {
+    all_implementations().filter(|solver| solver.name != DEFAULT_IMPLEMENTATION)
+}
+
+pub fn get_day(day: usize) -> Option<&'static dyn Solver> {
+    DAYS.iter()
+        .map(|registration| registration.solver)
+        .find(|solver| solver.day() == day)
+}
+
+pub fn get_info(day: usize) -> Option<&'static DayInfo> {
+    DAYS.iter()
+        .find(|registration| registration.solver.day() == day)
+        .map(|registration| &registration.info)
+}
+
+pub fn get_solver(day: usize, part: usize) -> Option<&'static Implementation> {
+    solvers().find(|solver| solver.day == day && solver.part == part)
+}
+
+/// The main solver and any alternatives for a day and part
+pub fn implementations(day: usize, part: usize) -> impl Iterator<Item = &'static Implementation> {
+    all_implementations().filter(move |solver| solver.day == day && solver.part == part)
+}
+
+pub fn get_implementation(day: usize, part: usize, name: &str) -> Option<&'static Implementation> {
+    implementations(day, part).find(|solver| solver.name == name)
+}
+
+/// The solvers that produce a correct answer, the rest are still `todo!()` or won't finish
+pub fn solved() -> impl Iterator<Item = &'static Implementation> {
+    solvers().filter(|solver| solver.status == Solved)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solvers_cover_every_day_and_part() {
+        let days_and_parts: Vec<_> = solvers().map(|solver| (solver.day, solver.part)).collect();
+        let expected: Vec<_> = (1..=25).flat_map(|day| [(day, 1), (day, 2)]).collect();
+        assert_eq!(days_and_parts, expected);
+    }
+
+    #[test]
+    fn test_registrations_are_for_their_day() {
+        for registration in registered() {
+            let day = registration.solver.day();
+            assert!(registration
+                .implementations
+                .iter()
+                .all(|implementation| implementation.day == day));
+        }
+    }
+
+    #[test]
+    fn test_days_agree_with_parts() {
+        assert_eq!(DAYS.len(), 25);
+        for implementation in solved() {
+            let (day, part) = (implementation.day, implementation.part);
+            for example in crate::examples::examples(day, part) {
+                let (answer, _) = get_day(day).unwrap().solve(part, example.input).unwrap();
+                assert_eq!(
+                    answer, example.answer,
+                    "day {day} part {part} {}",
+                    example.path
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_solver() {
+        assert_eq!(get_solver(24, 2).unwrap().status, Todo);
+        assert_eq!(get_solver(18, 2).unwrap().status, Incomplete);
+        assert!(get_solver(26, 1).is_none());
+        assert!(get_solver(1, 3).is_none());
+    }
+
+    #[test]
+    fn test_get_implementation() {
+        assert!(std::ptr::eq(
+            get_implementation(4, 2, DEFAULT_IMPLEMENTATION).unwrap(),
+            get_solver(4, 2).unwrap()
+        ));
+        assert_eq!(get_implementation(4, 2, "queue").unwrap().name, "queue");
+        assert!(get_implementation(4, 1, "queue").is_none());
+        assert_eq!(
+            implementations(11, 1)
+                .map(|solver| solver.name)
+                .collect::<Vec<_>>(),
+            vec![DEFAULT_IMPLEMENTATION, "expanding"]
+        );
+    }
+
+    #[test]
+    fn test_add_plugins() {
+        unsafe extern "C" fn solve(
+            input: *const u8,
+            input_len: usize,
+            output: *mut u8,
+            output_capacity: usize,
+            output_len: *mut usize,
+        ) -> bool {
+            crate::plugin::plugin_solve(
+                crate::day01::part1,
+                input,
+                input_len,
+                output,
+                output_capacity,
+                output_len,
+            )
+        }
+        let plugin = |day, part, name| Implementation::from_plugin(day, part, solve).named(name);
+        assert_eq!(
+            add_plugins(vec![plugin(26, 1, "faster")]),
+            Err(PluginError::NotFound(26, 1))
+        );
+        assert_eq!(
+            add_plugins(vec![plugin(4, 2, "queue")]),
+            Err(PluginError::Duplicate(4, 2, "queue".to_string()))
+        );
+        assert_eq!(
+            add_plugins(vec![plugin(1, 1, "faster"), plugin(1, 1, "faster")]),
+            Err(PluginError::Duplicate(1, 1, "faster".to_string()))
+        );
+        assert_eq!(add_plugins(vec![plugin(1, 1, "faster")]), Ok(()));
+        let faster = get_implementation(1, 1, "faster").unwrap();
+        assert_eq!(faster.status, Incomplete);
+        assert_eq!(faster.solve.call("1abc2").unwrap(), "12");
+        assert_eq!(add_plugins(vec![]), Err(PluginError::AlreadyAdded));
+    }
+}
@@ -0,0 +1,354 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::answer::{Answer, Mismatch, Stats};
+use crate::phases::PhaseTimes;
+
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    /// Just the answer, for piping into something else
+    Raw,
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("unknown format {0}, expected text, json, csv or raw")]
+pub struct UnknownFormat(String);
+
+impl FromStr for Format {
+    type Err = UnknownFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "raw" => Ok(Format::Raw),
+            _ => Err(UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+fn serialize_nanos<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration.map(|d| d.as_nanos()).serialize(serializer)
+}
+
+/// The outcome of running a single day and part
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunReport {
+    pub day: usize,
+    pub part: usize,
+    pub input: PathBuf,
+    pub answer: Option<Answer>,
+    /// Anything the part noted on the way to its answer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    #[serde(rename = "time_ns", serialize_with = "serialize_nanos")]
+    pub duration: Option<Duration>,
+    #[serde(
+        rename = "parse_ns",
+        serialize_with = "serialize_nanos",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parse_duration: Option<Duration>,
+    #[serde(
+        rename = "solve_ns",
+        serialize_with = "serialize_nanos",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub solve_duration: Option<Duration>,
+    #[serde(rename = "peak_bytes", skip_serializing_if = "Option::is_none")]
+    pub peak_memory: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<bool>,
+    /// The answer came from the cache rather than being solved again, so there's no time for it
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+}
+
+impl RunReport {
+    pub fn solved(
+        day: usize,
+        part: usize,
+        input: PathBuf,
+        answer: Answer,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            input,
+            stats: answer.stats().cloned(),
+            answer: Some(answer.without_stats()),
+            duration: Some(duration),
+            parse_duration: None,
+            solve_duration: None,
+            peak_memory: None,
+            error: None,
+            expected: None,
+            correct: None,
+            cached: false,
+        }
+    }
+
+    pub fn failed(day: usize, part: usize, input: PathBuf, error: impl ToString) -> Self {
+        Self {
+            day,
+            part,
+            input,
+            answer: None,
+            stats: None,
+            duration: None,
+            parse_duration: None,
+            solve_duration: None,
+            peak_memory: None,
+            error: Some(error.to_string()),
+            expected: None,
+            correct: None,
+            cached: false,
+        }
+    }
+
+    /// An answer looked up in the cache instead of being solved
+    pub fn cached(day: usize, part: usize, input: PathBuf, answer: Answer) -> Self {
+        Self {
+            duration: None,
+            cached: true,
+            ..Self::solved(day, part, input, answer, Duration::ZERO)
+        }
+    }
+
+    /// Split the time taken into parsing and solving, for the days that can
+    pub fn with_phases(mut self, phases: Option<PhaseTimes>) -> Self {
+        self.parse_duration = phases.map(|phases| phases.parse);
+        self.solve_duration = phases.map(|phases| phases.solve);
+        self
+    }
+
+    pub fn with_peak_memory(mut self, peak_memory: Option<usize>) -> Self {
+        self.peak_memory = peak_memory;
+        self
+    }
+
+    /// Record the known good answer, if there is one, and whether this run matched it
+    pub fn check_against(mut self, expected: Option<String>) -> Self {
+        self.correct = expected
+            .as_ref()
+            .zip(self.answer.as_ref())
+            .map(|(expected, answer)| answer.matches(expected));
+        self.expected = expected;
+        self
+    }
+
+    /// How the answer differs from the known good one, if it was checked and doesn't match
+    pub fn mismatch(&self) -> Option<Mismatch> {
+        self.answer.as_ref()?.compare(self.expected.as_ref()?).err()
+    }
+
+    pub fn is_incorrect(&self) -> bool {
+        self.correct == Some(false)
+    }
+
+    fn csv_row(&self) -> String {
+        let nanos = |duration: Option<Duration>| {
+            duration
+                .map(|duration| duration.as_nanos().to_string())
+                .unwrap_or_default()
+        };
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            self.input.display().to_string(),
+            self.answer
+                .as_ref()
+                .map(|answer| answer.to_string())
+                .unwrap_or_default(),
+            nanos(self.parse_duration),
+            nanos(self.solve_duration),
+            nanos(self.duration),
+            self.peak_memory
+                .map(|bytes| bytes.to_string())
+                .unwrap_or_default(),
+            self.error.clone().unwrap_or_default(),
+            self.expected.clone().unwrap_or_default(),
+            self.correct
+                .map(|correct| correct.to_string())
+                .unwrap_or_default(),
+            self.stats
+                .iter()
+                .flatten()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>()
+                .join(";"),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+const CSV_HEADER: &str =
+    "day,part,input,answer,parse_ns,solve_ns,total_ns,peak_bytes,error,expected,correct,stats";
+
+/// Quote a field if it would otherwise break the row
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// A header line followed by a line for each report
+pub fn to_csv(reports: &[RunReport]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    for report in reports {
+        csv.push('\n');
+        csv.push_str(&report.csv_row());
+    }
+    csv
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("raw".parse(), Ok(Format::Raw));
+        assert_eq!(
+            "xml".parse::<Format>(),
+            Err(UnknownFormat("xml".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_serialize_solved() {
+        let report = RunReport::solved(
+            1,
+            2,
+            PathBuf::from("inputs/d01.txt"),
+            Answer::U64(281),
+            Duration::from_micros(15),
+        );
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":1,"part":2,"input":"inputs/d01.txt","answer":281,"time_ns":15000}"#
+        );
+
+        let report = report.with_phases(Some(PhaseTimes {
+            parse: Duration::from_micros(5),
+            solve: Duration::from_micros(10),
+        }));
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":1,"part":2,"input":"inputs/d01.txt","answer":281,"time_ns":15000,"parse_ns":5000,"solve_ns":10000}"#
+        );
+
+        let report = report.with_peak_memory(Some(2048));
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":1,"part":2,"input":"inputs/d01.txt","answer":281,"time_ns":15000,"parse_ns":5000,"solve_ns":10000,"peak_bytes":2048}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_stats() {
+        let answer = Answer::U64(64).with_stat("cycle_length", 7u64);
+        let report = RunReport::solved(14, 2, PathBuf::from("-"), answer, Duration::ZERO);
+        assert_eq!(report.answer, Some(Answer::U64(64)));
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":14,"part":2,"input":"-","answer":64,"stats":{"cycle_length":7},"time_ns":0}"#
+        );
+    }
+
+    #[test]
+    fn test_check_against() {
+        let report = RunReport::solved(
+            1,
+            1,
+            PathBuf::from("inputs/d01.txt"),
+            Answer::U64(142),
+            Duration::ZERO,
+        );
+        assert_eq!(report.clone().check_against(None).correct, None);
+        assert_eq!(
+            report
+                .clone()
+                .check_against(Some("142".to_string()))
+                .correct,
+            Some(true)
+        );
+        let report = report.check_against(Some("143".to_string()));
+        assert!(report.is_incorrect());
+        assert_eq!(
+            report.mismatch().unwrap().to_string(),
+            "expected 143, got 142 (1 too low)"
+        );
+
+        let report = RunReport::failed(1, 1, PathBuf::from("inputs/d01.txt"), "input not found");
+        assert_eq!(report.check_against(Some("142".to_string())).correct, None);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let reports = [
+            RunReport::solved(
+                1,
+                2,
+                PathBuf::from("inputs/d01.txt"),
+                Answer::U64(281)
+                    .with_stat("lines", 7u64)
+                    .with_stat("first", "two"),
+                Duration::from_micros(15),
+            )
+            .with_phases(Some(PhaseTimes {
+                parse: Duration::from_micros(5),
+                solve: Duration::from_micros(10),
+            }))
+            .with_peak_memory(Some(2048))
+            .check_against(Some("281".to_string())),
+            RunReport::failed(
+                3,
+                1,
+                PathBuf::from("inputs/d03.txt"),
+                "not found, \"sorry\"",
+            ),
+        ];
+        assert_eq!(
+            to_csv(&reports),
+            "day,part,input,answer,parse_ns,solve_ns,total_ns,peak_bytes,error,expected,correct,stats
+1,2,inputs/d01.txt,281,5000,10000,15000,2048,,281,true,first=two;lines=7
+3,1,inputs/d03.txt,,,,,,\"not found, \"\"sorry\"\"\",,,"
+        );
+    }
+
+    #[test]
+    fn test_serialize_failed() {
+        let report = RunReport::failed(3, 1, PathBuf::from("inputs/d03.txt"), "input not found");
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":3,"part":1,"input":"inputs/d03.txt","answer":null,"time_ns":null,"error":"input not found"}"#
+        );
+    }
+}
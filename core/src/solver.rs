@@ -0,0 +1,168 @@
+use std::time::Instant;
+
+use tracing::debug_span;
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::phases::PhaseTimes;
+use crate::progress::{NoProgress, Progress};
+
+/// A day of the puzzle, which parses its input into `Parsed` once and solves either part from that,
+/// so the runner can time the two phases separately
+pub trait Day {
+    const DAY: usize;
+    /// The puzzle's title on adventofcode.com
+    const TITLE: &'static str;
+
+    /// What the input is parsed into, days that haven't been split up yet just pass the input on
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError>;
+
+    /// [Day::part1], for days slow enough to report how far they've got and notice being cancelled
+    fn part1_with_progress(
+        parsed: &Self::Parsed<'_>,
+        _progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        Self::part1(parsed)
+    }
+
+    /// [Day::part2], for days slow enough to report how far they've got and notice being cancelled
+    fn part2_with_progress(
+        parsed: &Self::Parsed<'_>,
+        _progress: &dyn Progress,
+    ) -> Result<Answer, AocError> {
+        Self::part2(parsed)
+    }
+}
+
+/// A [Day] the runner can pick at run time, without knowing what it parses its input into
+pub trait Solver: Sync {
+    fn day(&self) -> usize;
+
+    /// Parse then solve `part`, timing each
+    fn solve(&self, part: usize, input: &str) -> Result<(Answer, PhaseTimes), AocError> {
+        let mut result = None;
+        self.solve_parts(&[part], input, &NoProgress, &mut |_, solved| {
+            result = Some(solved)
+        });
+        result.expect("every part asked for is solved")
+    }
+
+    /// Parse once, then solve each of `parts` from that, handing each answer to `solved` as soon as
+    /// it's ready. Each part's times include the one parse they share. If the input doesn't parse,
+    /// every part is given that error. Days that report their progress report it to `progress`, and
+    /// give up if it's cancelled
+    fn solve_parts(
+        &self,
+        parts: &[usize],
+        input: &str,
+        progress: &dyn Progress,
+        solved: &mut dyn FnMut(usize, Result<(Answer, PhaseTimes), AocError>),
+    );
+}
+
+impl<D: Day + Sync> Solver for D {
+    fn day(&self) -> usize {
+        D::DAY
+    }
+
+    fn solve_parts(
+        &self,
+        parts: &[usize],
+        input: &str,
+        progress: &dyn Progress,
+        solved: &mut dyn FnMut(usize, Result<(Answer, PhaseTimes), AocError>),
+    ) {
+        let start = Instant::now();
+        let parsed = debug_span!("parse").in_scope(|| D::parse(input));
+        let parse = start.elapsed();
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                for &part in parts {
+                    solved(part, Err(error.clone()));
+                }
+                return;
+            }
+        };
+        for &part in parts {
+            let start = Instant::now();
+            let answer = debug_span!("solve", part).in_scope(|| match part {
+                1 => D::part1_with_progress(&parsed, progress),
+                2 => D::part2_with_progress(&parsed, progress),
+                _ => panic!("there are only two parts, not {part}"),
+            });
+            let solve = start.elapsed();
+            solved(
+                part,
+                answer.map(|answer| (answer, PhaseTimes { parse, solve })),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Lengths;
+
+    impl Day for Lengths {
+        const DAY: usize = 0;
+        const TITLE: &'static str = "Lengths";
+
+        type Parsed<'a> = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError> {
+            Ok(input.lines().map(str::len).collect())
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+            Ok(parsed.iter().sum::<usize>().into())
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError> {
+            let longest = parsed
+                .iter()
+                .max()
+                .ok_or(AocError::no_answer("there are no lines"))?;
+            Ok((*longest).into())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let solver: &dyn Solver = &Lengths;
+        let (answer, times) = solver.solve(1, "ab\nabc").unwrap();
+        assert_eq!(answer, "5");
+        assert_eq!(times.total(), times.parse + times.solve);
+        let (answer, _) = solver.solve(2, "ab\nabc").unwrap();
+        assert_eq!(answer, "3");
+        assert_eq!(
+            solver.solve(2, "").unwrap_err(),
+            AocError::no_answer("there are no lines")
+        );
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        let mut solved = vec![];
+        Lengths.solve_parts(&[2, 1], "ab\nabc", &NoProgress, &mut |part, result| {
+            let (answer, times) = result.unwrap();
+            solved.push((part, answer.to_string(), times.parse));
+        });
+        assert_eq!(
+            solved
+                .iter()
+                .map(|(part, answer, _)| (*part, answer.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, "3"), (1, "5")]
+        );
+        assert_eq!(solved[0].2, solved[1].2);
+    }
+}
@@ -0,0 +1,112 @@
+//! Helpers for tests that generate their own inputs
+
+/// A deterministic xorshift, so generated inputs are the same every run
+pub struct Random(u64);
+
+impl Random {
+    /// Any seed but 0, which the xorshift never leaves
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "a xorshift can't start from 0");
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number from 0 up to, but not including, `below`
+    pub fn below(&mut self, below: u64) -> u64 {
+        self.next_u64() % below
+    }
+}
+
+/// A square day 3 schematic `size` across, with numbers and symbols scattered through it the same
+/// way every time
+pub fn synthetic_schematic(size: usize) -> String {
+    let mut random = Random::new(0x2545_f491);
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < size {
+                match random.below(10) {
+                    0..=4 => line.push('.'),
+                    5..=7 => line.push_str(&random.below(1000).to_string()),
+                    8 => line.push('*'),
+                    _ => line.push('#'),
+                }
+                line.push('.');
+            }
+            line.truncate(size);
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `count` day 7 hands and bids, a line each. Some hands have fewer kinds of card than a deck, so
+/// there are more pairs and better
+pub fn generated_hands(count: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut random = Random::new(0x9e37_79b9_7f4a_7c15);
+    (0..count)
+        .map(|_| {
+            let kinds = random.below(13) + 1;
+            let hand: String = (0..5)
+                .map(|_| char::from(CARDS[random.below(kinds) as usize]))
+                .collect();
+            format!("{hand} {}", random.below(1000) + 1)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A day 13 map `width` by `height` with its rows reflected somewhere, and up to two cells flipped
+/// so it might need a smudge cleaned up
+pub fn generated_map(random: &mut Random, width: usize, height: usize) -> String {
+    let mut rows: Vec<Vec<bool>> = (0..height)
+        .map(|_| (0..width).map(|_| random.below(2) == 0).collect())
+        .collect();
+    let mirror = random.below(height as u64 - 1) as usize + 1;
+    for (back, forward) in (0..mirror).rev().zip(mirror..height) {
+        rows[forward] = rows[back].clone();
+    }
+    for _ in 0..random.below(3) {
+        let row = random.below(height as u64) as usize;
+        let column = random.below(width as u64) as usize;
+        rows[row][column] = !rows[row][column];
+    }
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A day 12 row of `length` springs, with at least one damaged so there's a group, and about half
+/// of them unknown. There's always at least one arrangement, the springs it was made from
+pub fn generated_row(random: &mut Random, length: usize) -> String {
+    let mut springs: Vec<bool> = (0..length).map(|_| random.below(3) == 0).collect();
+    if !springs.contains(&true) {
+        springs[random.below(length as u64) as usize] = true;
+    }
+    let groups = springs
+        .split(|damaged| !damaged)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len().to_string())
+        .collect::<Vec<_>>();
+    let conditions = springs
+        .iter()
+        .map(|&damaged| match (random.below(2), damaged) {
+            (0, _) => '?',
+            (_, true) => '#',
+            (_, false) => '.',
+        })
+        .collect::<String>();
+    format!("{conditions} {}", groups.join(","))
+}
@@ -1,26 +0,0 @@
-pub fn part1(_input: &str) -> String {
-    todo!()
-}
-
-pub fn part2(_input: &str) -> String {
-    todo!()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[ignore]
-    #[test]
-    fn test_part1() {
-        let input = "";
-        assert_eq!(part1(input), "");
-    }
-
-    #[ignore]
-    #[test]
-    fn test_part2() {
-        let input = "";
-        assert_eq!(part2(input), "");
-    }
-}
@@ -0,0 +1,113 @@
+//! A bar chart of how long each day's parts take, to see where the time goes
+
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use plotters::prelude::*;
+
+const WIDTH: u32 = 1000;
+const HEIGHT: u32 = 500;
+/// The puzzle site's colours, its background and text, and the silver and gold of its stars
+const BACKGROUND: RGBColor = RGBColor(0x0f, 0x0f, 0x23);
+const TEXT: RGBColor = RGBColor(0xcc, 0xcc, 0xcc);
+const SILVER: RGBColor = RGBColor(0x99, 0x99, 0xcc);
+const GOLD: RGBColor = RGBColor(0xff, 0xff, 0x66);
+
+/// A duration in nanoseconds, in whichever unit keeps the number small
+fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        nanos if nanos >= 1e9 => format!("{}s", nanos / 1e9),
+        nanos if nanos >= 1e6 => format!("{}ms", nanos / 1e6),
+        nanos if nanos >= 1e3 => format!("{}µs", nanos / 1e3),
+        nanos => format!("{nanos}ns"),
+    }
+}
+
+/// An SVG with a bar for each `(day, part, time)`, part 1 silver and part 2 gold, side by side for
+/// each day. Times go up in powers of ten, so the quick days don't all look the same
+pub fn runtimes_svg(title: &str, runtimes: &[(usize, usize, Duration)]) -> Result<String> {
+    let nanos = |duration: &Duration| duration.as_nanos().max(1) as f64;
+    let (Some(fastest), Some(slowest)) = (
+        runtimes.iter().map(|(_, _, time)| time).min(),
+        runtimes.iter().map(|(_, _, time)| time).max(),
+    ) else {
+        bail!("there are no times to chart");
+    };
+    let (bottom, top) = (nanos(fastest) / 2.0, nanos(slowest) * 2.0);
+
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, (WIDTH, HEIGHT)).into_drawing_area();
+        root.fill(&BACKGROUND)?;
+        let font = |size| ("sans-serif", size).into_font().color(&TEXT);
+        let mut chart = ChartBuilder::on(&root)
+            .caption(title, font(24))
+            .margin(10)
+            .x_label_area_size(40)
+            .y_label_area_size(70)
+            .build_cartesian_2d(0.5..25.5, (bottom..top).log_scale())?;
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(25)
+            .y_labels(5)
+            .x_label_formatter(&|day| format!("{day:.0}"))
+            .y_label_formatter(&|nanos| format_nanos(*nanos))
+            .x_desc("Day")
+            .axis_style(TEXT)
+            .bold_line_style(TEXT.mix(0.2))
+            .light_line_style(TRANSPARENT)
+            .label_style(font(14))
+            .draw()?;
+        for (part, colour, offset) in [(1, SILVER, -0.4), (2, GOLD, 0.0)] {
+            let bars = runtimes
+                .iter()
+                .filter(|(_, bar_part, _)| *bar_part == part)
+                .map(|(day, _, time)| {
+                    let left = *day as f64 + offset;
+                    Rectangle::new([(left, bottom), (left + 0.4, nanos(time))], colour.filled())
+                });
+            chart
+                .draw_series(bars)?
+                .label(format!("Part {part}"))
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], colour.filled())
+                });
+        }
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperRight)
+            .label_font(font(14))
+            .background_style(BACKGROUND)
+            .border_style(TEXT)
+            .draw()?;
+        root.present()?;
+    }
+    Ok(svg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!(format_nanos(1.0), "1ns");
+        assert_eq!(format_nanos(10_000.0), "10µs");
+        assert_eq!(format_nanos(1_500_000_000.0), "1.5s");
+    }
+
+    #[test]
+    fn test_runtimes_svg() {
+        let runtimes = [
+            (1, 1, Duration::from_micros(50)),
+            (1, 2, Duration::from_micros(80)),
+            (12, 2, Duration::from_millis(30)),
+        ];
+        let svg = runtimes_svg("Runtimes", &runtimes).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Part 1") && svg.contains("Part 2"));
+        assert_eq!(svg.matches("#FFFF66").count(), 3);
+        assert!(runtimes_svg("Runtimes", &[]).is_err());
+    }
+}
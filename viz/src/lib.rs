@@ -0,0 +1,12 @@
+//! Drawing what the solvers are up to: colours, progress bars, profiles, charts of how long they
+//! take and pictures of what they made of an input. Kept apart from the solvers so using them as a library doesn't pull any of
+//! this in
+
+pub mod chart;
+pub mod network;
+pub mod page;
+pub mod pipes;
+pub mod profile;
+pub mod progress;
+pub mod schematic;
+pub mod style;
@@ -0,0 +1,62 @@
+//! Day 8's network of nodes as a GraphViz graph, to see the loops the ghosts go round
+
+use std::fmt::Write;
+
+use advent_of_code_2024::day08::{is_finish, is_start, MapTo};
+
+/// A DOT graph with an edge for each way out of each node, labelled `L` or `R` (or `LR` where
+/// both go the same way). Node names are quoted, as the examples' can start with a digit. Starts
+/// are green, finishes red, and `AAA` and `ZZZ` are drawn bold
+pub fn network_dot<'a>(turnings: impl IntoIterator<Item = (&'a str, MapTo<&'a str>)>) -> String {
+    let mut dot = String::from("digraph network {\n    node [shape=circle];\n");
+    let mut edges = String::new();
+    for (node, MapTo { left, right }) in turnings {
+        let mut attributes = vec![];
+        if is_start(node) {
+            attributes.push(r##"style=filled, fillcolor="#66cc66""##);
+        } else if is_finish(node) {
+            attributes.push(r##"style=filled, fillcolor="#ff6666""##);
+        }
+        if node == "AAA" || node == "ZZZ" {
+            attributes.push("penwidth=3");
+        }
+        if !attributes.is_empty() {
+            let _ = writeln!(dot, "    {node:?} [{}];", attributes.join(", "));
+        }
+        if left == right {
+            let _ = writeln!(edges, r#"    {node:?} -> {left:?} [label="LR"];"#);
+        } else {
+            let _ = writeln!(edges, r#"    {node:?} -> {left:?} [label="L"];"#);
+            let _ = writeln!(edges, r#"    {node:?} -> {right:?} [label="R"];"#);
+        }
+    }
+    dot.push_str(&edges);
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_of_code_2024::day08::Day08;
+    use advent_of_code_2024::solver::Day;
+
+    #[test]
+    fn test_network_dot() {
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let (_, network) = Day08::parse(input).unwrap();
+        assert_eq!(
+            network_dot(network.turnings()),
+            r##"digraph network {
+    node [shape=circle];
+    "AAA" [style=filled, fillcolor="#66cc66", penwidth=3];
+    "ZZZ" [style=filled, fillcolor="#ff6666", penwidth=3];
+    "AAA" -> "BBB" [label="L"];
+    "AAA" -> "ZZZ" [label="R"];
+    "BBB" -> "ZZZ" [label="LR"];
+    "ZZZ" -> "ZZZ" [label="LR"];
+}
+"##
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! The HTML page the drawings are written into, dark like the puzzle's site
+
+/// A page titled `title` with `body` as preformatted text, styled by the page's own `css` as well
+/// as the colours every page shares
+pub fn page(title: &str, css: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ background: #0f0f23; color: #cccccc; }}
+{css}</style>
+</head>
+<body>
+<pre>
+{body}
+</pre>
+</body>
+</html>
+"#
+    )
+}
+
+/// `text` with the characters HTML would read as markup escaped
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
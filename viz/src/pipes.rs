@@ -0,0 +1,99 @@
+//! Day 10's pipe maze drawn with box drawing characters, with the loop picked out from the tiles
+//! inside and outside it, to see why a tile was or wasn't counted as enclosed
+
+use console::style;
+use itertools::Itertools;
+
+use advent_of_code_2024::day10::{Pipe, Tile};
+use advent_of_code_2024::grid::Grid;
+
+use crate::page::page;
+
+/// How a tile's drawn, a box drawing character for pipes, a big dot inside the loop and a small one
+/// outside
+fn symbol(tile: Tile) -> char {
+    match tile {
+        Tile::Loop(pipe) | Tile::Start(pipe) => match pipe {
+            Pipe::NS => '│',
+            Pipe::EW => '─',
+            Pipe::NE => '└',
+            Pipe::NW => '┘',
+            Pipe::SW => '┐',
+            Pipe::SE => '┌',
+            Pipe::Ground | Pipe::Start => '?',
+        },
+        Tile::Inside => '●',
+        Tile::Outside => '·',
+    }
+}
+
+/// For a terminal: the loop in yellow with the start in bold red, inside tiles in green and outside
+/// tiles dimmed
+pub fn pipes_ansi(tiles: &Grid<Tile>) -> String {
+    tiles
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&tile| {
+                    let text = style(symbol(tile));
+                    match tile {
+                        Tile::Loop(_) => text.yellow(),
+                        Tile::Start(_) => text.red().bold(),
+                        Tile::Inside => text.green(),
+                        Tile::Outside => text.dim(),
+                    }
+                    .to_string()
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// A page with the maze in, coloured the same as [pipes_ansi]
+pub fn pipes_html(tiles: &Grid<Tile>) -> String {
+    let body = tiles
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&tile| {
+                    let class = match tile {
+                        Tile::Loop(_) => "loop",
+                        Tile::Start(_) => "start",
+                        Tile::Inside => "inside",
+                        Tile::Outside => "outside",
+                    };
+                    format!(r#"<span class="{class}">{}</span>"#, symbol(tile))
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    page(
+        "Pipe Maze",
+        "pre { line-height: 1; }
+.loop { color: #ffff66; }
+.start { color: #ff4444; font-weight: bold; }
+.inside { color: #00cc00; }
+.outside { color: #555555; }
+",
+        &body,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_of_code_2024::day10::Day10;
+    use advent_of_code_2024::solver::Day;
+
+    #[test]
+    fn test_pipes_html() {
+        let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....";
+        let tiles = Day10::parse(input).unwrap().tiles().unwrap();
+        let html = pipes_html(&tiles);
+        assert!(html.contains(concat!(
+            r#"<span class="outside">·</span><span class="start">┌</span>"#,
+            r#"<span class="loop">─</span><span class="loop">┐</span>"#,
+        )));
+        assert!(html.contains(r#"<span class="inside">●</span>"#));
+    }
+}
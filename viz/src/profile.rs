@@ -0,0 +1,93 @@
+//! CPU profiling of a solver with `--profile`, only available when built with `--features profile`
+//! as it pulls in a lot of dependencies that are otherwise unused
+
+use std::path::Path;
+
+use anyhow::Result;
+
+/// How many times a second to sample, solvers are often quick enough to need plenty
+#[cfg(feature = "profile")]
+const FREQUENCY: i32 = 1000;
+
+/// What to write a profile as, picked from the file extension
+#[cfg_attr(not(feature = "profile"), allow(dead_code))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ProfileFormat {
+    /// A flamegraph to open in a browser
+    Svg,
+    /// A pprof protobuf, for `go tool pprof` and friends
+    Protobuf,
+}
+
+#[cfg_attr(not(feature = "profile"), allow(dead_code))]
+fn profile_format(path: &Path) -> ProfileFormat {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("pb") => ProfileFormat::Protobuf,
+        _ => ProfileFormat::Svg,
+    }
+}
+
+/// Samples every thread's stack until it's written out
+#[cfg(feature = "profile")]
+pub struct Profiler(pprof::ProfilerGuard<'static>);
+
+#[cfg(feature = "profile")]
+impl Profiler {
+    pub fn start() -> Result<Self> {
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()?;
+        Ok(Self(guard))
+    }
+
+    /// Stop sampling and write the profile to `path`, as protobuf if it ends `.pb` otherwise SVG
+    pub fn write(self, path: &Path) -> Result<()> {
+        use std::fs::{write, File};
+
+        use anyhow::Context;
+        use pprof::protos::Message;
+
+        let report = self.0.report().build()?;
+        match profile_format(path) {
+            ProfileFormat::Svg => {
+                let file = File::create(path)
+                    .with_context(|| format!("failed to create {}", path.display()))?;
+                report.flamegraph(file)?;
+            }
+            ProfileFormat::Protobuf => {
+                let mut content = Vec::new();
+                report.pprof()?.encode(&mut content)?;
+                write(path, content)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "profile"))]
+pub struct Profiler;
+
+#[cfg(not(feature = "profile"))]
+impl Profiler {
+    pub fn start() -> Result<Self> {
+        anyhow::bail!("profiling isn't available, rebuild with `--features profile`")
+    }
+
+    pub fn write(self, _path: &Path) -> Result<()> {
+        unreachable!("a profiler can't be started without the profile feature")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_profile_format() {
+        assert_eq!(profile_format(Path::new("d12.svg")), ProfileFormat::Svg);
+        assert_eq!(profile_format(Path::new("d12.pb")), ProfileFormat::Protobuf);
+        assert_eq!(profile_format(Path::new("d12")), ProfileFormat::Svg);
+    }
+}
@@ -0,0 +1,44 @@
+use std::ops::Deref;
+
+use advent_of_code_2024::progress::Progress;
+use indicatif::{ProgressDrawTarget, ProgressStyle};
+
+/// A bar with an ETA drawn to stderr, indicatif hides it if stderr isn't a terminal
+#[derive(Debug, Clone)]
+pub struct ProgressBar(indicatif::ProgressBar);
+
+impl ProgressBar {
+    /// Clear the bar away, and keep it hidden even if a solver that's been abandoned is still
+    /// ticking it along
+    pub fn finish(&self) {
+        self.0.finish_and_clear();
+        self.0.set_draw_target(ProgressDrawTarget::hidden());
+    }
+}
+
+impl Deref for ProgressBar {
+    type Target = indicatif::ProgressBar;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Progress for ProgressBar {
+    fn set_length(&self, length: u64) {
+        self.0.set_length(length);
+    }
+
+    fn inc(&self, delta: u64) {
+        self.0.inc(delta);
+    }
+}
+
+pub fn progress_bar() -> ProgressBar {
+    let bar = indicatif::ProgressBar::new(0);
+    bar.set_style(
+        ProgressStyle::with_template("{elapsed_precise} [{wide_bar}] {pos}/{len} (eta {eta})")
+            .expect("progress template should be valid"),
+    );
+    ProgressBar(bar)
+}
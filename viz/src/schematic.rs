@@ -0,0 +1,100 @@
+//! Day 3's engine schematic drawn back out with what the solver made of each cell, to see why a
+//! number was or wasn't counted
+
+use console::style;
+use itertools::Itertools;
+
+use advent_of_code_2024::day03::Mark;
+use advent_of_code_2024::grid::{Grid, Pos};
+
+use crate::page::{escape, page};
+
+/// Each line of `input` as runs of characters with the same mark
+fn runs<'a>(
+    input: &'a str,
+    marks: &'a Grid<Mark>,
+) -> impl Iterator<Item = Vec<(Mark, String)>> + 'a {
+    input.lines().enumerate().map(move |(row, line)| {
+        line.chars()
+            .enumerate()
+            .map(|(column, c)| {
+                let mark = marks.get(Pos::new(row, column)).copied();
+                (mark.unwrap_or(Mark::Blank), c)
+            })
+            .group_by(|(mark, _)| *mark)
+            .into_iter()
+            .map(|(mark, cells)| (mark, cells.map(|(_, c)| c).collect()))
+            .collect()
+    })
+}
+
+/// For a terminal: part numbers in green, numbers that aren't parts in red, gears in bold yellow
+/// and any other symbol in cyan
+pub fn schematic_ansi(input: &str, marks: &Grid<Mark>) -> String {
+    runs(input, marks)
+        .map(|line| {
+            line.into_iter()
+                .map(|(mark, text)| {
+                    let text = style(text);
+                    match mark {
+                        Mark::Blank => text.dim(),
+                        Mark::Part => text.green(),
+                        Mark::NotPart => text.red(),
+                        Mark::Symbol => text.cyan(),
+                        Mark::Gear => text.yellow().bold(),
+                    }
+                    .to_string()
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// A page with the schematic in, coloured the same as [schematic_ansi]
+pub fn schematic_html(input: &str, marks: &Grid<Mark>) -> String {
+    let body = runs(input, marks)
+        .map(|line| {
+            line.into_iter()
+                .map(|(mark, text)| {
+                    let text = escape(&text);
+                    match mark {
+                        Mark::Blank => format!(r#"<span class="blank">{text}</span>"#),
+                        Mark::Part => format!(r#"<span class="part">{text}</span>"#),
+                        Mark::NotPart => format!(r#"<span class="not-part">{text}</span>"#),
+                        Mark::Symbol => format!(r#"<span class="symbol">{text}</span>"#),
+                        Mark::Gear => format!(r#"<span class="gear">{text}</span>"#),
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    page(
+        "Gear Ratios",
+        ".blank { color: #555555; }
+.part { color: #00cc00; }
+.not-part { color: #ff4444; }
+.symbol { color: #00cccc; }
+.gear { color: #ffff66; font-weight: bold; }
+",
+        &body,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_of_code_2024::day03::{Day03, GearRule};
+    use advent_of_code_2024::solver::Day;
+
+    #[test]
+    fn test_schematic_html() {
+        let input = "467..114..\n...*......\n..35..633.";
+        let marks = Day03::parse(input).unwrap().marks(&GearRule::default());
+        let html = schematic_html(input, &marks);
+        assert!(html.contains(concat!(
+            r#"<span class="part">467</span><span class="blank">..</span>"#,
+            r#"<span class="not-part">114</span><span class="blank">..</span>"#,
+        )));
+        assert!(html.contains(r#"<span class="gear">*</span>"#));
+    }
+}
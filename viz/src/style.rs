@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use console::{style, StyledObject};
+
+use advent_of_code_2024::registry::Status;
+
+/// Colours are already turned off when stdout isn't a terminal, this is for when they're unwanted anyway
+pub fn disable_colors() {
+    console::set_colors_enabled(false);
+    console::set_colors_enabled_stderr(false);
+}
+
+pub fn answer<D: Display>(answer: D) -> StyledObject<D> {
+    style(answer).bold().cyan()
+}
+
+/// Green for under a millisecond, yellow for under a second and red for anything slower
+pub fn timing<D: Display>(text: D, duration: Duration) -> StyledObject<D> {
+    let styled = style(text);
+    if duration < Duration::from_millis(1) {
+        styled.green()
+    } else if duration < Duration::from_secs(1) {
+        styled.yellow()
+    } else {
+        styled.red()
+    }
+}
+
+pub fn error<D: Display>(error: D) -> StyledObject<D> {
+    style(error).red()
+}
+
+pub fn correct<D: Display>(text: D, correct: Option<bool>) -> StyledObject<D> {
+    match correct {
+        Some(true) => style(text).green(),
+        Some(false) => style(text).red().bold(),
+        None => style(text).dim(),
+    }
+}
+
+pub fn status<D: Display>(text: D, status: Status) -> StyledObject<D> {
+    match status {
+        Status::Solved => style(text).green(),
+        Status::Incomplete => style(text).yellow(),
+        Status::Todo => style(text).dim(),
+    }
+}